@@ -9,7 +9,7 @@
 //! | Code | Variant                  | Typical trigger                                             |
 //! |------|--------------------------|-------------------------------------------------------------|
 //! |  1   | `ProjectNotFound`        | Querying or operating on a project ID that does not exist   |
-//! |  2   | `MilestoneNotFound`      | Reserved for future milestone-level operations              |
+//! |  2   | `MilestoneNotFound`      | Milestone index passed to `acknowledge_milestone` is out of range |
 //! |  3   | `MilestoneAlreadyReleased` | Calling `verify_proof` on an already-verified/completed project |
 //! |  4   | `InsufficientBalance`    | Refund requested but donator has zero balance for that token |
 //! |  5   | `InvalidMilestones`      | Reserved for future milestone validation                    |
@@ -17,13 +17,13 @@
 //! |  7   | `InvalidGoal`            | Goal is ≤ 0 or exceeds the 10^30 upper bound               |
 //! |  8   | `AlreadyInitialized`     | `init` called more than once                                |
 //! |  9   | `RoleNotFound`           | Reserved for role-query edge cases                          |
-//! | 10   | `TooManyTokens`          | `accepted_tokens` list exceeds the 10-token cap             |
+//! | 10   | `TooManyTokens`          | `accepted_tokens` list exceeds the per-project token cap    |
 //! | 11   | `InvalidAmount`          | Deposit or transfer amount is ≤ 0                           |
 //! | 12   | `DuplicateToken`         | `accepted_tokens` contains the same address twice           |
 //! | 13   | `InvalidDeadline`        | Deadline is in the past or more than 5 years in the future  |
 //! | 14   | `ProjectExpired`         | Operation attempted on a project whose deadline has passed  |
 //! | 15   | `ProjectNotActive`       | Deposit/verify attempted on a Completed or invalid-status project |
-//! | 16   | `VerificationFailed`     | Submitted proof hash does not match the stored proof hash   |
+//! | 16   | `VerificationFailed`     | Invalid milestone hash, signed message or proof list |
 //! | 17   | `EmptyAcceptedTokens`    | `accepted_tokens` list is empty at registration             |
 //! | 18   | `Overflow`               | Arithmetic overflow on balance addition                     |
 //! | 19   | `ProtocolPaused`         | Mutating operation attempted while the protocol is paused   |
@@ -41,9 +41,52 @@
 //! | 31   | `MetadataCidInvalid`     | IPFS CID byte string was empty or exceeded max length |
 //! | 32   | `FeeBpsExceedsMaximum`   | Configured fee in basis points exceeds the 10_000 hard cap |
 //! | 33   | `ProjectPaused`          | Mutating project action attempted while the project is paused |
-//! | 34   | `GracePeriodActive`      | `claim_funds` called before the 24-hour grace period has elapsed |
+//! | 34   | `GracePeriodActive`      | `claim_funds` called before the grace (challenge) period has elapsed |
 //! | 35   | `ReentrancyDetected`     | A re-entrant call was detected; the contract is already executing |
 //! | 36   | `InvalidOracleConfig`    | Oracle threshold or count is invalid. |
+//! | 37   | `OracleKeyNotSet`        | Signed verification attempted before an oracle public key was set |
+//! | 38   | `RegisterCooldown`       | Creator registered again before the registration cooldown elapsed |
+//! | 39   | `TooManyAllocations`     | `deposit_split` called with more than 10 allocations |
+//! | 40   | `DepositWindowClosed`    | Deposit attempted after the project's deposit deadline |
+//! | 41   | `BatchTooLarge`          | A batch query exceeded its maximum number of entries |
+//! | 42   | `MemoTooLong`            | Donation memo exceeds the maximum length     |
+//! | 43   | `DeadlineTooFar`         | Deadline is beyond the configured maximum horizon |
+//! | 44   | `DonationsPaused`        | Creator has paused donations to the project  |
+//! | 45   | `DepositTooLarge`        | Deposit exceeds the per-transaction maximum  |
+//! | 46   | `TransferShortfall`      | Contract received less than a split deposit allocated |
+//! | 47   | `CapExceeded`            | Deposit would overshoot a capped project's goal |
+//! | 48   | `InvalidIncrement`       | Deposit is not a multiple of the project's increment |
+//! | 49   | `DonorCapExceeded`       | Deposit would take a donor past the project's per-donor cap |
+//! | 50   | `AnnounceTooSoon`        | Project was announced again before the announce cooldown elapsed |
+//! | 51   | `TokenNotApproved`       | Token gate is on and the token is not in the global registry |
+//! | 52   | `SnapshotLimitReached`   | Project already holds the maximum number of balance snapshots |
+//! | 53   | `InsufficientOracles`    | Fewer addresses hold the Oracle role than the configured minimum |
+//! | 54   | `CategoryNotAllowed`     | Project category is not in the admin-configured allow-list |
+//! | 55   | `PledgeNotFound`         | `fulfill_pledge` called with no open pledge for that token |
+//! | 56   | `SelfDepositTooEarly`    | Creator deposited into their own project before the self-deposit delay elapsed |
+//! | 57   | `BelowMinimum`           | Deposit is below the larger of the global and project minimum |
+//! | 58   | `RouterNotSet`           | `deposit_and_swap` called before a swap router was configured |
+//! | 59   | `SlippageExceeded`       | Swap delivered less than the donor's `min_out` |
+//! | 60   | `ProofHashNotSet`        | Project still carries the default proof hash and cannot be verified |
+//! | 61   | `ProofHashAlreadySet`    | `set_proof_hash` called on a project whose proof hash is already set |
+//! | 62   | `InvalidFeeTiers`        | Fee tiers are empty or not sorted ascending by threshold |
+//! | 63   | `ReleaseIncomplete`      | A project balance was left over after a full release |
+//! | 64   | `GoalNotMet`             | All-or-nothing project verified before its goal was reached |
+//! | 65   | `BalanceUnderflow`       | Refund or release larger than the recorded project balance |
+//! | 66   | `ProjectHasDeposits`     | `correct_goal` called after the project received deposits |
+//! | 67   | `ClaimTimeoutNotReached` | Verified funds reclaimed before the creator's claim timeout ran out |
+//! | 68   | `VerificationPending`    | Deposit into a locked project after the first oracle confirmation |
+//! | 69   | `DecommissionBlocked`    | `decommission` called while projects or other role holders remain |
+//! | 70   | `NotInstitution`         | Deposit into an institutions-only project from an unregistered donor |
+//! | 71   | `TokenHasBalance`        | `remove_token` called while the project still holds that token |
+//! | 72   | `ChallengeWindowClosed`  | Verification challenged after its challenge period ended |
+//! | 73   | `CreatorRoleRevoked`     | Release to a creator who lost their registration role |
+//! | 74   | `InvalidTags`            | Too many tags, a duplicate tag, or a tag over 9 characters |
+//! | 75   | `ConfirmationTooSoon`    | Oracle confirmation sooner than the gap after the previous one |
+//! | 76   | `SoftCapReached`         | Refund from an expired project that reached its soft cap |
+//! | 77   | `ProofMismatch`          | Proof hash matches neither `proof_hash` nor a required proof |
+//! | 78   | `OracleWeightTooLow`     | Lone oracle's weight is below the consensus threshold |
+//! | 79   | `TokensLocked`           | Token list change on a project whose tokens are locked |
 
 use soroban_sdk::contracterror;
 
@@ -79,7 +122,7 @@ pub enum Error {
     /// Reserved — the queried address holds no RBAC role.
     RoleNotFound = 9,
 
-    /// The `accepted_tokens` list exceeds the maximum of 10 tokens.
+    /// The `accepted_tokens` list exceeds the per-project token cap.
     TooManyTokens = 10,
 
     /// A deposit or transfer amount is ≤ 0.
@@ -97,7 +140,8 @@ pub enum Error {
     /// The project is not in `Funding` or `Active` status.
     ProjectNotActive = 15,
 
-    /// The submitted proof hash does not match the project's stored `proof_hash`.
+    /// The submitted proof material is invalid: a milestone hash mismatch,
+    /// a malformed signed message or a bad required-proof list.
     VerificationFailed = 16,
 
     /// Registration attempted with an empty `accepted_tokens` list.
@@ -151,7 +195,7 @@ pub enum Error {
     /// The target project is paused.
     ProjectPaused = 33,
 
-    /// The 24-hour grace period after proof verification has not yet elapsed.
+    /// The grace period after proof verification has not yet elapsed.
     GracePeriodActive = 34,
 
     /// A re-entrant call was detected.
@@ -159,4 +203,158 @@ pub enum Error {
 
     /// Oracle threshold or count is invalid.
     InvalidOracleConfig = 36,
+
+    /// No ed25519 oracle public key has been registered for signed verification.
+    OracleKeyNotSet = 37,
+
+    /// The creator registered another project before the cooldown elapsed.
+    RegisterCooldown = 38,
+
+    /// A split deposit exceeds the maximum number of allocations.
+    TooManyAllocations = 39,
+
+    /// The project's deposit window has closed, although it is not expired.
+    DepositWindowClosed = 40,
+
+    /// A batch query was called with more entries than it allows.
+    BatchTooLarge = 41,
+
+    /// Donation memo is longer than the allowed number of bytes.
+    MemoTooLong = 42,
+
+    /// Registration deadline is further out than the maximum deadline horizon.
+    DeadlineTooFar = 43,
+
+    /// The project creator has temporarily stopped accepting donations.
+    DonationsPaused = 44,
+
+    /// Deposit amount exceeds the configured per-transaction maximum.
+    DepositTooLarge = 45,
+
+    /// The contract received fewer tokens than a split deposit allocated
+    /// (e.g. a fee-on-transfer token).
+    TransferShortfall = 46,
+
+    /// Deposit would take the project past its goal and the overshoot policy
+    /// does not allow it.
+    CapExceeded = 47,
+
+    /// Deposit amount is not a multiple of the configured deposit increment.
+    InvalidIncrement = 48,
+
+    /// Deposit would take the donor's cumulative contribution in a token past
+    /// the project's per-donor cap.
+    DonorCapExceeded = 49,
+
+    /// `announce` was called for a project before `ANNOUNCE_COOLDOWN` elapsed
+    /// since its last announcement.
+    AnnounceTooSoon = 50,
+
+    /// The global token gate is enabled and the token has not been approved
+    /// via `approve_token`.
+    TokenNotApproved = 51,
+
+    /// The project already holds `MAX_SNAPSHOTS` balance snapshots.
+    SnapshotLimitReached = 52,
+
+    /// Fewer distinct addresses hold `Role::Oracle` than `MinOracles`
+    /// requires, so verification is refused.
+    InsufficientOracles = 53,
+
+    /// The category is not in the admin-configured allow-list.
+    CategoryNotAllowed = 54,
+
+    /// The donator has no open pledge in the given token for the project.
+    PledgeNotFound = 55,
+
+    /// The creator tried to fund their own project before
+    /// `SelfDepositDelay` elapsed since registration.
+    SelfDepositTooEarly = 56,
+
+    /// Deposit amount is below the effective minimum, the larger of
+    /// `GlobalMinDeposit` and the project's own minimum.
+    BelowMinimum = 57,
+
+    /// No swap router has been configured for `deposit_and_swap`.
+    RouterNotSet = 58,
+
+    /// The router delivered fewer tokens than the requested `min_out`.
+    SlippageExceeded = 59,
+
+    /// The project was registered without a proof hash and `set_proof_hash`
+    /// has not been called yet.
+    ProofHashNotSet = 60,
+
+    /// The proof hash can only be set once, replacing the default.
+    ProofHashAlreadySet = 61,
+
+    /// Deposit fee tiers must be non-empty with strictly ascending,
+    /// non-negative thresholds.
+    InvalidFeeTiers = 62,
+
+    /// Safety invariant: after every token of a project was released its
+    /// recorded balances must all be zero.
+    ReleaseIncomplete = 63,
+
+    /// An `AllOrNothing` project cannot be verified until every token goal
+    /// is met.
+    GoalNotMet = 64,
+
+    /// A balance decrement exceeded the recorded balance; the accounting
+    /// would otherwise go negative.
+    BalanceUnderflow = 65,
+
+    /// The goal can only be corrected before the project takes any deposit.
+    ProjectHasDeposits = 66,
+
+    /// `reclaim_unclaimed` called while the creator may still claim, or with
+    /// no claim timeout configured.
+    ClaimTimeoutNotReached = 67,
+
+    /// The project locks deposits once an oracle confirmation is recorded,
+    /// and one has been.
+    VerificationPending = 68,
+
+    /// The contract cannot be decommissioned: projects have been registered
+    /// or roles other than SuperAdmin are still held.
+    DecommissionBlocked = 69,
+
+    /// The project only accepts deposits from registered institutions and
+    /// the donor is not one.
+    NotInstitution = 70,
+
+    /// The token cannot be removed while the project holds a balance in it.
+    TokenHasBalance = 71,
+
+    /// The verification can no longer be challenged: its challenge period
+    /// has ended.
+    ChallengeWindowClosed = 72,
+
+    /// The creator no longer holds a role that may register projects, and
+    /// `RequireCreatorRole` is switched on.
+    CreatorRoleRevoked = 73,
+
+    /// More than `MAX_TAGS` tags, a repeated tag, or a tag longer than nine
+    /// characters.
+    InvalidTags = 74,
+
+    /// An oracle confirmed a project less than the configured confirmation
+    /// gap after the previous confirmation.
+    ConfirmationTooSoon = 75,
+
+    /// The project expired after reaching its soft cap, so its funds go to
+    /// the creator and cannot be refunded.
+    SoftCapReached = 76,
+
+    /// The submitted proof hash does not match the project's `proof_hash`,
+    /// or any of its required proofs.
+    ProofMismatch = 77,
+
+    /// A project without `authorized_oracles` was verified by an oracle
+    /// whose weight alone does not reach the consensus threshold.
+    OracleWeightTooLow = 78,
+
+    /// The project's creator locked its accepted token list, so tokens can
+    /// no longer be removed or migrated, even by an admin.
+    TokensLocked = 79,
 }
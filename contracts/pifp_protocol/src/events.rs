@@ -1,8 +1,126 @@
 #![allow(deprecated, dead_code)]
 //! On-chain event definitions and emission helpers for the PIFP protocol.
+//!
+//! ## Topics
+//!
+//! Each event is published with one of the `pub const` topic symbols below as
+//! its first topic, followed by the project ID where the event concerns a
+//! single project. Role events from [`crate::rbac`] use `#[contractevent]`
+//! instead, with the topics `role_set`, `role_del` and `role_lbl` named by
+//! `ROLE_SET`, `ROLE_DEL` and `ROLE_LABEL_SET`.
+//!
+//! `AuditRecord::action` is the topic of the recorded call's event, except
+//! `transfer_super_admin`, recorded as `su_xfer` (`SUPER_ADMIN_TRANSFERRED`)
+//! so it stands apart from an ordinary grant.
+//!
+//! | Topic       | Constant                       |
+//! |-------------|--------------------------------|
+//! | `proj_cr`   | `PROJECT_CREATED`              |
+//! | `proj_fnd`  | `PROJECT_FUNDED`               |
+//! | `donation`  | `DONATION_RECEIVED`            |
+//! | `anon_dep`  | `ANON_DEPOSITED`               |
+//! | `anon_ref`  | `ANON_REFUNDED`                |
+//! | `status`    | `STATUS_CHANGED`               |
+//! | `proj_act`  | `PROJECT_ACTIVE`               |
+//! | `goal_dl`   | `GOAL_REACHED_WITH_DEADLINE`   |
+//! | `ready_ver` | `READY_FOR_VERIFICATION`       |
+//! | `progress`  | `FUNDING_PROGRESS`             |
+//! | `tok_goal`  | `TOKEN_GOAL_SET`               |
+//! | `tok_rem`   | `TOKEN_REMOVED`                |
+//! | `tok_mig`   | `TOKEN_MIGRATED`               |
+//! | `proof_ok`  | `PROOF_MATCHED`                |
+//! | `req_prf`   | `REQUIRED_PROOFS_SET`          |
+//! | `ms_ack`    | `MILESTONE_ACKNOWLEDGED`       |
+//! | `proj_ver`  | `PROJECT_VERIFIED`             |
+//! | `challenge` | `VERIFICATION_CHALLENGED`      |
+//! | `prf_set`   | `PROOF_HASH_SET`               |
+//! | `hash_alg`  | `HASH_ALGO_UPDATED`            |
+//! | `pledge`    | `PLEDGE_RECORDED`              |
+//! | `pledge_ok` | `PLEDGE_FULFILLED`             |
+//! | `category`  | `CATEGORY_UPDATED`             |
+//! | `cat_list`  | `ALLOWED_CATEGORIES_SET`       |
+//! | `visible`   | `VISIBILITY_UPDATED`           |
+//! | `disp_goal` | `DISPLAY_GOAL_SET`             |
+//! | `soft_cap`  | `SOFT_CAP_SET`                 |
+//! | `tok_lock`  | `TOKENS_LOCKED`                |
+//! | `tags`      | `TAGS_UPDATED`                 |
+//! | `conf_lock` | `CONFIRMATION_LOCK_SET`        |
+//! | `inst_only` | `INSTITUTIONS_ONLY_SET`        |
+//! | `goal_fix`  | `GOAL_CORRECTED`               |
+//! | `fund_mdl`  | `FUNDING_MODEL_UPDATED`        |
+//! | `ovr_pol`   | `OVERSHOOT_POLICY_UPDATED`     |
+//! | `rel_to`    | `RELEASE_TO_UPDATED`           |
+//! | `proj_vsig` | `PROJECT_VERIFIED_SIGNED`      |
+//! | `proj_exp`  | `PROJECT_EXPIRED`              |
+//! | `cap_met`   | `SOFT_CAP_MET`                 |
+//! | `proj_can`  | `PROJECT_CANCELLED`            |
+//! | `prj_psd`   | `PROJECT_PAUSED`               |
+//! | `prj_unp`   | `PROJECT_UNPAUSED`             |
+//! | `don_paus`  | `DONATIONS_PAUSED_SET`         |
+//! | `dep_inc`   | `DEPOSIT_INCREMENT_SET`        |
+//! | `donor_cap` | `MAX_PER_DONOR_SET`            |
+//! | `vest_dur`  | `VESTING_DURATION_SET`         |
+//! | `vested`    | `VESTED_CLAIMED`               |
+//! | `fnd_rel`   | `FUNDS_RELEASED`               |
+//! | `earmark`   | `EARMARKED`                    |
+//! | `rel_fail`  | `RELEASE_FAILED`               |
+//! | `proj_ref`  | `REFUNDED`                     |
+//! | `ext_dead`  | `DEADLINE_EXTENDED`            |
+//! | `dep_dead`  | `DEPOSIT_DEADLINE_SET`         |
+//! | `cfg_upd`   | `PROTOCOL_CONFIG_UPDATED`      |
+//! | `reg_cd`    | `REGISTER_COOLDOWN_UPDATED`    |
+//! | `self_dly`  | `SELF_DEPOSIT_DELAY_UPDATED`   |
+//! | `dl_horiz`  | `MAX_DEADLINE_HORIZON_UPDATED` |
+//! | `tok_appr`  | `TOKEN_APPROVAL_SET`           |
+//! | `inst_set`  | `INSTITUTION_SET`              |
+//! | `tok_gate`  | `TOKEN_GATE_SET`               |
+//! | `max_tok`   | `MAX_TOKENS_UPDATED`           |
+//! | `adm_reg`   | `ADMINS_CAN_REGISTER_SET`      |
+//! | `crt_role`  | `CREATOR_ROLE_REQUIRED_SET`    |
+//! | `max_dep`   | `MAX_DEPOSIT_UPDATED`          |
+//! | `swapped`   | `DEPOSIT_SWAPPED`              |
+//! | `router`    | `SWAP_ROUTER_SET`              |
+//! | `gmin_dep`  | `GLOBAL_MIN_DEPOSIT_UPDATED`   |
+//! | `min_dep`   | `MIN_DEPOSIT_SET`              |
+//! | `min_orc`   | `MIN_ORACLES_UPDATED`          |
+//! | `orc_wgt`   | `ORACLE_WEIGHT_UPDATED`        |
+//! | `cns_thr`   | `CONSENSUS_THRESHOLD_UPDATED`  |
+//! | `conf_gap`  | `CONFIRMATION_GAP_UPDATED`     |
+//! | `rounding`  | `ROUNDING_MODE_UPDATED`        |
+//! | `fee_tier`  | `FEE_TIERS_UPDATED`            |
+//! | `orc_bps`   | `ORACLE_REWARD_UPDATED`        |
+//! | `orc_rwd`   | `ORACLE_REWARDED`              |
+//! | `ref_win`   | `REFUND_WINDOW_UPDATED`        |
+//! | `chal_per`  | `CHALLENGE_PERIOD_UPDATED`     |
+//! | `clm_tmo`   | `CLAIM_TIMEOUT_UPDATED`        |
+//! | `unclaimed` | `UNCLAIMED_RECLAIMED`          |
+//! | `snapshot`  | `SNAPSHOT_TAKEN`               |
+//! | `swept`     | `UNCLAIMED_SWEPT`              |
+//! | `trs_wdr`   | `TREASURY_WITHDRAWN`           |
+//! | `fee_ded`   | `FEE_DEDUCTED`                 |
+//! | `whl_add`   | `WHITELIST_ADDED`              |
+//! | `whl_rem`   | `WHITELIST_REMOVED`            |
+//! | `exp_recl`  | `EXPIRED_FUNDS_RECLAIMED`      |
+//! | `prot_psd`  | `PROTOCOL_PAUSED`              |
+//! | `prot_unp`  | `PROTOCOL_UNPAUSED`            |
+//! | `decomm`    | `DECOMMISSIONED`               |
+//! | `fnd_clm`   | `FUNDS_CLAIMED`                |
+//! | `ora_voted` | `ORACLE_VOTED`                 |
+//! | `ora_add`   | `ORACLE_ADDED`                 |
+//! | `ora_key`   | `ORACLE_PUBKEY_SET`            |
+//! | `ora_rem`   | `ORACLE_REMOVED`               |
+//! | `m_verify`  | `MILESTONE_VERIFIED`           |
+//! | `prot_upg`  | `PROTOCOL_UPGRADED`            |
+//! | `ver_set`   | `VERSION_SET`                  |
+//! | `role_set`  | `ROLE_SET`                     |
+//! | `role_del`  | `ROLE_DEL`                     |
+//! | `role_lbl`  | `ROLE_LABEL_SET`               |
 
-use crate::types::ProtocolConfig;
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol};
+use crate::types::{
+    FundingModel, OvershootPolicy, ProjectStatus, ProtocolConfig, RoundingMode, TokenBalance,
+    Visibility,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -11,9 +129,120 @@ pub struct ProtocolUpgraded {
     pub new_wasm_hash: BytesN<32>,
 }
 
-const PROJECT_CREATED: Symbol = symbol_short!("created");
-const FUNDS_RELEASED: Symbol = symbol_short!("released");
-const MILESTONE_VERIFIED: Symbol = symbol_short!("m_verify");
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionSet {
+    pub old_version: u32,
+    pub new_version: u32,
+}
+
+// ── Topics ──────────────────────────────────────────────────────────
+//
+// Every event's first topic. Clients match on these strings; see the
+// module docs for the full list.
+
+pub const PROJECT_CREATED: Symbol = symbol_short!("proj_cr");
+pub const PROJECT_FUNDED: Symbol = symbol_short!("proj_fnd");
+pub const DONATION_RECEIVED: Symbol = symbol_short!("donation");
+pub const ANON_DEPOSITED: Symbol = symbol_short!("anon_dep");
+pub const ANON_REFUNDED: Symbol = symbol_short!("anon_ref");
+pub const STATUS_CHANGED: Symbol = symbol_short!("status");
+pub const PROJECT_ACTIVE: Symbol = symbol_short!("proj_act");
+pub const GOAL_REACHED_WITH_DEADLINE: Symbol = symbol_short!("goal_dl");
+pub const READY_FOR_VERIFICATION: Symbol = symbol_short!("ready_ver");
+pub const FUNDING_PROGRESS: Symbol = symbol_short!("progress");
+pub const TOKEN_GOAL_SET: Symbol = symbol_short!("tok_goal");
+pub const TOKEN_REMOVED: Symbol = symbol_short!("tok_rem");
+pub const TOKEN_MIGRATED: Symbol = symbol_short!("tok_mig");
+pub const PROOF_MATCHED: Symbol = symbol_short!("proof_ok");
+pub const REQUIRED_PROOFS_SET: Symbol = symbol_short!("req_prf");
+pub const MILESTONE_ACKNOWLEDGED: Symbol = symbol_short!("ms_ack");
+pub const PROJECT_VERIFIED: Symbol = symbol_short!("proj_ver");
+pub const VERIFICATION_CHALLENGED: Symbol = symbol_short!("challenge");
+pub const PROOF_HASH_SET: Symbol = symbol_short!("prf_set");
+pub const HASH_ALGO_UPDATED: Symbol = symbol_short!("hash_alg");
+pub const PLEDGE_RECORDED: Symbol = symbol_short!("pledge");
+pub const PLEDGE_FULFILLED: Symbol = symbol_short!("pledge_ok");
+pub const CATEGORY_UPDATED: Symbol = symbol_short!("category");
+pub const ALLOWED_CATEGORIES_SET: Symbol = symbol_short!("cat_list");
+pub const VISIBILITY_UPDATED: Symbol = symbol_short!("visible");
+pub const DISPLAY_GOAL_SET: Symbol = symbol_short!("disp_goal");
+pub const SOFT_CAP_SET: Symbol = symbol_short!("soft_cap");
+pub const TOKENS_LOCKED: Symbol = symbol_short!("tok_lock");
+pub const TAGS_UPDATED: Symbol = symbol_short!("tags");
+pub const CONFIRMATION_LOCK_SET: Symbol = symbol_short!("conf_lock");
+pub const INSTITUTIONS_ONLY_SET: Symbol = symbol_short!("inst_only");
+pub const GOAL_CORRECTED: Symbol = symbol_short!("goal_fix");
+pub const FUNDING_MODEL_UPDATED: Symbol = symbol_short!("fund_mdl");
+pub const OVERSHOOT_POLICY_UPDATED: Symbol = symbol_short!("ovr_pol");
+pub const RELEASE_TO_UPDATED: Symbol = symbol_short!("rel_to");
+pub const PROJECT_VERIFIED_SIGNED: Symbol = symbol_short!("proj_vsig");
+pub const PROJECT_EXPIRED: Symbol = symbol_short!("proj_exp");
+pub const SOFT_CAP_MET: Symbol = symbol_short!("cap_met");
+pub const PROJECT_CANCELLED: Symbol = symbol_short!("proj_can");
+pub const PROJECT_PAUSED: Symbol = symbol_short!("prj_psd");
+pub const PROJECT_UNPAUSED: Symbol = symbol_short!("prj_unp");
+pub const DONATIONS_PAUSED_SET: Symbol = symbol_short!("don_paus");
+pub const DEPOSIT_INCREMENT_SET: Symbol = symbol_short!("dep_inc");
+pub const MAX_PER_DONOR_SET: Symbol = symbol_short!("donor_cap");
+pub const VESTING_DURATION_SET: Symbol = symbol_short!("vest_dur");
+pub const VESTED_CLAIMED: Symbol = symbol_short!("vested");
+pub const FUNDS_RELEASED: Symbol = symbol_short!("fnd_rel");
+pub const EARMARKED: Symbol = symbol_short!("earmark");
+pub const RELEASE_FAILED: Symbol = symbol_short!("rel_fail");
+pub const REFUNDED: Symbol = symbol_short!("proj_ref");
+pub const DEADLINE_EXTENDED: Symbol = symbol_short!("ext_dead");
+pub const DEPOSIT_DEADLINE_SET: Symbol = symbol_short!("dep_dead");
+pub const PROTOCOL_CONFIG_UPDATED: Symbol = symbol_short!("cfg_upd");
+pub const REGISTER_COOLDOWN_UPDATED: Symbol = symbol_short!("reg_cd");
+pub const SELF_DEPOSIT_DELAY_UPDATED: Symbol = symbol_short!("self_dly");
+pub const MAX_DEADLINE_HORIZON_UPDATED: Symbol = symbol_short!("dl_horiz");
+pub const TOKEN_APPROVAL_SET: Symbol = symbol_short!("tok_appr");
+pub const INSTITUTION_SET: Symbol = symbol_short!("inst_set");
+pub const TOKEN_GATE_SET: Symbol = symbol_short!("tok_gate");
+pub const MAX_TOKENS_UPDATED: Symbol = symbol_short!("max_tok");
+pub const ADMINS_CAN_REGISTER_SET: Symbol = symbol_short!("adm_reg");
+pub const CREATOR_ROLE_REQUIRED_SET: Symbol = symbol_short!("crt_role");
+pub const MAX_DEPOSIT_UPDATED: Symbol = symbol_short!("max_dep");
+pub const DEPOSIT_SWAPPED: Symbol = symbol_short!("swapped");
+pub const SWAP_ROUTER_SET: Symbol = symbol_short!("router");
+pub const GLOBAL_MIN_DEPOSIT_UPDATED: Symbol = symbol_short!("gmin_dep");
+pub const MIN_DEPOSIT_SET: Symbol = symbol_short!("min_dep");
+pub const MIN_ORACLES_UPDATED: Symbol = symbol_short!("min_orc");
+pub const ORACLE_WEIGHT_UPDATED: Symbol = symbol_short!("orc_wgt");
+pub const CONSENSUS_THRESHOLD_UPDATED: Symbol = symbol_short!("cns_thr");
+pub const CONFIRMATION_GAP_UPDATED: Symbol = symbol_short!("conf_gap");
+pub const ROUNDING_MODE_UPDATED: Symbol = symbol_short!("rounding");
+pub const FEE_TIERS_UPDATED: Symbol = symbol_short!("fee_tier");
+pub const ORACLE_REWARD_UPDATED: Symbol = symbol_short!("orc_bps");
+pub const ORACLE_REWARDED: Symbol = symbol_short!("orc_rwd");
+pub const REFUND_WINDOW_UPDATED: Symbol = symbol_short!("ref_win");
+pub const CHALLENGE_PERIOD_UPDATED: Symbol = symbol_short!("chal_per");
+pub const CLAIM_TIMEOUT_UPDATED: Symbol = symbol_short!("clm_tmo");
+pub const UNCLAIMED_RECLAIMED: Symbol = symbol_short!("unclaimed");
+pub const SNAPSHOT_TAKEN: Symbol = symbol_short!("snapshot");
+pub const UNCLAIMED_SWEPT: Symbol = symbol_short!("swept");
+pub const TREASURY_WITHDRAWN: Symbol = symbol_short!("trs_wdr");
+pub const FEE_DEDUCTED: Symbol = symbol_short!("fee_ded");
+pub const WHITELIST_ADDED: Symbol = symbol_short!("whl_add");
+pub const WHITELIST_REMOVED: Symbol = symbol_short!("whl_rem");
+pub const EXPIRED_FUNDS_RECLAIMED: Symbol = symbol_short!("exp_recl");
+pub const PROTOCOL_PAUSED: Symbol = symbol_short!("prot_psd");
+pub const PROTOCOL_UNPAUSED: Symbol = symbol_short!("prot_unp");
+pub const DECOMMISSIONED: Symbol = symbol_short!("decomm");
+pub const FUNDS_CLAIMED: Symbol = symbol_short!("fnd_clm");
+pub const ORACLE_VOTED: Symbol = symbol_short!("ora_voted");
+pub const ORACLE_ADDED: Symbol = symbol_short!("ora_add");
+pub const ORACLE_PUBKEY_SET: Symbol = symbol_short!("ora_key");
+pub const ORACLE_REMOVED: Symbol = symbol_short!("ora_rem");
+pub const MILESTONE_VERIFIED: Symbol = symbol_short!("m_verify");
+pub const PROTOCOL_UPGRADED: Symbol = symbol_short!("prot_upg");
+pub const VERSION_SET: Symbol = symbol_short!("ver_set");
+pub const ROLE_SET: Symbol = symbol_short!("role_set");
+pub const ROLE_DEL: Symbol = symbol_short!("role_del");
+pub const ROLE_LABEL_SET: Symbol = symbol_short!("role_lbl");
+/// Audit action only; the transfer itself emits `role_set`.
+pub const SUPER_ADMIN_TRANSFERRED: Symbol = symbol_short!("su_xfer");
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -22,6 +251,9 @@ pub struct ProjectCreated {
     pub creator: Address,
     pub token: Address,
     pub goal: i128,
+    pub hash_algo: Symbol,
+    /// Informational USD goal with 6 decimals; 0 when unset.
+    pub display_goal_usd: i128,
 }
 
 #[contracttype]
@@ -32,18 +264,276 @@ pub struct ProjectFunded {
     pub amount: i128,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationReceived {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub memo: String,
+    pub seq: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonDeposited {
+    pub project_id: u64,
+    pub commitment: BytesN<32>,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonRefunded {
+    pub project_id: u64,
+    pub commitment: BytesN<32>,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectActive {
     pub project_id: u64,
 }
 
+/// Emitted alongside `ProjectActive`: seconds left until `deadline` to
+/// complete the work.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GoalReachedWithDeadline {
+    pub project_id: u64,
+    pub deadline: u64,
+    pub remaining: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChanged {
+    pub project_id: u64,
+    pub old_status: ProjectStatus,
+    pub new_status: ProjectStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadyForVerification {
+    pub project_id: u64,
+}
+
+/// Progress of one token toward its goal after a deposit. `pct` is in basis
+/// points and exceeds 10_000 once the token is overfunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingProgress {
+    pub project_id: u64,
+    pub token: Address,
+    pub balance: i128,
+    pub goal: i128,
+    pub pct: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGoalSet {
+    pub project_id: u64,
+    pub token: Address,
+    pub goal: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRemoved {
+    pub project_id: u64,
+    pub token: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMigrated {
+    pub project_id: u64,
+    pub old_token: Address,
+    pub new_token: Address,
+    pub balance: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectVerified {
     pub project_id: u64,
     pub oracle: Address,
     pub proof_hash: BytesN<32>,
+    pub hash_algo: Symbol,
+}
+
+/// An auditor halted a verification during its challenge period. `oracle`
+/// is the verifying oracle, if one verified it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationChallenged {
+    pub project_id: u64,
+    pub auditor: Address,
+    pub oracle: Option<Address>,
+    pub verified_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneAcknowledged {
+    pub project_id: u64,
+    pub milestone_index: u32,
+    pub oracle: Address,
+    pub proof_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofMatched {
+    pub project_id: u64,
+    pub index: u32,
+    pub oracle: Address,
+    pub remaining: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequiredProofsSet {
+    pub project_id: u64,
+    pub count: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectVerifiedSigned {
+    pub project_id: u64,
+    pub oracle_pubkey: BytesN<32>,
+    pub message_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofHashSet {
+    pub project_id: u64,
+    pub by: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashAlgoUpdated {
+    pub project_id: u64,
+    pub hash_algo: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PledgeRecorded {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PledgeFulfilled {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryUpdated {
+    pub project_id: u64,
+    pub category: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowedCategoriesSet {
+    pub admin: Address,
+    pub categories: Vec<Symbol>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OvershootPolicyUpdated {
+    pub project_id: u64,
+    pub policy: OvershootPolicy,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VisibilityUpdated {
+    pub project_id: u64,
+    pub visibility: Visibility,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisplayGoalSet {
+    pub project_id: u64,
+    pub display_goal_usd: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SoftCapSet {
+    pub project_id: u64,
+    pub soft_cap: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensLocked {
+    pub project_id: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TagsUpdated {
+    pub project_id: u64,
+    pub tags: Vec<Symbol>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfirmationLockSet {
+    pub project_id: u64,
+    pub lock: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstitutionsOnlySet {
+    pub project_id: u64,
+    pub only: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GoalCorrected {
+    pub project_id: u64,
+    pub old: i128,
+    pub new: i128,
+    pub reason: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingModelUpdated {
+    pub project_id: u64,
+    pub model: FundingModel,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseToUpdated {
+    pub project_id: u64,
+    pub release_to: Address,
 }
 
 #[contracttype]
@@ -53,6 +543,14 @@ pub struct ProjectExpired {
     pub deadline: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SoftCapMet {
+    pub project_id: u64,
+    pub soft_cap: i128,
+    pub balance: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Refunded {
@@ -82,6 +580,12 @@ pub struct ProtocolUnpaused {
     pub admin: Address,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Decommissioned {
+    pub super_admin: Address,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DeadlineExtended {
@@ -90,6 +594,13 @@ pub struct DeadlineExtended {
     pub new_deadline: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositDeadlineSet {
+    pub project_id: u64,
+    pub deposit_deadline: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProtocolConfigUpdated {
@@ -101,134 +612,778 @@ pub struct ProtocolConfigUpdated {
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct FeeDeducted {
-    pub project_id: u64,
+pub struct RegisterCooldownUpdated {
+    pub admin: Address,
+    pub cooldown: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SelfDepositDelayUpdated {
+    pub admin: Address,
+    pub delay: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxDeadlineHorizonUpdated {
+    pub admin: Address,
+    pub horizon: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenApprovalSet {
+    pub admin: Address,
     pub token: Address,
-    pub amount: i128,
-    pub recipient: Address,
+    pub approved: bool,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct WhitelistAdded {
-    pub project_id: u64,
-    pub address: Address,
+pub struct InstitutionSet {
+    pub admin: Address,
+    pub account: Address,
+    pub institution: bool,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct WhitelistRemoved {
-    pub project_id: u64,
-    pub address: Address,
+pub struct TokenGateSet {
+    pub admin: Address,
+    pub enabled: bool,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProjectCancelled {
-    pub project_id: u64,
-    pub cancelled_by: Address,
+pub struct MaxTokensUpdated {
+    pub admin: Address,
+    pub max: u32,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProjectPaused {
-    pub project_id: u64,
+pub struct AdminsCanRegisterSet {
     pub admin: Address,
+    pub allowed: bool,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProjectUnpaused {
-    pub project_id: u64,
+pub struct CreatorRoleRequiredSet {
     pub admin: Address,
+    pub required: bool,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct FundsReleased {
-    pub project_id: u64,
-    pub token: Address,
-    pub amount: i128,
+pub struct MinOraclesUpdated {
+    pub admin: Address,
+    pub min: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleWeightUpdated {
+    pub admin: Address,
+    pub oracle: Address,
+    pub weight: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsensusThresholdUpdated {
+    pub admin: Address,
+    pub threshold: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfirmationGapUpdated {
+    pub admin: Address,
+    pub gap: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTiersUpdated {
+    pub admin: Address,
+    pub tiers: Vec<(i128, u32)>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundingModeUpdated {
+    pub admin: Address,
+    pub mode: RoundingMode,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleRewardUpdated {
+    pub admin: Address,
+    pub bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleRewarded {
+    pub project_id: u64,
+    pub oracle: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundWindowUpdated {
+    pub admin: Address,
+    pub window: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengePeriodUpdated {
+    pub admin: Address,
+    pub period: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimTimeoutUpdated {
+    pub admin: Address,
+    pub timeout: u64,
+}
+
+/// A verified project whose creator never claimed was opened for refunds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnclaimedReclaimed {
+    pub project_id: u64,
+    pub verified_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotTaken {
+    pub project_id: u64,
+    pub index: u32,
+    pub ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnclaimedSwept {
+    pub project_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxDepositUpdated {
+    pub admin: Address,
+    pub max: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositSwapped {
+    pub project_id: u64,
+    pub donator: Address,
+    pub from_token: Address,
+    pub from_amount: i128,
+    pub to_token: Address,
+    pub received: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapRouterSet {
+    pub admin: Address,
+    pub router: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalMinDepositUpdated {
+    pub admin: Address,
+    pub min: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinDepositSet {
+    pub project_id: u64,
+    pub min: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryWithdrawn {
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeDeducted {
+    pub project_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WhitelistAdded {
+    pub project_id: u64,
+    pub address: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WhitelistRemoved {
+    pub project_id: u64,
+    pub address: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectCancelled {
+    pub project_id: u64,
+    pub cancelled_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationsPausedSet {
+    pub project_id: u64,
+    pub creator: Address,
+    pub paused: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositIncrementSet {
+    pub project_id: u64,
+    pub token: Address,
+    pub increment: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxPerDonorSet {
+    pub project_id: u64,
+    pub cap: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingDurationSet {
+    pub project_id: u64,
+    pub duration: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestedClaimed {
+    pub project_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub elapsed: u64,
+    pub duration: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectPaused {
+    pub project_id: u64,
+    pub admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectUnpaused {
+    pub project_id: u64,
+    pub admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundsReleased {
+    pub project_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarmarkedDonation {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub earmark: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseFailed {
+    pub project_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleAdded {
+    pub project_id: u64,
+    pub oracle: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleRemoved {
+    pub project_id: u64,
+    pub oracle: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OraclePubKeySet {
+    pub admin: Address,
+    pub pubkey: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundsClaimed {
+    pub project_id: u64,
+    pub creator: Address,
+    /// Number of tokens with a non-zero payout to the creator.
+    pub tokens_released: u32,
+    /// Amount sent to the creator per token, after fees.
+    pub released: Vec<TokenBalance>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleVoted {
+    pub project_id: u64,
+    pub oracle: Address,
+    pub index: u32,
+    pub voter_count: u32,
+    pub threshold: u32,
+}
+
+// ── Emission helpers ──────────────────────────────────────────────────────────
+
+pub fn emit_project_created(
+    env: &Env,
+    project_id: u64,
+    creator: Address,
+    token: Address,
+    goal: i128,
+    hash_algo: Symbol,
+    category: Symbol,
+    display_goal_usd: i128,
+) {
+    let topics = (PROJECT_CREATED, project_id, category);
+    let data = ProjectCreated {
+        project_id,
+        creator,
+        token,
+        goal,
+        hash_algo,
+        display_goal_usd,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_project_funded(
+    env: &Env,
+    project_id: u64,
+    category: Symbol,
+    donator: Address,
+    amount: i128,
+) {
+    let topics = (PROJECT_FUNDED, project_id, category);
+    let data = ProjectFunded {
+        project_id,
+        donator,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_donation_received(
+    env: &Env,
+    project_id: u64,
+    donator: Address,
+    token: Address,
+    amount: i128,
+    memo: String,
+    seq: u32,
+) {
+    let topics = (DONATION_RECEIVED, project_id);
+    let data = DonationReceived {
+        project_id,
+        donator,
+        token,
+        amount,
+        memo,
+        seq,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_anon_deposited(
+    env: &Env,
+    project_id: u64,
+    commitment: BytesN<32>,
+    token: Address,
+    amount: i128,
+) {
+    let topics = (ANON_DEPOSITED, project_id);
+    let data = AnonDeposited {
+        project_id,
+        commitment,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_anon_refunded(
+    env: &Env,
+    project_id: u64,
+    commitment: BytesN<32>,
+    recipient: Address,
+    amount: i128,
+) {
+    let topics = (ANON_REFUNDED, project_id);
+    let data = AnonRefunded {
+        project_id,
+        commitment,
+        recipient,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_status_changed(
+    env: &Env,
+    project_id: u64,
+    old_status: ProjectStatus,
+    new_status: ProjectStatus,
+) {
+    let topics = (STATUS_CHANGED, project_id);
+    let data = StatusChanged {
+        project_id,
+        old_status,
+        new_status,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_project_active(env: &Env, project_id: u64) {
+    let topics = (PROJECT_ACTIVE, project_id);
+    let data = ProjectActive { project_id };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_goal_reached_with_deadline(env: &Env, project_id: u64, deadline: u64) {
+    let topics = (GOAL_REACHED_WITH_DEADLINE, project_id);
+    let data = GoalReachedWithDeadline {
+        project_id,
+        deadline,
+        remaining: deadline.saturating_sub(env.ledger().timestamp()),
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_ready_for_verification(env: &Env, project_id: u64) {
+    let topics = (READY_FOR_VERIFICATION, project_id);
+    let data = ReadyForVerification { project_id };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_funding_progress(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    balance: i128,
+    goal: i128,
+    pct: u32,
+) {
+    let topics = (FUNDING_PROGRESS, project_id);
+    let data = FundingProgress {
+        project_id,
+        token,
+        balance,
+        goal,
+        pct,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_token_goal_set(env: &Env, project_id: u64, token: Address, goal: i128) {
+    let topics = (TOKEN_GOAL_SET, project_id);
+    let data = TokenGoalSet {
+        project_id,
+        token,
+        goal,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_token_removed(env: &Env, project_id: u64, token: Address) {
+    let topics = (TOKEN_REMOVED, project_id);
+    let data = TokenRemoved { project_id, token };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_token_migrated(
+    env: &Env,
+    project_id: u64,
+    old_token: Address,
+    new_token: Address,
+    balance: i128,
+) {
+    let topics = (TOKEN_MIGRATED, project_id);
+    let data = TokenMigrated {
+        project_id,
+        old_token,
+        new_token,
+        balance,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_proof_matched(env: &Env, project_id: u64, index: u32, oracle: Address, remaining: u32) {
+    let topics = (PROOF_MATCHED, project_id);
+    let data = ProofMatched {
+        project_id,
+        index,
+        oracle,
+        remaining,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_required_proofs_set(env: &Env, project_id: u64, count: u32) {
+    let topics = (REQUIRED_PROOFS_SET, project_id);
+    let data = RequiredProofsSet { project_id, count };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_milestone_acknowledged(
+    env: &Env,
+    project_id: u64,
+    milestone_index: u32,
+    oracle: Address,
+    proof_hash: BytesN<32>,
+) {
+    let topics = (MILESTONE_ACKNOWLEDGED, project_id);
+    let data = MilestoneAcknowledged {
+        project_id,
+        milestone_index,
+        oracle,
+        proof_hash,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_project_verified(
+    env: &Env,
+    project_id: u64,
+    oracle: Address,
+    proof_hash: BytesN<32>,
+    hash_algo: Symbol,
+) {
+    let topics = (PROJECT_VERIFIED, project_id);
+    let data = ProjectVerified {
+        project_id,
+        oracle,
+        proof_hash,
+        hash_algo,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_verification_challenged(
+    env: &Env,
+    project_id: u64,
+    auditor: Address,
+    oracle: Option<Address>,
+    verified_at: u64,
+) {
+    let topics = (VERIFICATION_CHALLENGED, project_id);
+    let data = VerificationChallenged {
+        project_id,
+        auditor,
+        oracle,
+        verified_at,
+    };
+    env.events().publish(topics, data);
+}
+
+/// The hash itself is left out, matching its redaction in `get_project`.
+pub fn emit_proof_hash_set(env: &Env, project_id: u64, by: Address) {
+    let topics = (PROOF_HASH_SET, project_id);
+    let data = ProofHashSet { project_id, by };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_hash_algo_updated(env: &Env, project_id: u64, hash_algo: Symbol) {
+    let topics = (HASH_ALGO_UPDATED, project_id);
+    let data = HashAlgoUpdated {
+        project_id,
+        hash_algo,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_pledge_recorded(env: &Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+    let topics = (PLEDGE_RECORDED, project_id);
+    let data = PledgeRecorded {
+        project_id,
+        donator,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_pledge_fulfilled(env: &Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+    let topics = (PLEDGE_FULFILLED, project_id);
+    let data = PledgeFulfilled {
+        project_id,
+        donator,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_category_updated(env: &Env, project_id: u64, category: Symbol) {
+    let topics = (CATEGORY_UPDATED, project_id, category.clone());
+    let data = CategoryUpdated {
+        project_id,
+        category,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_allowed_categories_set(env: &Env, admin: Address, categories: Vec<Symbol>) {
+    let topics = (ALLOWED_CATEGORIES_SET,);
+    let data = AllowedCategoriesSet { admin, categories };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_visibility_updated(env: &Env, project_id: u64, visibility: Visibility) {
+    let topics = (VISIBILITY_UPDATED, project_id);
+    let data = VisibilityUpdated {
+        project_id,
+        visibility,
+    };
+    env.events().publish(topics, data);
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct OracleAdded {
-    pub project_id: u64,
-    pub oracle: Address,
+pub fn emit_display_goal_set(env: &Env, project_id: u64, display_goal_usd: i128) {
+    let topics = (DISPLAY_GOAL_SET, project_id);
+    let data = DisplayGoalSet {
+        project_id,
+        display_goal_usd,
+    };
+    env.events().publish(topics, data);
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct OracleRemoved {
-    pub project_id: u64,
-    pub oracle: Address,
+pub fn emit_soft_cap_set(env: &Env, project_id: u64, soft_cap: i128) {
+    let topics = (SOFT_CAP_SET, project_id);
+    let data = SoftCapSet {
+        project_id,
+        soft_cap,
+    };
+    env.events().publish(topics, data);
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct FundsClaimed {
-    pub project_id: u64,
-    pub creator: Address,
+pub fn emit_tokens_locked(env: &Env, project_id: u64) {
+    let topics = (TOKENS_LOCKED, project_id);
+    let data = TokensLocked { project_id };
+    env.events().publish(topics, data);
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct OracleVoted {
-    pub project_id: u64,
-    pub oracle: Address,
-    pub index: u32,
-    pub voter_count: u32,
-    pub threshold: u32,
+pub fn emit_tags_updated(env: &Env, project_id: u64, tags: Vec<Symbol>) {
+    let topics = (TAGS_UPDATED, project_id);
+    let data = TagsUpdated { project_id, tags };
+    env.events().publish(topics, data);
 }
 
-// ── Emission helpers ──────────────────────────────────────────────────────────
+pub fn emit_confirmation_lock_set(env: &Env, project_id: u64, lock: bool) {
+    let topics = (CONFIRMATION_LOCK_SET, project_id);
+    let data = ConfirmationLockSet { project_id, lock };
+    env.events().publish(topics, data);
+}
 
-pub fn emit_project_created(
-    env: &Env,
-    project_id: u64,
-    creator: Address,
-    token: Address,
-    goal: i128,
-) {
-    let topics = (symbol_short!("proj_cr"), project_id);
-    let data = ProjectCreated {
-        project_id,
-        creator,
-        token,
-        goal,
-    };
+pub fn emit_institutions_only_set(env: &Env, project_id: u64, only: bool) {
+    let topics = (INSTITUTIONS_ONLY_SET, project_id);
+    let data = InstitutionsOnlySet { project_id, only };
     env.events().publish(topics, data);
 }
 
-pub fn emit_project_funded(env: &Env, project_id: u64, donator: Address, amount: i128) {
-    let topics = (symbol_short!("proj_fnd"), project_id);
-    let data = ProjectFunded {
+pub fn emit_goal_corrected(env: &Env, project_id: u64, old: i128, new: i128, reason: Symbol) {
+    let topics = (GOAL_CORRECTED, project_id);
+    let data = GoalCorrected {
         project_id,
-        donator,
-        amount,
+        old,
+        new,
+        reason,
     };
     env.events().publish(topics, data);
 }
 
-pub fn emit_project_active(env: &Env, project_id: u64) {
-    let topics = (symbol_short!("proj_act"), project_id);
-    let data = ProjectActive { project_id };
+pub fn emit_funding_model_updated(env: &Env, project_id: u64, model: FundingModel) {
+    let topics = (FUNDING_MODEL_UPDATED, project_id);
+    let data = FundingModelUpdated { project_id, model };
     env.events().publish(topics, data);
 }
 
-pub fn emit_project_verified(env: &Env, project_id: u64, oracle: Address, proof_hash: BytesN<32>) {
-    let topics = (symbol_short!("proj_ver"), project_id);
-    let data = ProjectVerified {
+pub fn emit_overshoot_policy_updated(env: &Env, project_id: u64, policy: OvershootPolicy) {
+    let topics = (OVERSHOOT_POLICY_UPDATED, project_id);
+    let data = OvershootPolicyUpdated { project_id, policy };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_release_to_updated(env: &Env, project_id: u64, release_to: Address) {
+    let topics = (RELEASE_TO_UPDATED, project_id);
+    let data = ReleaseToUpdated {
         project_id,
-        oracle,
-        proof_hash,
+        release_to,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_project_verified_signed(
+    env: &Env,
+    project_id: u64,
+    oracle_pubkey: BytesN<32>,
+    message_hash: BytesN<32>,
+) {
+    let topics = (PROJECT_VERIFIED_SIGNED, project_id);
+    let data = ProjectVerifiedSigned {
+        project_id,
+        oracle_pubkey,
+        message_hash,
     };
     env.events().publish(topics, data);
 }
 
 pub fn emit_project_expired(env: &Env, project_id: u64, deadline: u64) {
-    let topics = (symbol_short!("proj_exp"), project_id);
+    let topics = (PROJECT_EXPIRED, project_id);
     let data = ProjectExpired {
         project_id,
         deadline,
@@ -236,8 +1391,18 @@ pub fn emit_project_expired(env: &Env, project_id: u64, deadline: u64) {
     env.events().publish(topics, data);
 }
 
+pub fn emit_soft_cap_met(env: &Env, project_id: u64, soft_cap: i128, balance: i128) {
+    let topics = (SOFT_CAP_MET, project_id);
+    let data = SoftCapMet {
+        project_id,
+        soft_cap,
+        balance,
+    };
+    env.events().publish(topics, data);
+}
+
 pub fn emit_project_cancelled(env: &Env, project_id: u64, cancelled_by: Address) {
-    let topics = (symbol_short!("proj_can"), project_id);
+    let topics = (PROJECT_CANCELLED, project_id);
     let data = ProjectCancelled {
         project_id,
         cancelled_by,
@@ -246,29 +1411,113 @@ pub fn emit_project_cancelled(env: &Env, project_id: u64, cancelled_by: Address)
 }
 
 pub fn emit_project_paused(env: &Env, project_id: u64, admin: Address) {
-    let topics = (symbol_short!("prj_psd"), project_id);
+    let topics = (PROJECT_PAUSED, project_id);
     let data = ProjectPaused { project_id, admin };
     env.events().publish(topics, data);
 }
 
 pub fn emit_project_unpaused(env: &Env, project_id: u64, admin: Address) {
-    let topics = (symbol_short!("prj_unp"), project_id);
+    let topics = (PROJECT_UNPAUSED, project_id);
     let data = ProjectUnpaused { project_id, admin };
     env.events().publish(topics, data);
 }
 
-pub fn emit_funds_released(env: &Env, project_id: u64, token: Address, amount: i128) {
-    let topics = (symbol_short!("fnd_rel"), project_id);
+pub fn emit_donations_paused_set(env: &Env, project_id: u64, creator: Address, paused: bool) {
+    let topics = (DONATIONS_PAUSED_SET, project_id);
+    let data = DonationsPausedSet {
+        project_id,
+        creator,
+        paused,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_deposit_increment_set(env: &Env, project_id: u64, token: Address, increment: i128) {
+    let topics = (DEPOSIT_INCREMENT_SET, project_id);
+    let data = DepositIncrementSet {
+        project_id,
+        token,
+        increment,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_max_per_donor_set(env: &Env, project_id: u64, cap: i128) {
+    let topics = (MAX_PER_DONOR_SET, project_id);
+    let data = MaxPerDonorSet { project_id, cap };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_vesting_duration_set(env: &Env, project_id: u64, duration: u64) {
+    let topics = (VESTING_DURATION_SET, project_id);
+    let data = VestingDurationSet {
+        project_id,
+        duration,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_vested_claimed(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    amount: i128,
+    elapsed: u64,
+    duration: u64,
+) {
+    let topics = (VESTED_CLAIMED, project_id);
+    let data = VestedClaimed {
+        project_id,
+        token,
+        amount,
+        elapsed,
+        duration,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_funds_released(env: &Env, project_id: u64, token: Address, amount: i128, to: Address) {
+    let topics = (FUNDS_RELEASED, project_id);
     let data = FundsReleased {
         project_id,
         token,
         amount,
+        to,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_earmarked(
+    env: &Env,
+    project_id: u64,
+    donator: Address,
+    token: Address,
+    amount: i128,
+    earmark: Symbol,
+) {
+    let topics = (EARMARKED, project_id, earmark.clone());
+    let data = EarmarkedDonation {
+        project_id,
+        donator,
+        token,
+        amount,
+        earmark,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_release_failed(env: &Env, project_id: u64, token: Address, amount: i128) {
+    let topics = (RELEASE_FAILED, project_id);
+    let data = ReleaseFailed {
+        project_id,
+        token,
+        amount,
     };
     env.events().publish(topics, data);
 }
 
 pub fn emit_refunded(env: &Env, project_id: u64, donator: Address, amount: i128) {
-    let topics = (symbol_short!("proj_ref"), project_id);
+    let topics = (REFUNDED, project_id);
     let data = Refunded {
         project_id,
         donator,
@@ -278,7 +1527,7 @@ pub fn emit_refunded(env: &Env, project_id: u64, donator: Address, amount: i128)
 }
 
 pub fn emit_deadline_extended(env: &Env, project_id: u64, old_deadline: u64, new_deadline: u64) {
-    let topics = (symbol_short!("ext_dead"), project_id);
+    let topics = (DEADLINE_EXTENDED, project_id);
     env.events().publish(
         topics,
         DeadlineExtended {
@@ -289,12 +1538,23 @@ pub fn emit_deadline_extended(env: &Env, project_id: u64, old_deadline: u64, new
     );
 }
 
+pub fn emit_deposit_deadline_set(env: &Env, project_id: u64, deposit_deadline: u64) {
+    let topics = (DEPOSIT_DEADLINE_SET, project_id);
+    env.events().publish(
+        topics,
+        DepositDeadlineSet {
+            project_id,
+            deposit_deadline,
+        },
+    );
+}
+
 pub fn emit_protocol_config_updated(
     env: &Env,
     old_config: Option<ProtocolConfig>,
     new_config: ProtocolConfig,
 ) {
-    let topics = (symbol_short!("cfg_upd"),);
+    let topics = (PROTOCOL_CONFIG_UPDATED,);
     let data = ProtocolConfigUpdated {
         old_fee_recipient: old_config.as_ref().map(|cfg| cfg.fee_recipient.clone()),
         old_fee_bps: old_config.as_ref().map_or(0, |cfg| cfg.fee_bps),
@@ -304,6 +1564,223 @@ pub fn emit_protocol_config_updated(
     env.events().publish(topics, data);
 }
 
+pub fn emit_register_cooldown_updated(env: &Env, admin: Address, cooldown: u64) {
+    let topics = (REGISTER_COOLDOWN_UPDATED,);
+    let data = RegisterCooldownUpdated { admin, cooldown };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_self_deposit_delay_updated(env: &Env, admin: Address, delay: u64) {
+    let topics = (SELF_DEPOSIT_DELAY_UPDATED,);
+    let data = SelfDepositDelayUpdated { admin, delay };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_max_deadline_horizon_updated(env: &Env, admin: Address, horizon: u64) {
+    let topics = (MAX_DEADLINE_HORIZON_UPDATED,);
+    let data = MaxDeadlineHorizonUpdated { admin, horizon };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_token_approval_set(env: &Env, admin: Address, token: Address, approved: bool) {
+    let topics = (TOKEN_APPROVAL_SET,);
+    let data = TokenApprovalSet {
+        admin,
+        token,
+        approved,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_institution_set(env: &Env, admin: Address, account: Address, institution: bool) {
+    let topics = (INSTITUTION_SET,);
+    let data = InstitutionSet {
+        admin,
+        account,
+        institution,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_token_gate_set(env: &Env, admin: Address, enabled: bool) {
+    let topics = (TOKEN_GATE_SET,);
+    let data = TokenGateSet { admin, enabled };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_max_tokens_updated(env: &Env, admin: Address, max: u32) {
+    let topics = (MAX_TOKENS_UPDATED,);
+    let data = MaxTokensUpdated { admin, max };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_admins_can_register_set(env: &Env, admin: Address, allowed: bool) {
+    let topics = (ADMINS_CAN_REGISTER_SET,);
+    let data = AdminsCanRegisterSet { admin, allowed };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_creator_role_required_set(env: &Env, admin: Address, required: bool) {
+    let topics = (CREATOR_ROLE_REQUIRED_SET,);
+    let data = CreatorRoleRequiredSet { admin, required };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_max_deposit_updated(env: &Env, admin: Address, max: i128) {
+    let topics = (MAX_DEPOSIT_UPDATED,);
+    let data = MaxDepositUpdated { admin, max };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_deposit_swapped(
+    env: &Env,
+    project_id: u64,
+    donator: Address,
+    from_token: Address,
+    from_amount: i128,
+    to_token: Address,
+    received: i128,
+) {
+    let topics = (DEPOSIT_SWAPPED, project_id);
+    let data = DepositSwapped {
+        project_id,
+        donator,
+        from_token,
+        from_amount,
+        to_token,
+        received,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_swap_router_set(env: &Env, admin: Address, router: Address) {
+    let topics = (SWAP_ROUTER_SET,);
+    let data = SwapRouterSet { admin, router };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_global_min_deposit_updated(env: &Env, admin: Address, min: i128) {
+    let topics = (GLOBAL_MIN_DEPOSIT_UPDATED,);
+    let data = GlobalMinDepositUpdated { admin, min };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_min_deposit_set(env: &Env, project_id: u64, min: i128) {
+    let topics = (MIN_DEPOSIT_SET, project_id);
+    let data = MinDepositSet { project_id, min };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_min_oracles_updated(env: &Env, admin: Address, min: u32) {
+    let topics = (MIN_ORACLES_UPDATED,);
+    let data = MinOraclesUpdated { admin, min };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_oracle_weight_updated(env: &Env, admin: Address, oracle: Address, weight: u32) {
+    let topics = (ORACLE_WEIGHT_UPDATED,);
+    let data = OracleWeightUpdated {
+        admin,
+        oracle,
+        weight,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_consensus_threshold_updated(env: &Env, admin: Address, threshold: u32) {
+    let topics = (CONSENSUS_THRESHOLD_UPDATED,);
+    let data = ConsensusThresholdUpdated { admin, threshold };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_confirmation_gap_updated(env: &Env, admin: Address, gap: u64) {
+    let topics = (CONFIRMATION_GAP_UPDATED,);
+    let data = ConfirmationGapUpdated { admin, gap };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_rounding_mode_updated(env: &Env, admin: Address, mode: RoundingMode) {
+    let topics = (ROUNDING_MODE_UPDATED,);
+    let data = RoundingModeUpdated { admin, mode };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_fee_tiers_updated(env: &Env, admin: Address, tiers: Vec<(i128, u32)>) {
+    let topics = (FEE_TIERS_UPDATED,);
+    let data = FeeTiersUpdated { admin, tiers };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_oracle_reward_updated(env: &Env, admin: Address, bps: u32) {
+    let topics = (ORACLE_REWARD_UPDATED,);
+    let data = OracleRewardUpdated { admin, bps };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_oracle_rewarded(env: &Env, project_id: u64, oracle: Address, token: Address, amount: i128) {
+    let topics = (ORACLE_REWARDED, project_id);
+    let data = OracleRewarded {
+        project_id,
+        oracle,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_refund_window_updated(env: &Env, admin: Address, window: u64) {
+    let topics = (REFUND_WINDOW_UPDATED,);
+    let data = RefundWindowUpdated { admin, window };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_challenge_period_updated(env: &Env, admin: Address, period: u64) {
+    let topics = (CHALLENGE_PERIOD_UPDATED,);
+    let data = ChallengePeriodUpdated { admin, period };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_claim_timeout_updated(env: &Env, admin: Address, timeout: u64) {
+    let topics = (CLAIM_TIMEOUT_UPDATED,);
+    let data = ClaimTimeoutUpdated { admin, timeout };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_unclaimed_reclaimed(env: &Env, project_id: u64, verified_at: u64) {
+    let topics = (UNCLAIMED_RECLAIMED, project_id);
+    let data = UnclaimedReclaimed {
+        project_id,
+        verified_at,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_snapshot_taken(env: &Env, project_id: u64, index: u32, ledger: u32) {
+    let topics = (SNAPSHOT_TAKEN, project_id);
+    let data = SnapshotTaken {
+        project_id,
+        index,
+        ledger,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_unclaimed_swept(env: &Env, project_id: u64, token: Address, amount: i128) {
+    let topics = (UNCLAIMED_SWEPT, project_id);
+    let data = UnclaimedSwept {
+        project_id,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_treasury_withdrawn(env: &Env, token: Address, to: Address, amount: i128) {
+    let topics = (TREASURY_WITHDRAWN, token.clone());
+    let data = TreasuryWithdrawn { token, to, amount };
+    env.events().publish(topics, data);
+}
+
 pub fn emit_fee_deducted(
     env: &Env,
     project_id: u64,
@@ -311,7 +1788,7 @@ pub fn emit_fee_deducted(
     amount: i128,
     recipient: Address,
 ) {
-    let topics = (symbol_short!("fee_ded"), project_id, token.clone());
+    let topics = (FEE_DEDUCTED, project_id, token.clone());
     let data = FeeDeducted {
         project_id,
         token,
@@ -322,7 +1799,7 @@ pub fn emit_fee_deducted(
 }
 
 pub fn emit_whitelist_added(env: &Env, project_id: u64, address: Address) {
-    let topics = (symbol_short!("whl_add"), project_id);
+    let topics = (WHITELIST_ADDED, project_id);
     let data = WhitelistAdded {
         project_id,
         address,
@@ -331,7 +1808,7 @@ pub fn emit_whitelist_added(env: &Env, project_id: u64, address: Address) {
 }
 
 pub fn emit_whitelist_removed(env: &Env, project_id: u64, address: Address) {
-    let topics = (symbol_short!("whl_rem"), project_id);
+    let topics = (WHITELIST_REMOVED, project_id);
     let data = WhitelistRemoved {
         project_id,
         address,
@@ -346,7 +1823,7 @@ pub fn emit_expired_funds_reclaimed(
     token: Address,
     amount: i128,
 ) {
-    let topics = (symbol_short!("exp_recl"), project_id);
+    let topics = (EXPIRED_FUNDS_RECLAIMED, project_id);
     let data = ExpiredFundsReclaimed {
         project_id,
         creator,
@@ -357,22 +1834,35 @@ pub fn emit_expired_funds_reclaimed(
 }
 
 pub fn emit_protocol_paused(env: &Env, admin: Address) {
-    let topics = (symbol_short!("prot_psd"),);
+    let topics = (PROTOCOL_PAUSED,);
     let data = ProtocolPaused { admin };
     env.events().publish(topics, data);
 }
 
 pub fn emit_protocol_unpaused(env: &Env, admin: Address) {
-    let topics = (symbol_short!("prot_unp"),);
+    let topics = (PROTOCOL_UNPAUSED,);
     let data = ProtocolUnpaused { admin };
     env.events().publish(topics, data);
 }
 
-pub fn emit_funds_claimed(env: &Env, project_id: u64, creator: Address) {
-    let topics = (symbol_short!("fnd_clm"), project_id);
+pub fn emit_decommissioned(env: &Env, super_admin: Address) {
+    let topics = (DECOMMISSIONED,);
+    let data = Decommissioned { super_admin };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_funds_claimed(
+    env: &Env,
+    project_id: u64,
+    creator: Address,
+    released: Vec<TokenBalance>,
+) {
+    let topics = (FUNDS_CLAIMED, project_id);
     let data = FundsClaimed {
         project_id,
         creator,
+        tokens_released: released.len(),
+        released,
     };
     env.events().publish(topics, data);
 }
@@ -385,7 +1875,7 @@ pub fn emit_oracle_voted(
     voter_count: u32,
     threshold: u32,
 ) {
-    let topics = (symbol_short!("ora_voted"), project_id);
+    let topics = (ORACLE_VOTED, project_id);
     let data = OracleVoted {
         project_id,
         oracle,
@@ -397,13 +1887,19 @@ pub fn emit_oracle_voted(
 }
 
 pub fn emit_oracle_added(env: &Env, project_id: u64, oracle: Address) {
-    let topics = (symbol_short!("ora_add"), project_id);
+    let topics = (ORACLE_ADDED, project_id);
     let data = OracleAdded { project_id, oracle };
     env.events().publish(topics, data);
 }
 
+pub fn emit_oracle_pubkey_set(env: &Env, admin: Address, pubkey: BytesN<32>) {
+    let topics = (ORACLE_PUBKEY_SET,);
+    let data = OraclePubKeySet { admin, pubkey };
+    env.events().publish(topics, data);
+}
+
 pub fn emit_oracle_removed(env: &Env, project_id: u64, oracle: Address) {
-    let topics = (symbol_short!("ora_rem"), project_id);
+    let topics = (ORACLE_REMOVED, project_id);
     let data = OracleRemoved { project_id, oracle };
     env.events().publish(topics, data);
 }
@@ -414,10 +1910,19 @@ pub fn emit_milestone_verified(env: &Env, project_id: u64, milestone_index: u32,
 }
 
 pub fn emit_protocol_upgraded(env: &Env, caller: Address, new_wasm_hash: BytesN<32>) {
-    let topics = (symbol_short!("prot_upg"),);
+    let topics = (PROTOCOL_UPGRADED,);
     let data = ProtocolUpgraded {
         caller,
         new_wasm_hash,
     };
     env.events().publish(topics, data);
 }
+
+pub fn emit_version_set(env: &Env, old_version: u32, new_version: u32) {
+    let topics = (VERSION_SET,);
+    let data = VersionSet {
+        old_version,
+        new_version,
+    };
+    env.events().publish(topics, data);
+}
@@ -30,6 +30,14 @@ fn create_token<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
     token::Client::new(env, &addr.address())
 }
 
+/// `get_project` with the redacted `proof_hash` filled back in via the
+/// admin-only `get_proof_hash`, for invariant checks against the original.
+fn load_unredacted(client: &PifpProtocolClient, admin: &Address, id: u64) -> crate::Project {
+    let mut project = client.get_project(&id);
+    project.proof_hash = client.get_proof_hash(admin, &id);
+    project
+}
+
 fn dummy_metadata_uri(env: &Env) -> Bytes {
     Bytes::from_slice(
         env,
@@ -443,7 +451,7 @@ proptest! {
         sac.mint(&donator, &amount);
         client.deposit(&original.id, &donator, &token_client.address, &amount);
 
-        let after = client.get_project(&original.id);
+        let after = load_unredacted(&client, &admin, original.id);
         check_inv10_config_immutable(&original, &after);
     }
 
@@ -481,7 +489,7 @@ proptest! {
         client.set_oracle(&admin, &oracle);
         client.verify_proof(&oracle, &original.id, &proof_hash);
 
-        let after = client.get_project(&original.id);
+        let after = load_unredacted(&client, &admin, original.id);
         check_inv10_config_immutable(&original, &after);
     }
 }
@@ -541,7 +549,7 @@ proptest! {
 
             check_inv5_deposit_sums(before_balance, after_balance, *amount);
 
-            let after = client.get_project(&project.id);
+            let after = load_unredacted(&client, &admin, project.id);
             check_inv10_config_immutable(&project, &after);
             check_all_project_invariants(&env, &after);
 
@@ -563,7 +571,7 @@ proptest! {
 
         client.claim_funds(&project.id);
 
-        let final_project = client.get_project(&project.id);
+        let final_project = load_unredacted(&client, &admin, project.id);
         check_inv7_status_transition(&ProjectStatus::Funding, &final_project.status);
         check_inv10_config_immutable(&project, &final_project);
         assert_eq!(final_project.status, ProjectStatus::Completed);
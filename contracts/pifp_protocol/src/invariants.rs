@@ -14,10 +14,10 @@ pub fn assert_balance_non_negative(project: &Project) {
     );
 }
 
-/// INV-2: Project goal must always be positive.
+/// INV-2: Project goal must always be positive (zero allowed for open-ended projects).
 pub fn assert_goal_positive(project: &Project) {
     assert!(
-        project.goal > 0,
+        project.goal > 0 || (project.open_ended && project.goal == 0),
         "INV-2 violated: project {} has non-positive goal ({})",
         project.id,
         project.goal
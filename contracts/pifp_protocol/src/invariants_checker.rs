@@ -14,10 +14,10 @@ pub fn check_inv1_balance_non_negative(env: &Env, project_id: u64, tokens: &Vec<
     let _ = (env, project_id, tokens);
 }
 
-/// INV-2: project.goal > 0 for all projects.
+/// INV-2: project.goal > 0 for all projects (== 0 allowed when open-ended).
 pub fn check_inv2_goal_positive(project: &Project) {
     assert!(
-        project.goal > 0,
+        project.goal > 0 || (project.open_ended && project.goal == 0),
         "INV-2 violated: project {} has non-positive goal ({})",
         project.id,
         project.goal
@@ -79,11 +79,13 @@ pub fn check_inv7_status_transition(from: &ProjectStatus, to: &ProjectStatus) {
             | (ProjectStatus::Funding, ProjectStatus::Verified)
             | (ProjectStatus::Funding, ProjectStatus::Completed)
             | (ProjectStatus::Funding, ProjectStatus::Expired)
+            | (ProjectStatus::Funding, ProjectStatus::Cancelled)
             | (ProjectStatus::Active, ProjectStatus::Verified)
             | (ProjectStatus::Active, ProjectStatus::Completed)
             | (ProjectStatus::Active, ProjectStatus::Expired)
             | (ProjectStatus::Active, ProjectStatus::Cancelled)
             | (ProjectStatus::Verified, ProjectStatus::Completed)
+            | (ProjectStatus::Verified, ProjectStatus::Cancelled)
     );
 
     assert!(
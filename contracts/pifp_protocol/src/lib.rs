@@ -11,7 +11,7 @@
 //! | Registration | [`PifpProtocol::register_project`]                      |
 //! | Funding      | [`PifpProtocol::deposit`]                               |
 //! | Donor safety | [`PifpProtocol::refund`]                                |
-//! | Verification | [`PifpProtocol::verify_proof`]                          |
+//! | Verification | [`PifpProtocol::verify_proof`], `verify_and_release_signed` |
 //! | Claiming     | [`PifpProtocol::claim_funds`]                           |
 //! | Queries      | `get_project`, `get_project_balances`, `role_of`, etc.  |
 =======
@@ -37,14 +37,29 @@
 #![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, token, Address, Bytes, BytesN, Env, Vec,
+    contract, contractimpl, panic_with_error, symbol_short, token,
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
 };
 
-/// Refund window: 6 months after a project enters a terminal refundable state.
+/// Version of the contract code. Bump whenever a release needs `migrate` to
+/// rewrite stored data.
+pub const CONTRACT_VERSION: u32 = 1;
+
+/// Default refund window: 6 months after a project enters a terminal
+/// refundable state. Admins may change it via `set_refund_window`.
 pub const REFUND_WINDOW: u64 = 6 * 30 * 24 * 60 * 60;
 
-/// Grace period: 24 hours (in seconds) between proof verification and fund
-/// release, allowing community disputes.
+/// Default number of tokens a project may accept. Admins may change it via
+/// `set_max_tokens_per_project`, up to `MAX_TOKENS_CEILING`.
+pub const DEFAULT_MAX_TOKENS: u32 = 10;
+
+/// Hard ceiling on the per-project token cap, bounding per-token loops.
+const MAX_TOKENS_CEILING: u32 = 50;
+
+/// Default grace period: 24 hours (in seconds) between proof verification
+/// and fund release, during which auditors may `challenge_verification`.
+/// Admins may change it via `set_challenge_period`.
 const GRACE_PERIOD: u64 = 24 * 60 * 60; // 86_400 seconds
 
 /// Maximum allowed length for a project metadata URI / CID.
@@ -53,12 +68,62 @@ const MAX_METADATA_URI_LEN: u32 = 64;
 /// Maximum number of authorized oracles per project (fits in a u32 BitSet).
 const MAX_ORACLES: u32 = 32;
 
+/// Maximum number of project allocations in a single `deposit_split`.
+const MAX_SPLIT_ALLOCATIONS: u32 = 10;
+
+/// Upper bound on a project's funding goal.
+const MAX_GOAL: i128 = 1_000_000_000_000_000_000_000_000_000_000;
+
+/// Domain tag opening every message signed for `verify_and_release_signed`.
+const SIGNED_VERIFY_DOMAIN: &[u8] = b"pifp-verify";
+
+/// Maximum number of project IDs accepted by `get_projects`.
+const MAX_BATCH_PROJECTS: u32 = 50;
+
+/// Maximum number of project IDs accepted by `batch_expire`.
+const MAX_BATCH_EXPIRE: u32 = 30;
+
+/// Maximum number of donors refunded by one `refund_batch` call.
+const MAX_REFUND_BATCH: u32 = 30;
+
+/// Maximum number of tokens accepted by `get_token_balances`.
+const MAX_BATCH_TOKENS: u32 = 20;
+
+/// Maximum length in bytes of a donation memo.
+const MAX_MEMO_LEN: u32 = 64;
+
+/// Maximum number of entries returned by one `get_donations` call.
+const MAX_DONATION_PAGE: u32 = 50;
+
+/// Maximum number of balance snapshots a project may hold.
+const MAX_SNAPSHOTS: u32 = 32;
+
+/// Number of most recent records the on-chain audit log retains.
+pub const AUDIT_LOG_SIZE: u32 = 100;
+
+/// Maximum number of entries returned by one `get_audit_log` call.
+const MAX_AUDIT_PAGE: u32 = 50;
+
+/// Minimum seconds between two `announce` calls for the same project.
+const ANNOUNCE_COOLDOWN: u64 = 60 * 60;
+
+/// Digest algorithm recorded for a project's `proof_hash` unless the creator
+/// states otherwise via `set_hash_algo`.
+pub const DEFAULT_HASH_ALGO: Symbol = symbol_short!("sha256");
+
+/// Category given to a project until the creator picks one via `set_category`.
+pub const DEFAULT_CATEGORY: Symbol = symbol_short!("general");
+
+/// Maximum number of tags a project may carry.
+pub const MAX_TAGS: u32 = 5;
+
 pub mod categories;
 pub mod errors;
 pub mod events;
 pub mod invariants_checker;
 mod milestones;
 pub mod rbac;
+pub mod router;
 mod storage;
 mod types;
 pub mod rbac;
@@ -68,49 +133,177 @@ mod fuzz_test;
 #[cfg(test)]
 mod rbac_test;
 #[cfg(test)]
+mod test_admin_cancel;
+#[cfg(test)]
 mod test;
 #[cfg(test)]
+mod test_announce;
+#[cfg(test)]
+mod test_anonymous_deposit;
+#[cfg(test)]
+mod test_attestation;
+#[cfg(test)]
+mod test_audit_log;
+#[cfg(test)]
 mod test_batch_deposit;
 #[cfg(test)]
+mod test_category;
+#[cfg(test)]
+mod test_challenge;
+#[cfg(test)]
+mod test_claim_timeout;
+#[cfg(test)]
+mod test_clone_project;
+#[cfg(test)]
+mod test_confirmation_gap;
+#[cfg(test)]
+mod test_confirmation_lock;
+#[cfg(test)]
+mod test_correct_goal;
+#[cfg(test)]
 mod test_deadline;
 #[cfg(test)]
+mod test_deadline_horizon;
+#[cfg(test)]
+mod test_creator_role;
+#[cfg(test)]
+mod test_decommission;
+#[cfg(test)]
+mod test_deposit_auth;
+#[cfg(test)]
+mod test_deposit_limit;
+#[cfg(test)]
+mod test_deposit_window;
+#[cfg(test)]
 mod test_donation_count;
 #[cfg(test)]
+mod test_donation_log;
+#[cfg(test)]
+mod test_donation_memo;
+#[cfg(test)]
+mod test_earmark;
+#[cfg(test)]
 mod test_errors;
 #[cfg(test)]
 mod test_events;
 #[cfg(test)]
 mod test_expire;
 #[cfg(test)]
+mod test_fee_on_transfer;
+#[cfg(test)]
+mod test_fee_tiers;
+#[cfg(test)]
+mod test_funding_model;
+#[cfg(test)]
+mod test_get_projects;
+#[cfg(test)]
+mod test_global_balance;
+#[cfg(test)]
 mod test_grace_period;
 #[cfg(test)]
+mod test_hash_algo;
+#[cfg(test)]
+mod test_health;
+#[cfg(test)]
+mod test_institutions;
+#[cfg(test)]
+mod test_migrate_token;
+#[cfg(test)]
+mod test_milestones;
+#[cfg(test)]
+mod test_open_ended;
+#[cfg(test)]
+mod test_oracle_reward;
+#[cfg(test)]
+mod test_oracle_weight;
+#[cfg(test)]
+mod test_project_timeline;
+#[cfg(test)]
+mod test_overshoot;
+#[cfg(test)]
+mod test_pledge;
+#[cfg(test)]
 mod test_project_pause;
 #[cfg(test)]
+mod test_proof_hash;
+#[cfg(test)]
 mod test_protocol_config;
 #[cfg(test)]
 mod test_reclaim;
 #[cfg(test)]
 mod test_reentrancy;
 #[cfg(test)]
+mod test_rounding;
+#[cfg(test)]
+mod test_release_failed;
+#[cfg(test)]
+mod test_release_to;
+#[cfg(test)]
 mod test_refund;
 #[cfg(test)]
+mod test_refund_batch;
+#[cfg(test)]
+mod test_refund_window;
+#[cfg(test)]
+mod test_register_cooldown;
+#[cfg(test)]
+mod test_register_and_deposit;
+#[cfg(test)]
+mod test_remove_token;
+#[cfg(test)]
+mod test_required_proofs;
+#[cfg(test)]
+mod test_self_deposit;
+#[cfg(test)]
+mod test_signed_verify;
+#[cfg(test)]
+mod test_simulate_release;
+#[cfg(test)]
+mod test_snapshot;
+#[cfg(test)]
+mod test_soft_cap;
+#[cfg(test)]
+mod test_split_deposit;
+#[cfg(test)]
+mod test_status_events;
+#[cfg(test)]
+mod test_swap_deposit;
+#[cfg(test)]
+mod test_tags;
+#[cfg(test)]
+mod test_token_cap;
+#[cfg(test)]
+mod test_token_goals;
+#[cfg(test)]
+mod test_token_registry;
+#[cfg(test)]
+mod test_tokens_locked;
+#[cfg(test)]
+mod test_treasury;
+#[cfg(test)]
 mod test_utils;
 #[cfg(test)]
+mod test_version;
+#[cfg(test)]
+mod test_vesting;
+#[cfg(test)]
 mod test_whitelist;
 
 <<<<<<< HEAD
 use crate::types::ProjectStatus;
 pub use errors::Error;
 pub use events::emit_funds_released;
-pub use rbac::Role;
+pub use rbac::{Permissions, Role};
 use storage::{
     clear_oracle_agreement, drain_token_balance, get_and_increment_project_id, get_protocol_config,
     is_whitelisted, load_project_pair, save_project, save_project_config, save_project_state,
     set_protocol_config,
 };
 pub use types::{
-    DepositRequest, Milestone, OracleAgreement, Project, ProjectBalances, ProjectConfig,
-    ProjectState, ProtocolConfig,
+    AuditRecord, AuditSubject, ContributionAttestation, DepositRequest, DonationRecord,
+    FundingModel, HealthStatus, Milestone, OracleAgreement, OvershootPolicy, PayoutLine, Pledge,
+    Project, ProjectBalances, ProjectConfig, ProjectState, ProjectTimeline, ProtocolConfig,
+    RoundingMode, TokenBalance, Visibility,
 };
 =======
 use storage::{get_and_increment_project_id, load_project, save_project};
@@ -139,6 +332,7 @@ pub enum Error {
     // New in RBAC integration:
     AlreadyInitialized    = 8,
     RoleNotFound          = 9,
+    ProofMismatch         = 10,
 }
 >>>>>>> origin/pr-38
 
@@ -167,6 +361,60 @@ impl PifpProtocol {
     pub fn init(env: Env, super_admin: Address) {
         super_admin.require_auth();
         rbac::init_super_admin(&env, &super_admin);
+        storage::set_contract_version(&env, CONTRACT_VERSION);
+        events::emit_version_set(&env, 0, CONTRACT_VERSION);
+    }
+
+    /// Idempotent variant of [`Self::init`] for deployment scripts that may
+    /// re-run. Returns `false` without touching state if the contract is
+    /// already initialised; otherwise runs `init` and returns `true`.
+    pub fn init_if_needed(env: Env, super_admin: Address) -> bool {
+        if rbac::get_super_admin(&env).is_some() {
+            return false;
+        }
+        Self::init(env, super_admin);
+        true
+    }
+
+    /// Reset an unused deployment so it can be `init`-ed again. Clears all
+    /// instance configuration, role labels and the SuperAdmin. Only allowed
+    /// while no project has ever been registered, so no funds or fees can be
+    /// held, and once every other role (extra SuperAdmin grants included)
+    /// has been revoked. SuperAdmin only.
+    ///
+    /// Per-address settings cannot be enumerated and carry over into the
+    /// next `init`: oracle weights, the approved token registry and the
+    /// institution registry. Reset them before decommissioning if needed.
+    pub fn decommission(env: Env, caller: Address) {
+        caller.require_auth();
+        rbac::require_super_admin(&env, &caller);
+        if storage::peek_next_project_id(&env) != 0 {
+            panic_with_error!(&env, Error::DecommissionBlocked);
+        }
+        rbac::decommission_super_admin(&env, &caller);
+        storage::clear_instance_config(&env);
+        events::emit_decommissioned(&env, caller);
+    }
+
+    /// Read-only status snapshot for monitoring. No auth, no writes.
+    pub fn health(env: Env) -> HealthStatus {
+        HealthStatus {
+            initialized: rbac::get_super_admin(&env).is_some(),
+            paused: storage::is_paused(&env),
+            project_count: storage::peek_next_project_id(&env),
+            fee_bps: get_protocol_config(&env).map(|c| c.fee_bps).unwrap_or(0),
+        }
+    }
+
+    /// Version of the code currently deployed.
+    pub fn version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
+    /// Version recorded in storage by `init` or the last `migrate`.
+    /// Lags behind [`Self::version`] after an `upgrade` until `migrate` runs.
+    pub fn get_version(env: Env) -> u32 {
+        storage::get_contract_version(&env)
     }
 
     // ─────────────────────────────────────────────────────────
@@ -185,6 +433,12 @@ impl PifpProtocol {
 >>>>>>> origin/pr-38
     pub fn grant_role(env: Env, caller: Address, target: Address, role: Role) {
         rbac::grant_role(&env, &caller, &target, role);
+        storage::push_audit_record(
+            &env,
+            &caller,
+            events::ROLE_SET,
+            AuditSubject::Account(target),
+        );
     }
 
 <<<<<<< HEAD
@@ -195,7 +449,14 @@ impl PifpProtocol {
     /// - Cannot be used to remove the SuperAdmin; use `transfer_super_admin`.
 >>>>>>> origin/pr-38
     pub fn revoke_role(env: Env, caller: Address, target: Address) {
-        rbac::revoke_role(&env, &caller, &target);
+        if rbac::revoke_role(&env, &caller, &target) {
+            storage::push_audit_record(
+                &env,
+                &caller,
+                events::ROLE_DEL,
+                AuditSubject::Account(target),
+            );
+        }
     }
 
 <<<<<<< HEAD
@@ -207,6 +468,12 @@ impl PifpProtocol {
 >>>>>>> origin/pr-38
     pub fn transfer_super_admin(env: Env, current_super_admin: Address, new_super_admin: Address) {
         rbac::transfer_super_admin(&env, &current_super_admin, &new_super_admin);
+        storage::push_audit_record(
+            &env,
+            &current_super_admin,
+            events::SUPER_ADMIN_TRANSFERRED,
+            AuditSubject::Account(new_super_admin),
+        );
     }
 
 <<<<<<< HEAD
@@ -225,6 +492,23 @@ impl PifpProtocol {
         rbac::has_role(&env, address, role)
     }
 
+    /// Set a human-friendly label for `role`, carried on `role_set` events.
+    /// Admin or above only.
+    pub fn set_role_label(env: Env, caller: Address, role: Role, label: String) {
+        caller.require_auth();
+        rbac::set_role_label(&env, &caller, &role, &label);
+    }
+
+    /// Display label of `role`; the variant name unless one was set.
+    pub fn get_role_label(env: Env, role: Role) -> String {
+        rbac::role_label(&env, &role)
+    }
+
+    /// What `address` is allowed to do under its current role.
+    pub fn get_permissions(env: Env, address: Address) -> Permissions {
+        rbac::permissions(&env, &address)
+    }
+
     // ─────────────────────────────────────────────────────────
 <<<<<<< HEAD
     // Emergency Control
@@ -234,6 +518,7 @@ impl PifpProtocol {
         caller.require_auth();
         rbac::require_admin_or_above(&env, &caller);
         storage::set_paused(&env, true);
+        storage::push_audit_record(&env, &caller, events::PROTOCOL_PAUSED, AuditSubject::Protocol);
         events::emit_protocol_paused(&env, caller);
     }
 
@@ -241,6 +526,12 @@ impl PifpProtocol {
         caller.require_auth();
         rbac::require_admin_or_above(&env, &caller);
         storage::set_paused(&env, false);
+        storage::push_audit_record(
+            &env,
+            &caller,
+            events::PROTOCOL_UNPAUSED,
+            AuditSubject::Protocol,
+        );
         events::emit_protocol_unpaused(&env, caller);
     }
 
@@ -256,6 +547,28 @@ impl PifpProtocol {
         events::emit_protocol_upgraded(&env, caller, new_wasm_hash);
     }
 
+    /// Bring stored data up to [`CONTRACT_VERSION`] after an `upgrade`.
+    ///
+    /// A no-op when storage is already current. Rejects a stored version newer
+    /// than the code, which would indicate a downgrade.
+    pub fn migrate(env: Env, caller: Address) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+
+        let stored = storage::get_contract_version(&env);
+        if stored == CONTRACT_VERSION {
+            return;
+        }
+        if stored > CONTRACT_VERSION {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        // Per-version data migrations are keyed on `stored` and go here.
+
+        storage::set_contract_version(&env, CONTRACT_VERSION);
+        events::emit_version_set(&env, stored, CONTRACT_VERSION);
+    }
+
     // ─────────────────────────────────────────────────────────
     // Oracle management
     // ─────────────────────────────────────────────────────────
@@ -312,6 +625,19 @@ impl PifpProtocol {
         rbac::grant_role(&env, &caller, &oracle, Role::Oracle);
     }
 
+    /// Register the ed25519 public key whose signatures are accepted by
+    /// `verify_and_release_signed`. Replaces any previously set key.
+    pub fn set_oracle_pubkey(env: Env, caller: Address, pubkey: BytesN<32>) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_oracle_pubkey(&env, &pubkey);
+        events::emit_oracle_pubkey_set(&env, caller, pubkey);
+    }
+
+    pub fn get_oracle_pubkey(env: Env) -> Option<BytesN<32>> {
+        storage::get_oracle_pubkey(&env)
+    }
+
     // ─────────────────────────────────────────────────────────
     // Project lifecycle
     // ─────────────────────────────────────────────────────────
@@ -338,6 +664,211 @@ impl PifpProtocol {
         categories: u32,
         authorized_oracles: Vec<Address>,
         threshold: u32,
+    ) -> Project {
+        let project = Self::register(
+            env.clone(),
+            creator,
+            accepted_tokens,
+            goal,
+            false,
+            proof_hash,
+            metadata_uri,
+            deadline,
+            is_private,
+            milestones,
+            categories,
+            authorized_oracles,
+            threshold,
+        );
+        Self::emit_created(&env, &project);
+        project
+    }
+
+    /// Register an open-donation project with no funding goal.
+    ///
+    /// The project stays in `Funding` and accepts deposits until it is
+    /// verified, cancelled, or expires. Same rules as `register_project`
+    /// otherwise.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_open_project(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        proof_hash: BytesN<32>,
+        metadata_uri: Bytes,
+        deadline: u64,
+        is_private: bool,
+        milestones: Vec<Milestone>,
+        categories: u32,
+        authorized_oracles: Vec<Address>,
+        threshold: u32,
+    ) -> Project {
+        let project = Self::register(
+            env.clone(),
+            creator,
+            accepted_tokens,
+            0,
+            true,
+            proof_hash,
+            metadata_uri,
+            deadline,
+            is_private,
+            milestones,
+            categories,
+            authorized_oracles,
+            threshold,
+        );
+        Self::emit_created(&env, &project);
+        project
+    }
+
+    /// Register a project and seed it with `seed_amount` of `seed_token`
+    /// from `creator` in one call. The seed is a regular deposit, so it is
+    /// subject to every deposit rule, including the self-deposit delay.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_and_deposit(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        goal: i128,
+        proof_hash: BytesN<32>,
+        metadata_uri: Bytes,
+        deadline: u64,
+        is_private: bool,
+        milestones: Vec<Milestone>,
+        categories: u32,
+        authorized_oracles: Vec<Address>,
+        threshold: u32,
+        seed_token: Address,
+        seed_amount: i128,
+    ) -> Project {
+        let project = Self::register(
+            env.clone(),
+            creator.clone(),
+            accepted_tokens,
+            goal,
+            false,
+            proof_hash,
+            metadata_uri,
+            deadline,
+            is_private,
+            milestones,
+            categories,
+            authorized_oracles,
+            threshold,
+        );
+        Self::emit_created(&env, &project);
+        Self::deposit_internal(env.clone(), project.id, creator, seed_token, seed_amount);
+        storage::load_project(&env, project.id)
+    }
+
+    /// Register a fresh project that copies `template_id`'s configuration.
+    ///
+    /// Accepted tokens, goal, metadata, milestones, oracles, privacy, tags,
+    /// hash algorithm and overshoot policy are carried over, as are the
+    /// deposit limits (minimum, per-donor cap, increments), token goals and
+    /// vesting duration. The whitelist is not. The clone gets a new ID,
+    /// `caller` as creator, zero balances and `Funding` status. `caller`
+    /// must pass the same role gate as `register_project`.
+    pub fn clone_project(
+        env: Env,
+        caller: Address,
+        template_id: u64,
+        new_deadline: u64,
+        new_proof_hash: BytesN<32>,
+    ) -> Project {
+        let template = storage::load_project(&env, template_id);
+        let mut project = Self::register(
+            env.clone(),
+            caller,
+            template.accepted_tokens,
+            template.goal,
+            template.open_ended,
+            new_proof_hash,
+            template.metadata_uri,
+            new_deadline,
+            template.is_private,
+            template.milestones,
+            template.categories,
+            template.authorized_oracles,
+            template.threshold,
+        );
+        project.hash_algo = template.hash_algo;
+        project.category = template.category;
+        project.required_proofs = template.required_proofs;
+        project.overshoot_policy = template.overshoot_policy;
+        project.funding_model = template.funding_model;
+        project.visibility = template.visibility;
+        project.lock_on_first_confirmation = template.lock_on_first_confirmation;
+        project.institutions_only = template.institutions_only;
+        project.display_goal_usd = template.display_goal_usd;
+        project.tags = template.tags;
+        project.soft_cap = template.soft_cap;
+        project.tokens_locked = template.tokens_locked;
+        for tag in project.tags.iter() {
+            storage::add_tag_project(&env, &tag, project.id);
+        }
+        save_project(&env, &project);
+
+        // Per-project settings kept outside `Project`.
+        let min_deposit = storage::get_min_deposit(&env, template_id);
+        if min_deposit > 0 {
+            storage::set_min_deposit(&env, project.id, min_deposit);
+        }
+        let max_per_donor = storage::get_max_per_donor(&env, template_id);
+        if max_per_donor > 0 {
+            storage::set_max_per_donor(&env, project.id, max_per_donor);
+        }
+        let vesting = storage::get_vesting_duration(&env, template_id);
+        if vesting > 0 {
+            storage::set_vesting_duration(&env, project.id, vesting);
+        }
+        for token in project.accepted_tokens.iter() {
+            let increment = storage::get_deposit_increment(&env, template_id, &token);
+            if increment > 0 {
+                storage::set_deposit_increment(&env, project.id, &token, increment);
+            }
+            let goal = storage::get_token_goal(&env, template_id, &token);
+            if goal > 0 {
+                storage::set_token_goal(&env, project.id, &token, goal);
+            }
+        }
+        Self::emit_created(&env, &project);
+        project
+    }
+
+    /// Emit `proj_cr` for a freshly registered project. Kept out of
+    /// `register` so `clone_project` can announce the copied fields.
+    fn emit_created(env: &Env, project: &Project) {
+        if let Some(token) = project.accepted_tokens.get(0) {
+            events::emit_project_created(
+                env,
+                project.id,
+                project.creator.clone(),
+                token,
+                project.goal,
+                project.hash_algo.clone(),
+                project.category.clone(),
+                project.display_goal_usd,
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn register(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        goal: i128,
+        open_ended: bool,
+        proof_hash: BytesN<32>,
+        metadata_uri: Bytes,
+        deadline: u64,
+        is_private: bool,
+        milestones: Vec<Milestone>,
+        categories: u32,
+        authorized_oracles: Vec<Address>,
+        threshold: u32,
     ) -> Project {
         Self::require_not_paused(&env);
         creator.require_auth();
@@ -356,7 +887,7 @@ impl PifpProtocol {
         if accepted_tokens.is_empty() {
             panic_with_error!(&env, Error::EmptyAcceptedTokens);
         }
-        if accepted_tokens.len() > 10 {
+        if accepted_tokens.len() > storage::get_max_tokens_per_project(&env) {
             panic_with_error!(&env, Error::TooManyTokens);
         }
         for i in 0..accepted_tokens.len() {
@@ -365,7 +896,15 @@ impl PifpProtocol {
                 panic_with_error!(&env, Error::DuplicateToken);
             }
         }
-        if goal <= 0 || goal > 1_000_000_000_000_000_000_000_000_000_000i128 {
+        if storage::is_token_gate_enabled(&env) {
+            for token in accepted_tokens.iter() {
+                if !storage::is_token_approved(&env, &token) {
+                    panic_with_error!(&env, Error::TokenNotApproved);
+                }
+            }
+        }
+        let min_goal = if open_ended { 0 } else { 1 };
+        if goal < min_goal || goal > MAX_GOAL {
             panic_with_error!(&env, Error::InvalidGoal);
         }
 
@@ -376,6 +915,11 @@ impl PifpProtocol {
         if deadline <= now || deadline > now + 157_680_000 {
             panic_with_error!(&env, Error::InvalidDeadline);
         }
+        if let Some(horizon) = storage::get_max_deadline_horizon(&env) {
+            if deadline > now.saturating_add(horizon) {
+                panic_with_error!(&env, Error::DeadlineTooFar);
+            }
+        }
 
         let oracle_count = authorized_oracles.len();
         if oracle_count > 0 && (threshold == 0 || threshold > oracle_count) {
@@ -386,7 +930,15 @@ impl PifpProtocol {
 >>>>>>> origin/pr-38
         }
 
+        Self::enforce_register_cooldown(&env, &creator);
+
         let id = get_and_increment_project_id(&env);
+        // An all-zero hash means "set later"; see `set_proof_hash`.
+        let proof_hash = if proof_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            Self::default_proof_hash(&env, id)
+        } else {
+            proof_hash
+        };
         let mut completed_milestones = Vec::new(&env);
         for _ in 0..milestones.len() {
             completed_milestones.push_back(false);
@@ -411,12 +963,29 @@ impl PifpProtocol {
             completed_milestones,
             authorized_oracles,
             threshold,
+            hash_algo: DEFAULT_HASH_ALGO,
+            deposit_deadline: deadline,
+            open_ended,
+            overshoot_policy: OvershootPolicy::Keep,
+            release_to: creator.clone(),
+            category: DEFAULT_CATEGORY,
+            created_at: now,
+            required_proofs: Vec::new(&env),
+            funding_model: FundingModel::KeepItAll,
+            visibility: Visibility::Public,
+            lock_on_first_confirmation: false,
+            institutions_only: false,
+            display_goal_usd: 0,
+            tags: Vec::new(&env),
+            soft_cap: 0,
+            tokens_locked: false,
         };
 
         save_project(&env, &project);
 <<<<<<< HEAD
-        if let Some(token) = accepted_tokens.get(0) {
-            events::emit_project_created(&env, id, creator, token, goal);
+        storage::increment_active_project_count(&env);
+        for token in accepted_tokens.iter() {
+            storage::push_token_project(&env, &token, id);
         }
         project
     }
@@ -488,59 +1057,259 @@ impl PifpProtocol {
 <<<<<<< HEAD
         let (config, mut state) = load_project_pair(&env, project_id);
         Self::require_project_not_paused(&env, &state);
+        Self::require_verifiable(&env, &config, &mut state, project_id);
+        Self::require_min_oracles(&env);
+        Self::require_goal_for_model(&env, project_id, &config);
+        Self::require_creator_role(&env, &config);
 
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            ProjectStatus::Verified | ProjectStatus::Completed => {
-                panic_with_error!(&env, Error::MilestoneAlreadyReleased)
+        // Authorize the oracle before anything is recorded for it.
+        let mut agreement = None;
+        if config.authorized_oracles.is_empty() {
+            // A single confirmation verifies, so it must carry the full weight.
+            if storage::get_oracle_weight(&env, &oracle) < storage::get_consensus_threshold(&env) {
+                panic_with_error!(&env, Error::OracleWeightTooLow);
             }
-            _ => panic_with_error!(&env, Error::InvalidTransition),
-        }
-
-        if env.ledger().timestamp() >= config.deadline {
-            state.status = ProjectStatus::Expired;
-            state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-            save_project_state(&env, project_id, &state);
-            panic_with_error!(&env, Error::ProjectExpired);
+        } else {
+            let idx = match config.authorized_oracles.first_index_of(&oracle) {
+                Some(i) => i,
+                None => panic_with_error!(&env, Error::NotAuthorized),
+            };
+            agreement = Some((storage::load_oracle_agreement(&env, project_id), 1u32 << idx));
         }
+        let new_vote = matches!(&agreement, Some((a, bit)) if (a.votes & bit) == 0);
 
-        if submitted_proof_hash != config.proof_hash {
-            panic_with_error!(&env, Error::VerificationFailed);
-        }
+        let all_proofs_in = if config.required_proofs.is_empty() {
+            if config.proof_hash == Self::default_proof_hash(&env, project_id) {
+                panic_with_error!(&env, Error::ProofHashNotSet);
+            }
+            if submitted_proof_hash != config.proof_hash {
+                panic_with_error!(&env, Error::ProofMismatch);
+            }
+            true
+        } else {
+            // A proof another oracle already matched still counts as this
+            // oracle's vote.
+            Self::match_required_proof(
+                &env,
+                &config,
+                project_id,
+                &oracle,
+                &submitted_proof_hash,
+                new_vote,
+            )
+        };
 
-        if !config.authorized_oracles.is_empty() {
-            let mut oracle_index: Option<u32> = None;
-            for (i, auth) in config.authorized_oracles.iter().enumerate() {
-                if auth == oracle {
-                    oracle_index = Some(i as u32);
-                    break;
+        if let Some((mut agreement, bit)) = agreement {
+            if new_vote {
+                let now = env.ledger().timestamp();
+                let last = storage::get_last_confirmation_at(&env, project_id);
+                if last > 0 && now < last.saturating_add(storage::get_confirmation_gap(&env)) {
+                    panic_with_error!(&env, Error::ConfirmationTooSoon);
                 }
-            }
-            let idx = oracle_index.ok_or(Error::NotAuthorized).unwrap();
-            let mut agreement = storage::load_oracle_agreement(&env, project_id);
-            let bit = 1u32 << idx;
-            if (agreement.votes & bit) == 0 {
+                storage::set_last_confirmation_at(&env, project_id, now);
                 agreement.votes |= bit;
                 agreement.voter_count += 1;
+                agreement.weight = agreement
+                    .weight
+                    .saturating_add(storage::get_oracle_weight(&env, &oracle));
             }
 
-            if agreement.voter_count < config.threshold {
+            if !all_proofs_in
+                || agreement.voter_count < config.threshold
+                || agreement.weight < storage::get_consensus_threshold(&env)
+            {
                 storage::save_oracle_agreement(&env, project_id, &agreement);
+                storage::set_confirmation_recorded(&env, project_id);
                 return;
             }
             clear_oracle_agreement(&env, project_id);
-        } else {
-            rbac::require_oracle(&env, &oracle);
+            storage::clear_last_confirmation_at(&env, project_id);
+        } else if !all_proofs_in {
+            // Verification only proceeds once the last required proof is in.
+            storage::set_confirmation_recorded(&env, project_id);
+            return;
         }
 
         invariants_checker::check_no_recursive_state(&env);
         invariants_checker::acquire_lock(&env);
 
-        state.status = ProjectStatus::Verified;
+        Self::set_status(&env, project_id, &mut state, ProjectStatus::Verified);
         state.last_proof_time = env.ledger().timestamp();
         save_project_state(&env, project_id, &state);
+        storage::set_verifier(&env, project_id, &oracle);
         invariants_checker::release_lock(&env);
-        events::emit_project_verified(&env, project_id, oracle, submitted_proof_hash);
+        events::emit_project_verified(
+            &env,
+            project_id,
+            oracle,
+            submitted_proof_hash,
+            config.hash_algo,
+        );
+    }
+
+    /// Halt the release of a `Verified` project during its challenge period
+    /// (the grace period after verification). The verification is undone:
+    /// the project returns to `Active` if its goal was reached, else
+    /// `Funding`, and oracles must verify again from scratch. Not possible
+    /// once any funds have been released. Auditor or admin-or-above only.
+    pub fn challenge_verification(env: Env, auditor: Address, project_id: u64) {
+        Self::require_not_paused(&env);
+        auditor.require_auth();
+        rbac::require_any_of(&env, &auditor, &[Role::Auditor, Role::Admin, Role::SuperAdmin]);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        // Once part of the funds left, undoing verification would let
+        // donors be refunded more than the project still holds.
+        if state.status != ProjectStatus::Verified || storage::is_funds_released(&env, project_id) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        let verified_at = state.last_proof_time;
+        let challenge_ends = verified_at.saturating_add(storage::get_challenge_period(&env));
+        if env.ledger().timestamp() >= challenge_ends {
+            panic_with_error!(&env, Error::ChallengeWindowClosed);
+        }
+
+        let status = if storage::get_goal_reached_at(&env, project_id) > 0 {
+            ProjectStatus::Active
+        } else {
+            ProjectStatus::Funding
+        };
+        Self::set_status(&env, project_id, &mut state, status);
+        state.last_proof_time = 0;
+        save_project_state(&env, project_id, &state);
+        let oracle = storage::get_verifier(&env, project_id);
+        storage::clear_verifier(&env, project_id);
+        storage::clear_confirmation_recorded(&env, project_id);
+        for index in 0..config.required_proofs.len() {
+            storage::clear_proof_matched(&env, project_id, index);
+        }
+        events::emit_verification_challenged(&env, project_id, auditor, oracle, verified_at);
+    }
+
+    /// Set how long after verification auditors may challenge it and funds
+    /// stay locked. Applies to already-verified projects too. Admin or above
+    /// only.
+    pub fn set_challenge_period(env: Env, caller: Address, period: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_challenge_period(&env, period);
+        events::emit_challenge_period_updated(&env, caller, period);
+    }
+
+    pub fn get_challenge_period(env: Env) -> u64 {
+        storage::get_challenge_period(&env)
+    }
+
+    /// Record that milestone `milestone_index` is done, checked against the
+    /// proof hash committed for that milestone at registration. Only marks
+    /// the milestone complete; no funds move and the project status is
+    /// unchanged. Oracle only.
+    pub fn acknowledge_milestone(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        milestone_index: u32,
+        proof: BytesN<32>,
+    ) {
+        Self::require_not_paused(&env);
+        oracle.require_auth();
+        rbac::require_oracle(&env, &oracle);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        Self::require_project_not_paused(&env, &state);
+        Self::require_verifiable(&env, &config, &mut state, project_id);
+        Self::require_min_oracles(&env);
+
+        if let Err(err) = milestones::verify_milestone(
+            &env,
+            &config.milestones,
+            &mut state.completed_milestones,
+            milestone_index,
+            proof.clone(),
+        ) {
+            panic_with_error!(&env, err);
+        }
+        save_project_state(&env, project_id, &state);
+        events::emit_milestone_acknowledged(&env, project_id, milestone_index, oracle, proof);
+    }
+
+    /// Verify a project using an ed25519 signature from the oracle key set via
+    /// `set_oracle_pubkey`, instead of a proof hash committed at registration.
+    ///
+    /// `message` must start with `get_signed_message_prefix(project_id)`:
+    /// a domain tag, this contract's address, the big-endian `project_id`
+    /// and the project's signed-verification nonce. A signature therefore
+    /// cannot be replayed against another deployment, another project, or
+    /// the same project after a challenge. The Stellar account of the
+    /// signing key is recorded as the verifier. Projects with required
+    /// proofs or their own `authorized_oracles` cannot be verified this way.
+    pub fn verify_and_release_signed(
+        env: Env,
+        project_id: u64,
+        signature: BytesN<64>,
+        message: Bytes,
+    ) {
+        Self::require_not_paused(&env);
+        let pubkey = match storage::get_oracle_pubkey(&env) {
+            Some(key) => key,
+            None => panic_with_error!(&env, Error::OracleKeyNotSet),
+        };
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        Self::require_project_not_paused(&env, &state);
+        Self::require_verifiable(&env, &config, &mut state, project_id);
+        Self::require_min_oracles(&env);
+        Self::require_goal_for_model(&env, project_id, &config);
+        Self::require_creator_role(&env, &config);
+        // Projects with required proofs are verified proof by proof, and
+        // projects with their own oracle set need that set's agreement.
+        if !config.required_proofs.is_empty() || !config.authorized_oracles.is_empty() {
+            panic_with_error!(&env, Error::VerificationFailed);
+        }
+
+        let prefix = Self::signed_message_prefix(&env, project_id);
+        if message.len() < prefix.len() || message.slice(0..prefix.len()) != prefix {
+            panic_with_error!(&env, Error::VerificationFailed);
+        }
+        env.crypto().ed25519_verify(&pubkey, &message, &signature);
+        // The signature is a single confirmation, so like one from an
+        // Oracle-role holder it must carry the full weight.
+        let verifier = Self::account_address(&env, &pubkey);
+        if storage::get_oracle_weight(&env, &verifier) < storage::get_consensus_threshold(&env) {
+            panic_with_error!(&env, Error::OracleWeightTooLow);
+        }
+        storage::increment_signed_nonce(&env, project_id);
+
+        Self::set_status(&env, project_id, &mut state, ProjectStatus::Verified);
+        state.last_proof_time = env.ledger().timestamp();
+        save_project_state(&env, project_id, &state);
+        storage::set_verifier(&env, project_id, &verifier);
+
+        let message_hash = env.crypto().sha256(&message).to_bytes();
+        events::emit_project_verified_signed(&env, project_id, pubkey, message_hash);
+    }
+
+    /// Bytes a message signed for `verify_and_release_signed` on
+    /// `project_id` must start with.
+    pub fn get_signed_message_prefix(env: Env, project_id: u64) -> Bytes {
+        Self::signed_message_prefix(&env, project_id)
+    }
+
+    fn signed_message_prefix(env: &Env, project_id: u64) -> Bytes {
+        let mut prefix = Bytes::from_slice(env, SIGNED_VERIFY_DOMAIN);
+        prefix.append(&env.current_contract_address().to_xdr(env));
+        prefix.extend_from_array(&project_id.to_be_bytes());
+        prefix.extend_from_array(&storage::get_signed_nonce(env, project_id).to_be_bytes());
+        prefix
+    }
+
+    /// The Stellar account whose ed25519 public key is `pubkey`.
+    fn account_address(env: &Env, pubkey: &BytesN<32>) -> Address {
+        // XDR of ScVal::Address(ScAddress::Account(PublicKey::Ed25519(pubkey))).
+        let mut xdr = Bytes::from_array(env, &[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+        xdr.append(&pubkey.clone().into());
+        Address::from_xdr(env, &xdr)
+            .unwrap_or_else(|_| panic_with_error!(env, Error::VerificationFailed))
     }
 
     pub fn claim_funds(env: Env, project_id: u64) {
@@ -552,333 +1321,2566 @@ impl PifpProtocol {
             panic_with_error!(&env, Error::InvalidTransition);
         }
 
-        if env.ledger().timestamp() < state.last_proof_time + GRACE_PERIOD {
+        let grace_ends = state.last_proof_time + storage::get_challenge_period(&env);
+        if env.ledger().timestamp() < grace_ends {
             panic_with_error!(&env, Error::GracePeriodActive);
         }
+        Self::require_creator_role(&env, &config);
+        // Vesting projects pay out gradually through `claim_vested`.
+        if storage::get_vesting_duration(&env, project_id) > 0 {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
 
-        state.status = ProjectStatus::Completed;
-        let contract_address = env.current_contract_address();
         let protocol_config = get_protocol_config(&env);
 
         invariants_checker::check_no_recursive_state(&env);
         invariants_checker::acquire_lock(&env);
 
+        // A token that refuses the transfer keeps its balance on the project
+        // and the project stays `Verified`, so `claim_funds` can be retried.
+        let mut released = Vec::new(&env);
+        let mut pending = false;
         for token in config.accepted_tokens.iter() {
-            let mut balance = drain_token_balance(&env, project_id, &token);
-            if balance > 0 {
-                let token_client = token::Client::new(&env, &token);
-                if let Some(pcfg) = &protocol_config {
-                    if pcfg.fee_bps > 0 {
-                        let fee = balance
-                            .checked_mul(pcfg.fee_bps as i128)
-                            .unwrap()
-                            .checked_div(10000)
-                            .unwrap();
-                        if fee > 0 {
-                            token_client.transfer(&contract_address, &pcfg.fee_recipient, &fee);
-                            balance -= fee;
-                            events::emit_fee_deducted(
-                                &env,
-                                project_id,
-                                token.clone(),
-                                fee,
-                                pcfg.fee_recipient.clone(),
-                            );
-                        }
-                    }
-                }
-                if balance > 0 {
-                    token_client.transfer(&contract_address, &config.creator, &balance);
-                    events::emit_funds_released(&env, project_id, token, balance);
+            let balance = drain_token_balance(&env, project_id, &token);
+            match Self::pay_out(&env, project_id, &token, balance, &config, &protocol_config) {
+                Some(net) if net > 0 => released.push_back(TokenBalance { token, balance: net }),
+                Some(_) => {}
+                None => {
+                    storage::add_to_token_balance(&env, project_id, &token, balance);
+                    events::emit_release_failed(&env, project_id, token, balance);
+                    pending = true;
                 }
             }
         }
         invariants_checker::release_lock(&env);
-        save_project_state(&env, project_id, &state);
+        if !pending {
+            for token in config.accepted_tokens.iter() {
+                if storage::get_token_balance(&env, project_id, &token) != 0 {
+                    panic_with_error!(&env, Error::ReleaseIncomplete);
+                }
+            }
+            Self::set_status(&env, project_id, &mut state, ProjectStatus::Completed);
+            storage::decrement_active_project_count(&env);
+            save_project_state(&env, project_id, &state);
+            // Anyone may trigger the release, so no caller is recorded.
+            storage::push_audit_record(
+                &env,
+                &env.current_contract_address(),
+                events::FUNDS_CLAIMED,
+                AuditSubject::Project(project_id),
+            );
+        }
+        events::emit_funds_claimed(&env, project_id, config.creator, released);
     }
 
-    pub fn deposit(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
-        Self::require_not_paused(&env);
-        donator.require_auth();
-        Self::deposit_internal(env, project_id, donator, token, amount);
-    }
+    /// Dry run of `claim_funds`: the transfers a release of the project's
+    /// current balances would make, using the same fee, oracle reward and
+    /// rounding math. Each token yields up to three lines: the protocol fee
+    /// (recipient is this contract, where the fee accrues to the treasury),
+    /// the oracle reward and the net amount to `release_to`. No reward line
+    /// appears before an oracle has verified the project. Moves no funds.
+    pub fn simulate_release(env: Env, project_id: u64) -> Vec<PayoutLine> {
+        let config = storage::load_project_config(&env, project_id);
+        let protocol_config = get_protocol_config(&env);
+        let contract_address = env.current_contract_address();
 
-    fn deposit_internal(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
-        if amount <= 0 {
-            panic_with_error!(&env, Error::InvalidAmount);
+        let mut lines = Vec::new(&env);
+        for token in config.accepted_tokens.iter() {
+            let amount = storage::get_token_balance(&env, project_id, &token);
+            if amount <= 0 {
+                continue;
+            }
+            let (fee, oracle, reward) =
+                Self::release_cuts(&env, project_id, amount, &protocol_config);
+            if fee > 0 {
+                lines.push_back(PayoutLine {
+                    recipient: contract_address.clone(),
+                    token: token.clone(),
+                    amount: fee,
+                });
+            }
+            if let Some(oracle) = oracle.filter(|_| reward > 0) {
+                lines.push_back(PayoutLine {
+                    recipient: oracle,
+                    token: token.clone(),
+                    amount: reward,
+                });
+            }
+            let net = amount - fee - reward;
+            if net > 0 {
+                lines.push_back(PayoutLine {
+                    recipient: config.release_to.clone(),
+                    token,
+                    amount: net,
+                });
+            }
         }
+        lines
+    }
 
+    /// Transfer whatever of the linear vesting schedule has accrued in `token`
+    /// and not yet been claimed. Vesting starts when the grace period after
+    /// verification ends and runs for the project's vesting duration; the
+    /// project completes once the last token is fully paid out. Returns the
+    /// gross amount claimed (before the protocol fee). Creator only.
+    pub fn claim_vested(env: Env, creator: Address, project_id: u64, token: Address) -> i128 {
+        Self::require_not_paused(&env);
+        creator.require_auth();
         let (config, mut state) = load_project_pair(&env, project_id);
-        Self::require_project_not_paused(&env, &state);
-
-        if env.ledger().timestamp() >= config.deadline {
-            if (state.status == ProjectStatus::Funding || state.status == ProjectStatus::Active)
-                && env.ledger().timestamp() >= config.deadline
-            {
-                state.status = ProjectStatus::Expired;
-                state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-                save_project_state(&env, project_id, &state);
-            }
-            panic_with_error!(&env, Error::ProjectExpired);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
         }
-
-        if config.is_private && !is_whitelisted(&env, project_id, &donator) {
-            panic_with_error!(&env, Error::NotWhitelisted);
+        Self::require_project_not_paused(&env, &state);
+        if state.status != ProjectStatus::Verified {
+            panic_with_error!(&env, Error::InvalidTransition);
         }
-
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            _ => panic_with_error!(&env, Error::ProjectNotActive),
+        let duration = storage::get_vesting_duration(&env, project_id);
+        if duration == 0 {
+            panic_with_error!(&env, Error::InvalidTransition);
         }
-
+        Self::require_creator_role(&env, &config);
         if !config.accepts_token(&token) {
             panic_with_error!(&env, Error::TokenNotAccepted);
         }
+        let vesting_start = state.last_proof_time + storage::get_challenge_period(&env);
+        let now = env.ledger().timestamp();
+        if now < vesting_start {
+            panic_with_error!(&env, Error::GracePeriodActive);
+        }
 
-        let current_donor_balance =
-            storage::get_donator_balance(&env, project_id, &token, &donator);
-        if current_donor_balance == 0 {
-            state.donation_count += 1;
+        let elapsed = (now - vesting_start).min(duration);
+        let claimed = storage::get_vesting_claimed(&env, project_id, &token);
+        let balance = storage::get_token_balance(&env, project_id, &token);
+        let vested = (balance + claimed)
+            .checked_mul(elapsed as i128)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow))
+            / duration as i128;
+        let amount = vested - claimed;
+
+        if amount > 0 {
+            invariants_checker::check_no_recursive_state(&env);
+            invariants_checker::acquire_lock(&env);
+            let protocol_config = get_protocol_config(&env);
+            let paid = Self::pay_out(&env, project_id, &token, amount, &config, &protocol_config);
+            if paid.is_some() {
+                storage::sub_from_token_balance(&env, project_id, &token, amount);
+                storage::set_vesting_claimed(&env, project_id, &token, claimed + amount);
+            }
+            invariants_checker::release_lock(&env);
+            if paid.is_none() {
+                events::emit_release_failed(&env, project_id, token, amount);
+                return 0;
+            }
+            storage::push_audit_record(
+                &env,
+                &creator,
+                events::VESTED_CLAIMED,
+                AuditSubject::Project(project_id),
+            );
+            events::emit_vested_claimed(&env, project_id, token, amount, elapsed, duration);
+        }
+
+        if elapsed == duration
+            && config
+                .accepted_tokens
+                .iter()
+                .all(|t| storage::get_token_balance(&env, project_id, &t) == 0)
+        {
+            Self::set_status(&env, project_id, &mut state, ProjectStatus::Completed);
+            storage::decrement_active_project_count(&env);
             save_project_state(&env, project_id, &state);
         }
+        amount
+    }
 
-        let token_client = token::Client::new(&env, &token);
-        invariants_checker::check_no_recursive_state(&env);
-        invariants_checker::acquire_lock(&env);
-        token_client.transfer(&donator, env.current_contract_address(), &amount);
-        invariants_checker::release_lock(&env);
+    /// Protocol fee, rewarded oracle and oracle reward taken off a release
+    /// of `amount`. The two cuts together never exceed `amount`.
+    fn release_cuts(
+        env: &Env,
+        project_id: u64,
+        amount: i128,
+        protocol_config: &Option<ProtocolConfig>,
+    ) -> (i128, Option<Address>, i128) {
+        let fee = match protocol_config {
+            Some(pcfg) if pcfg.fee_bps > 0 => Self::bps_cut(env, amount, pcfg.fee_bps).min(amount),
+            _ => 0,
+        };
+        let reward_bps = storage::get_oracle_reward_bps(env);
+        let oracle = storage::get_verifier(env, project_id).filter(|_| reward_bps > 0);
+        let reward = match oracle {
+            Some(_) => Self::bps_cut(env, amount, reward_bps).min(amount - fee),
+            None => 0,
+        };
+        (fee, oracle, reward)
+    }
 
-        let new_balance = storage::add_to_token_balance(&env, project_id, &token, amount);
+    /// Release `amount` of `token` to the project's `release_to`, first
+    /// moving the protocol fee (if any) to the treasury and paying the
+    /// verifying oracle its reward. Both cuts are taken off `amount` and
+    /// together never exceed it. A reward the oracle cannot receive (e.g. a
+    /// frozen or missing trustline) goes to `release_to` instead and is
+    /// reported as `release_failed`. Returns the net amount transferred, or
+    /// `None` without side effects if the token refused the transfer to
+    /// `release_to` (e.g. a frozen asset). The caller has already debited
+    /// the project balance.
+    fn pay_out(
+        env: &Env,
+        project_id: u64,
+        token: &Address,
+        amount: i128,
+        config: &ProjectConfig,
+        protocol_config: &Option<ProtocolConfig>,
+    ) -> Option<i128> {
+        if amount <= 0 {
+            return Some(0);
+        }
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(env, token);
+        let (fee, oracle, reward) = Self::release_cuts(env, project_id, amount, protocol_config);
+        let mut balance = amount - fee - reward;
 
-        if state.status == ProjectStatus::Funding {
-            if let Some(first_token) = config.accepted_tokens.get(0) {
-                if token == first_token && new_balance >= config.goal {
-                    state.status = ProjectStatus::Active;
-                    save_project_state(&env, project_id, &state);
-                    events::emit_project_active(&env, project_id);
-                }
+        if balance > 0
+            && !matches!(
+                token_client.try_transfer(&contract_address, &config.release_to, &balance),
+                Ok(Ok(()))
+            )
+        {
+            return None;
+        }
+        storage::set_funds_released(env, project_id);
+        if fee > 0 {
+            // Fees accrue in the treasury and are swept later
+            // via `withdraw_treasury`.
+            storage::add_to_treasury(env, token, fee);
+            events::emit_fee_deducted(env, project_id, token.clone(), fee, contract_address.clone());
+        }
+        if let (Some(oracle), true) = (oracle, reward > 0) {
+            if matches!(
+                token_client.try_transfer(&contract_address, &oracle, &reward),
+                Ok(Ok(()))
+            ) {
+                events::emit_oracle_rewarded(env, project_id, oracle, token.clone(), reward);
+            } else {
+                token_client.transfer(&contract_address, &config.release_to, &reward);
+                events::emit_release_failed(env, project_id, token.clone(), reward);
+                balance += reward;
             }
         }
+        if balance > 0 {
+            events::emit_funds_released(
+                env,
+                project_id,
+                token.clone(),
+                balance,
+                config.release_to.clone(),
+            );
+        }
+        Some(balance)
+    }
 
-        storage::set_donator_balance(
-            &env,
-            project_id,
-            &token,
-            &donator,
-            current_donor_balance + amount,
-        );
-        events::emit_project_funded(&env, project_id, donator, amount);
+    /// Deposit `amount` of `token` into `project_id`.
+    ///
+    /// The donator's authorization is scoped to exactly
+    /// `(project_id, token, amount)`: a wallet signs a `deposit` invocation
+    /// with those three arguments (plus the nested token `transfer`), so a
+    /// relayer submitting the transaction cannot swap the token or change
+    /// the amount.
+    pub fn deposit(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+        Self::require_not_paused(&env);
+        donator.require_auth_for_args((project_id, token.clone(), amount).into_val(&env));
+        Self::deposit_internal(env, project_id, donator, token, amount);
     }
 
-    pub fn batch_deposit(env: Env, donator: Address, deposits: Vec<DepositRequest>) {
+    /// Deposit with a short memo (at most 64 bytes) for grant tracking and
+    /// reconciliation. The memo is stored per donation and carried on the
+    /// `donation` event.
+    pub fn deposit_with_memo(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+        memo: String,
+    ) {
         Self::require_not_paused(&env);
         donator.require_auth();
-        for req in deposits.iter() {
-            Self::deposit_internal(
-                env.clone(),
-                req.project_id,
-                donator.clone(),
-                req.token,
-                req.amount,
-            );
+        if memo.len() > MAX_MEMO_LEN {
+            panic_with_error!(&env, Error::MemoTooLong);
         }
+        let (config, state) = Self::validate_deposit(&env, project_id, &donator, &token, amount);
+        let received = Self::collect_deposit(&env, project_id, &donator, &token, amount, &config);
+        let received =
+            Self::book_deposit(&env, project_id, Some(&donator), &token, received, &config, state);
+        let seq = storage::push_donation_memo(&env, project_id, &donator, &memo);
+        events::emit_donation_received(&env, project_id, donator, token, received, memo, seq);
     }
 
-    pub fn cancel_project(env: Env, caller: Address, project_id: u64) {
-        caller.require_auth();
-        rbac::require_can_cancel_project(&env, &caller);
-        let (config, mut state) = load_project_pair(&env, project_id);
-        Self::require_project_not_paused(&env, &state);
+    /// Deposit toward a donor-chosen purpose within the project (e.g. a
+    /// sub-goal of a campaign). The amount credited is added to the
+    /// earmark's running total; release logic is unaffected.
+    pub fn deposit_earmarked(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+        earmark: Symbol,
+    ) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        let (config, state) = Self::validate_deposit(&env, project_id, &donator, &token, amount);
+        let received = Self::collect_deposit(&env, project_id, &donator, &token, amount, &config);
+        let received =
+            Self::book_deposit(&env, project_id, Some(&donator), &token, received, &config, state);
+        storage::add_to_earmark(&env, project_id, &earmark, received);
+        events::emit_earmarked(&env, project_id, donator, token, received, earmark);
+    }
 
-        if state.status != ProjectStatus::Active {
-            panic_with_error!(&env, Error::InvalidTransition);
+    /// Total credited toward `earmark` on `project_id`, summed across tokens.
+    pub fn get_earmark_total(env: Env, project_id: u64, earmark: Symbol) -> i128 {
+        storage::get_earmark_total(&env, project_id, &earmark)
+    }
+
+    /// Record a soft pledge of `amount` in `token` without moving any tokens.
+    /// The pledge must pass the same checks as a deposit; pledging again
+    /// adds to the open amount. Convert it with `fulfill_pledge`.
+    pub fn pledge(env: Env, donator: Address, project_id: u64, token: Address, amount: i128) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        let open = storage::get_pledge(&env, project_id, &donator, &token);
+        let total = open
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+        Self::validate_deposit(&env, project_id, &donator, &token, total);
+        storage::set_pledge(&env, project_id, &donator, &token, total);
+        events::emit_pledge_recorded(&env, project_id, donator, token, amount);
+    }
+
+    /// Transfer the full open pledge in `token` as a regular deposit and
+    /// clear it. Fails like a deposit would, e.g. once the project expired.
+    pub fn fulfill_pledge(env: Env, donator: Address, project_id: u64, token: Address) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        let amount = storage::get_pledge(&env, project_id, &donator, &token);
+        if amount == 0 {
+            panic_with_error!(&env, Error::PledgeNotFound);
         }
-        if matches!(rbac::get_role(&env, &caller), Some(Role::ProjectManager))
-            && caller != config.creator
-        {
-            panic_with_error!(&env, Error::NotAuthorized);
+        storage::set_pledge(&env, project_id, &donator, &token, 0);
+        Self::deposit_internal(env.clone(), project_id, donator.clone(), token.clone(), amount);
+        events::emit_pledge_fulfilled(&env, project_id, donator, token, amount);
+    }
+
+    /// Open pledges on `project_id`, in the order they were first made.
+    pub fn get_pledges(env: Env, project_id: u64) -> Vec<Pledge> {
+        let mut pledges = Vec::new(&env);
+        for (donor, token) in storage::get_pledgers(&env, project_id).iter() {
+            let amount = storage::get_pledge(&env, project_id, &donor, &token);
+            pledges.push_back(Pledge { donor, token, amount });
         }
+        pledges
+    }
 
-        state.status = ProjectStatus::Cancelled;
-        state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-        save_project_state(&env, project_id, &state);
-        events::emit_project_cancelled(&env, project_id, caller);
+    pub fn get_donation_memo(env: Env, project_id: u64, donator: Address, seq: u32) -> Option<String> {
+        storage::get_donation_memo(&env, project_id, &donator, seq)
     }
 
-    pub fn refund(env: Env, donator: Address, project_id: u64, token: Address) {
-        donator.require_auth();
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Pull the tokens first and credit what actually arrived, so tokens that
+    /// charge a transfer fee never leave an accounting gap.
+    fn deposit_internal(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+        let (config, state) = Self::validate_deposit(&env, project_id, &donator, &token, amount);
+        let received = Self::collect_deposit(&env, project_id, &donator, &token, amount, &config);
+        Self::book_deposit(&env, project_id, Some(&donator), &token, received, &config, state);
+    }
 
-        if (state.status == ProjectStatus::Funding || state.status == ProjectStatus::Active)
-            && env.ledger().timestamp() >= config.deadline
-        {
-            state.status = ProjectStatus::Expired;
-            state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-            save_project_state(&env, project_id, &state);
+    /// Validate a deposit into `project_id` and book it against the project
+    /// and donator balances, returning the amount credited after the
+    /// overshoot policy. The caller is responsible for pulling the tokens.
+    fn credit_deposit(
+        env: &Env,
+        project_id: u64,
+        donator: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> i128 {
+        let (config, state) = Self::validate_deposit(env, project_id, donator, token, amount);
+        let accepted = Self::overshoot_accepted(env, project_id, token, amount, &config);
+        Self::book_deposit(env, project_id, Some(donator), token, accepted, &config, state);
+        accepted
+    }
+
+    /// Check that `project_id` can take a deposit of `amount` in `token`
+    /// from `donator`, returning the loaded project pair.
+    fn validate_deposit(
+        env: &Env,
+        project_id: u64,
+        donator: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> (ProjectConfig, ProjectState) {
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+        if amount > storage::get_max_deposit_per_tx(env) {
+            panic_with_error!(env, Error::DepositTooLarge);
+        }
+        let min = storage::get_global_min_deposit(env).max(storage::get_min_deposit(env, project_id));
+        if amount < min {
+            panic_with_error!(env, Error::BelowMinimum);
         }
 
-        if !matches!(
-            state.status,
-            ProjectStatus::Expired | ProjectStatus::Cancelled
-        ) {
-            panic_with_error!(&env, Error::ProjectNotExpired);
+        let (config, mut state) = load_project_pair(env, project_id);
+        Self::require_project_not_paused(env, &state);
+        if storage::is_donations_paused(env, project_id) {
+            panic_with_error!(env, Error::DonationsPaused);
         }
-        if state.refund_expiry > 0 && env.ledger().timestamp() >= state.refund_expiry {
-            panic_with_error!(&env, Error::RefundWindowExpired);
+
+        if env.ledger().timestamp() >= config.deadline {
+            if (state.status == ProjectStatus::Funding || state.status == ProjectStatus::Active)
+                && env.ledger().timestamp() >= config.deadline
+            {
+                state.status = ProjectStatus::Expired;
+                state.refund_expiry = Self::refund_expiry_from_now(env);
+                save_project_state(env, project_id, &state);
+            }
+            panic_with_error!(env, Error::ProjectExpired);
         }
 
-        let amount = storage::get_donator_balance(&env, project_id, &token, &donator);
-        if amount <= 0 {
-            panic_with_error!(&env, Error::InsufficientBalance);
+        if env.ledger().timestamp() > config.deposit_deadline {
+            panic_with_error!(env, Error::DepositWindowClosed);
         }
 
-        storage::set_donator_balance(&env, project_id, &token, &donator, 0);
-        storage::add_to_token_balance(&env, project_id, &token, -amount);
+        if config.is_private && !is_whitelisted(env, project_id, donator) {
+            panic_with_error!(env, Error::NotWhitelisted);
+        }
+        if config.institutions_only && !storage::is_institution(env, donator) {
+            panic_with_error!(env, Error::NotInstitution);
+        }
 
-        invariants_checker::check_no_recursive_state(&env);
-        invariants_checker::acquire_lock(&env);
-        token::Client::new(&env, &token).transfer(
-            &env.current_contract_address(),
-            &donator,
-            &amount,
-        );
-        invariants_checker::release_lock(&env);
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            _ => panic_with_error!(env, Error::ProjectNotActive),
+        }
+        if config.lock_on_first_confirmation && storage::is_confirmation_recorded(env, project_id) {
+            panic_with_error!(env, Error::VerificationPending);
+        }
+
+        if !config.accepts_token(token) {
+            panic_with_error!(env, Error::TokenNotAccepted);
+        }
+
+        let increment = storage::get_deposit_increment(env, project_id, token);
+        if increment > 0 && amount % increment != 0 {
+            panic_with_error!(env, Error::InvalidIncrement);
+        }
+
+        if donator == &config.creator {
+            let delay = storage::get_self_deposit_delay(env);
+            if env.ledger().timestamp() < config.created_at.saturating_add(delay) {
+                panic_with_error!(env, Error::SelfDepositTooEarly);
+            }
+        }
 
-        events::emit_refunded(&env, project_id, donator, amount);
+        let cap = storage::get_max_per_donor(env, project_id);
+        if cap > 0 {
+            let contributed = storage::get_donator_balance(env, project_id, token, donator);
+            if contributed.saturating_add(amount) > cap {
+                panic_with_error!(env, Error::DonorCapExceeded);
+            }
+        }
+
+        (config, state)
     }
 
-    pub fn expire_project(env: Env, project_id: u64) {
+    /// Credit `amount` of `token`, less the tiered deposit fee, to the
+    /// project and donator balances and advance the project to `Active` once
+    /// the goal is reached. Returns the amount credited.
+    fn book_deposit(
+        env: &Env,
+        project_id: u64,
+        donator: Option<&Address>,
+        token: &Address,
+        amount: i128,
+        config: &ProjectConfig,
+        mut state: ProjectState,
+    ) -> i128 {
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+        let fee = Self::deposit_fee(env, amount);
+        if fee > 0 {
+            storage::add_to_treasury(env, token, fee);
+            events::emit_fee_deducted(
+                env,
+                project_id,
+                token.clone(),
+                fee,
+                env.current_contract_address(),
+            );
+        }
+        let amount = amount - fee;
+
+        // Anonymous deposits keep their own balance and donor count.
+        let Some(donator) = donator else {
+            Self::book_project_funds(env, project_id, token, amount, config, &mut state);
+            Self::log_donation(env, project_id, None, token, amount);
+            return amount;
+        };
+
+        let current_donor_balance = storage::get_donator_balance(env, project_id, token, donator);
+        if current_donor_balance == 0 {
+            // Saturate rather than overflow: this runs after the tokens
+            // have already moved.
+            state.donation_count = state.donation_count.saturating_add(1);
+            save_project_state(env, project_id, &state);
+            storage::add_donor_project(env, donator, project_id);
+        }
+
+        Self::book_project_funds(env, project_id, token, amount, config, &mut state);
+
+        storage::set_donator_balance(
+            env,
+            project_id,
+            token,
+            donator,
+            current_donor_balance + amount,
+        );
+        Self::log_donation(env, project_id, Some(donator.clone()), token, amount);
+        events::emit_project_funded(
+            env,
+            project_id,
+            config.category.clone(),
+            donator.clone(),
+            amount,
+        );
+        amount
+    }
+
+    /// Append a deposit to the project's donation log.
+    fn log_donation(
+        env: &Env,
+        project_id: u64,
+        donor: Option<Address>,
+        token: &Address,
+        amount: i128,
+    ) {
+        storage::push_donation_entry(
+            env,
+            project_id,
+            &DonationRecord {
+                donor,
+                token: token.clone(),
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Deposit fee for a donation of `amount`, at the bps of the highest
+    /// fee tier whose threshold `amount` reaches (0 below the first tier).
+    fn deposit_fee(env: &Env, amount: i128) -> i128 {
+        let mut bps = 0;
+        for (threshold, tier_bps) in storage::get_fee_tiers(env).iter() {
+            if amount < threshold {
+                break;
+            }
+            bps = tier_bps;
+        }
+        Self::bps_cut(env, amount, bps)
+    }
+
+    /// `bps` basis points of `amount`, rounded per the configured
+    /// `RoundingMode`. The caller keeps the rest, so no dust is left over.
+    fn bps_cut(env: &Env, amount: i128, bps: u32) -> i128 {
+        let scaled = amount
+            .checked_mul(bps as i128)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+        match storage::get_rounding_mode(env) {
+            RoundingMode::Down => scaled / 10000,
+            RoundingMode::Up => (scaled + 9999) / 10000,
+            RoundingMode::NearestWithRemainderToCreator => (scaled + 4999) / 10000,
+        }
+    }
+
+    /// Add `amount` to the project's `token` balance and advance it to
+    /// `Active` once the goal is reached.
+    fn book_project_funds(
+        env: &Env,
+        project_id: u64,
+        token: &Address,
+        amount: i128,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+    ) {
+        let new_balance = storage::add_to_token_balance(env, project_id, token, amount);
+
+        // Tokens without a goal (secondary tokens never given one, or any
+        // token of an open-ended project) report no progress.
+        let goal = if config.accepted_tokens.first_index_of(token) == Some(0) {
+            config.goal
+        } else {
+            storage::get_token_goal(env, project_id, token)
+        };
+        if goal > 0 && !config.open_ended {
+            let pct = (new_balance.saturating_mul(10000) / goal).min(u32::MAX as i128) as u32;
+            events::emit_funding_progress(env, project_id, token.clone(), new_balance, goal, pct);
+        }
+
+        if state.status == ProjectStatus::Funding && !config.open_ended {
+            if let Some(first_token) = config.accepted_tokens.get(0) {
+                if *token == first_token && new_balance >= config.goal {
+                    Self::set_status(env, project_id, state, ProjectStatus::Active);
+                    save_project_state(env, project_id, state);
+                    storage::set_goal_reached_at(env, project_id, env.ledger().timestamp());
+                    events::emit_project_active(env, project_id);
+                    events::emit_goal_reached_with_deadline(env, project_id, config.deadline);
+                }
+            }
+        }
+
+        if !storage::is_ready_signalled(env, project_id) && Self::goals_met(env, project_id, config) {
+            storage::set_ready_signalled(env, project_id);
+            events::emit_ready_for_verification(env, project_id);
+        }
+    }
+
+    /// Refuse verification or release when `RequireCreatorRole` is on and
+    /// the creator has since lost the right to register projects.
+    fn require_creator_role(env: &Env, config: &ProjectConfig) {
+        if storage::get_require_creator_role(env) && !rbac::can_register(env, &config.creator) {
+            panic_with_error!(env, Error::CreatorRoleRevoked);
+        }
+    }
+
+    /// Refuse verification of an `AllOrNothing` project whose goals are not
+    /// all met.
+    fn require_goal_for_model(env: &Env, project_id: u64, config: &ProjectConfig) {
+        if config.funding_model == FundingModel::AllOrNothing
+            && !Self::goals_met(env, project_id, config)
+        {
+            panic_with_error!(env, Error::GoalNotMet);
+        }
+    }
+
+    /// True when the first token has reached `goal` and every secondary token
+    /// with a per-token goal has reached it too. Never true for open-ended
+    /// projects.
+    fn goals_met(env: &Env, project_id: u64, config: &ProjectConfig) -> bool {
+        if config.open_ended {
+            return false;
+        }
+        for (i, token) in config.accepted_tokens.iter().enumerate() {
+            let goal = if i == 0 {
+                config.goal
+            } else {
+                storage::get_token_goal(env, project_id, &token)
+            };
+            if storage::get_token_balance(env, project_id, &token) < goal {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Deposit on behalf of a hidden donor identified only by `commitment`
+    /// (`sha256` of a secret preimage). `payer` funds the deposit but is not
+    /// recorded against it; the balance can later be reclaimed with
+    /// `refund_anonymous` by revealing the preimage. Booked like `deposit`
+    /// (deposit fee, donation log), with no donor on the log entry.
+    pub fn deposit_anonymous(
+        env: Env,
+        payer: Address,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+        commitment: BytesN<32>,
+    ) {
+        Self::require_not_paused(&env);
+        payer.require_auth();
+
+        let (config, mut state) = Self::validate_deposit(&env, project_id, &payer, &token, amount);
+        let received = Self::collect_deposit(&env, project_id, &payer, &token, amount, &config);
+        if received <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let current = storage::get_anon_donor_balance(&env, project_id, &token, &commitment);
+        if current == 0 {
+            state.donation_count = state.donation_count.saturating_add(1);
+            save_project_state(&env, project_id, &state);
+        }
+        let received = Self::book_deposit(&env, project_id, None, &token, received, &config, state);
+        storage::set_anon_donor_balance(&env, project_id, &token, &commitment, current + received);
+        events::emit_anon_deposited(&env, project_id, commitment, token, received);
+    }
+
+    /// Refund an anonymous deposit to `recipient` by revealing the preimage
+    /// of its commitment. Same eligibility rules as `refund`.
+    pub fn refund_anonymous(
+        env: Env,
+        recipient: Address,
+        project_id: u64,
+        token: Address,
+        preimage: Bytes,
+    ) {
+        recipient.require_auth();
+        let (config, mut state) = load_project_pair(&env, project_id);
+        Self::require_refundable(&env, &config, &mut state, project_id);
+        if !config.accepts_token(&token) {
+            panic_with_error!(&env, Error::TokenNotAccepted);
+        }
+
+        let commitment: BytesN<32> = env.crypto().sha256(&preimage).into();
+        let amount = storage::get_anon_donor_balance(&env, project_id, &token, &commitment);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        storage::set_anon_donor_balance(&env, project_id, &token, &commitment, 0);
+        storage::sub_from_token_balance(&env, project_id, &token, amount);
+        storage::add_to_total_refunded(&env, project_id, &token, amount);
+
+        invariants_checker::check_no_recursive_state(&env);
+        invariants_checker::acquire_lock(&env);
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &amount,
+        );
+        invariants_checker::release_lock(&env);
+
+        events::emit_anon_refunded(&env, project_id, commitment, recipient, amount);
+    }
+
+    /// Pull a deposit, apply the project's overshoot policy, and return the
+    /// amount to credit. Any excess is sent straight back to `donator`.
+    fn collect_deposit(
+        env: &Env,
+        project_id: u64,
+        donator: &Address,
+        token: &Address,
+        amount: i128,
+        config: &ProjectConfig,
+    ) -> i128 {
+        let received = Self::pull_deposit(env, donator, token, amount);
+        Self::settle_overshoot(env, project_id, donator, token, received, config)
+    }
+
+    /// Keep what the overshoot policy allows of `received` (already held by
+    /// the contract) and send the rest back to `donator`.
+    fn settle_overshoot(
+        env: &Env,
+        project_id: u64,
+        donator: &Address,
+        token: &Address,
+        received: i128,
+        config: &ProjectConfig,
+    ) -> i128 {
+        let accepted = Self::overshoot_accepted(env, project_id, token, received, config);
+        if accepted < received {
+            invariants_checker::check_no_recursive_state(env);
+            invariants_checker::acquire_lock(env);
+            token::Client::new(env, token).transfer(
+                &env.current_contract_address(),
+                donator,
+                &(received - accepted),
+            );
+            invariants_checker::release_lock(env);
+        }
+        accepted
+    }
+
+    /// How much of `amount` the project may take under its overshoot policy.
+    /// Panics with `CapExceeded` when the policy forbids the deposit.
+    fn overshoot_accepted(
+        env: &Env,
+        project_id: u64,
+        token: &Address,
+        amount: i128,
+        config: &ProjectConfig,
+    ) -> i128 {
+        if config.overshoot_policy == OvershootPolicy::Keep
+            || config.open_ended
+            || config.accepted_tokens.get(0).as_ref() != Some(token)
+        {
+            return amount;
+        }
+        let current = storage::get_token_balance(env, project_id, token);
+        let remaining = (config.goal - current).max(0);
+        if amount <= remaining {
+            return amount;
+        }
+        match config.overshoot_policy {
+            OvershootPolicy::RefundExcess if remaining > 0 => remaining,
+            _ => panic_with_error!(env, Error::CapExceeded),
+        }
+    }
+
+    /// Pull `amount` of `token` from `donator` into the contract under the
+    /// re-entrancy guard and return the amount the contract actually received.
+    fn pull_deposit(env: &Env, donator: &Address, token: &Address, amount: i128) -> i128 {
+        let token_client = token::Client::new(env, token);
+        let contract = env.current_contract_address();
+        invariants_checker::check_no_recursive_state(env);
+        invariants_checker::acquire_lock(env);
+        let before = token_client.balance(&contract);
+        token_client.transfer(donator, &contract, &amount);
+        let after = token_client.balance(&contract);
+        invariants_checker::release_lock(env);
+        after - before
+    }
+
+    /// Donate `from_amount` of `from_token`, swapped through the configured
+    /// router into `to_token`, which the project must accept. Reverts with
+    /// `SlippageExceeded` if fewer than `min_out` tokens arrive. The amount
+    /// received is then booked exactly like a regular deposit.
+    pub fn deposit_and_swap(
+        env: Env,
+        donator: Address,
+        project_id: u64,
+        from_token: Address,
+        from_amount: i128,
+        to_token: Address,
+        min_out: i128,
+    ) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        let router = match storage::get_swap_router(&env) {
+            Some(router) => router,
+            None => panic_with_error!(&env, Error::RouterNotSet),
+        };
+        if from_amount <= 0 || min_out <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        // Fail fast on a token the project does not accept, before swapping.
+        let (config, _) = Self::validate_deposit(&env, project_id, &donator, &to_token, min_out);
+
+        let pulled = Self::pull_deposit(&env, &donator, &from_token, from_amount);
+        let contract = env.current_contract_address();
+        let out_client = token::Client::new(&env, &to_token);
+        invariants_checker::check_no_recursive_state(&env);
+        invariants_checker::acquire_lock(&env);
+        let before = out_client.balance(&contract);
+        token::Client::new(&env, &from_token).transfer(&contract, &router, &pulled);
+        router::SwapRouterClient::new(&env, &router).swap(
+            &from_token,
+            &to_token,
+            &pulled,
+            &min_out,
+            &contract,
+        );
+        let received = out_client.balance(&contract) - before;
+        invariants_checker::release_lock(&env);
+        if received < min_out {
+            panic_with_error!(&env, Error::SlippageExceeded);
+        }
+
+        let (_, state) = Self::validate_deposit(&env, project_id, &donator, &to_token, received);
+        let accepted = Self::settle_overshoot(&env, project_id, &donator, &to_token, received, &config);
+        Self::book_deposit(&env, project_id, Some(&donator), &to_token, accepted, &config, state);
+        events::emit_deposit_swapped(&env, project_id, donator, from_token, pulled, to_token, received);
+    }
+
+    /// Set the DEX router used by `deposit_and_swap`. Admin or above only.
+    pub fn set_swap_router(env: Env, caller: Address, router: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_swap_router(&env, &router);
+        events::emit_swap_router_set(&env, caller, router);
+    }
+
+    pub fn get_swap_router(env: Env) -> Option<Address> {
+        storage::get_swap_router(&env)
+    }
+
+    pub fn batch_deposit(env: Env, donator: Address, deposits: Vec<DepositRequest>) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        for req in deposits.iter() {
+            Self::deposit_internal(
+                env.clone(),
+                req.project_id,
+                donator.clone(),
+                req.token,
+                req.amount,
+            );
+        }
+    }
+
+    /// Fund several projects with a single `token` transfer.
+    ///
+    /// Each `(project_id, amount)` allocation is validated and credited exactly
+    /// like a standalone `deposit`; the donator then pays the total once. Any
+    /// invalid allocation rolls back the whole call.
+    pub fn deposit_split(
+        env: Env,
+        donator: Address,
+        token: Address,
+        allocations: Vec<(u64, i128)>,
+    ) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+
+        if allocations.is_empty() {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if allocations.len() > MAX_SPLIT_ALLOCATIONS {
+            panic_with_error!(&env, Error::TooManyAllocations);
+        }
+
+        let mut total: i128 = 0;
+        for (project_id, amount) in allocations.iter() {
+            let amount = Self::credit_deposit(&env, project_id, &donator, &token, amount);
+            total = match total.checked_add(amount) {
+                Some(t) => t,
+                None => panic_with_error!(&env, Error::Overflow),
+            };
+        }
+        if Self::pull_deposit(&env, &donator, &token, total) < total {
+            panic_with_error!(&env, Error::TransferShortfall);
+        }
+    }
+
+    pub fn cancel_project(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        rbac::require_can_cancel_project(&env, &caller);
+        let (config, mut state) = load_project_pair(&env, project_id);
+        Self::require_project_not_paused(&env, &state);
+
+        if state.status != ProjectStatus::Active {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if matches!(rbac::get_role(&env, &caller), Some(Role::ProjectManager))
+            && caller != config.creator
+        {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        Self::set_status(&env, project_id, &mut state, ProjectStatus::Cancelled);
+        state.refund_expiry = Self::refund_expiry_from_now(&env);
+        save_project_state(&env, project_id, &state);
+        storage::decrement_active_project_count(&env);
+        events::emit_project_cancelled(&env, project_id, caller);
+    }
+
+    /// Force-cancel a project that may already hold donations (e.g. fraud).
+    ///
+    /// Only Admin or SuperAdmin may call this. Works from `Funding`, `Active`
+    /// or `Verified` (during the grace period) and always lands in the
+    /// refundable `Cancelled` state, so every donor can reclaim exactly what
+    /// they deposited via `refund`. Refused once any funds have been paid
+    /// out, as donors could then no longer be refunded in full.
+    pub fn admin_cancel_project(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        let mut state = storage::load_project_state(&env, project_id);
+
+        if !matches!(
+            state.status,
+            ProjectStatus::Funding | ProjectStatus::Active | ProjectStatus::Verified
+        ) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if storage::is_funds_released(&env, project_id) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        Self::set_status(&env, project_id, &mut state, ProjectStatus::Cancelled);
+        state.refund_expiry = Self::refund_expiry_from_now(&env);
+        save_project_state(&env, project_id, &state);
+        storage::decrement_active_project_count(&env);
+        events::emit_project_cancelled(&env, project_id, caller);
+    }
+
+    pub fn refund(env: Env, donator: Address, project_id: u64, token: Address) {
+        donator.require_auth();
+        let (config, mut state) = load_project_pair(&env, project_id);
+        Self::require_refundable(&env, &config, &mut state, project_id);
+        if !config.accepts_token(&token) {
+            panic_with_error!(&env, Error::TokenNotAccepted);
+        }
+
+        if Self::refund_donor(&env, project_id, &token, donator) == 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+    }
+
+    /// Refund each of `donors` their `token` balance in `project_id`, on the
+    /// same terms as `refund`. Donors with nothing left to refund (never
+    /// donated, or already refunded) are skipped, so a keeper can safely
+    /// retry or overlap batches. Returns how many donors were refunded. At
+    /// most 30 donors per call.
+    pub fn refund_batch(env: Env, project_id: u64, token: Address, donors: Vec<Address>) -> u32 {
+        if donors.len() > MAX_REFUND_BATCH {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
         let (config, mut state) = load_project_pair(&env, project_id);
+        Self::require_refundable(&env, &config, &mut state, project_id);
+        if !config.accepts_token(&token) {
+            panic_with_error!(&env, Error::TokenNotAccepted);
+        }
+
+        let mut refunded = 0;
+        for donator in donors.iter() {
+            if Self::refund_donor(&env, project_id, &token, donator) > 0 {
+                refunded += 1;
+            }
+        }
+        refunded
+    }
+
+    /// Pay `donator` their whole `token` balance in `project_id` and zero it.
+    /// Returns the amount refunded; 0 (and no transfer) if there was none.
+    fn refund_donor(env: &Env, project_id: u64, token: &Address, donator: Address) -> i128 {
+        let amount = storage::get_donator_balance(env, project_id, token, &donator);
+        if amount <= 0 {
+            return 0;
+        }
+
+        storage::set_donator_balance(env, project_id, token, &donator, 0);
+        storage::sub_from_token_balance(env, project_id, token, amount);
+        storage::add_to_total_refunded(env, project_id, token, amount);
+
+        invariants_checker::check_no_recursive_state(env);
+        invariants_checker::acquire_lock(env);
+        token::Client::new(env, token).transfer(&env.current_contract_address(), &donator, &amount);
+        invariants_checker::release_lock(env);
+
+        events::emit_refunded(env, project_id, donator, amount);
+        amount
+    }
+
+    /// Total amount of `token` refunded to donors of `project_id`, including
+    /// anonymous refunds. Compared with the amount raised, this shows how
+    /// much was reclaimed versus left unclaimed.
+    pub fn get_total_refunded(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_total_refunded(&env, project_id, &token)
+    }
+
+    /// Move a `Verified` project whose creator never claimed to `Cancelled`,
+    /// so donors can `refund`. Allowed once the grace period plus the claim
+    /// timeout has elapsed since verification. Vesting projects pay out
+    /// through `claim_vested` and are excluded, as are projects a partial
+    /// `claim_funds` has already paid from. Callable by anyone.
+    pub fn reclaim_unclaimed(env: Env, project_id: u64) {
+        let mut state = storage::load_project_state(&env, project_id);
+        if state.status != ProjectStatus::Verified
+            || storage::get_vesting_duration(&env, project_id) > 0
+            || storage::is_funds_released(&env, project_id)
+        {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        let timeout = storage::get_claim_timeout(&env);
+        let claim_by = state
+            .last_proof_time
+            .saturating_add(storage::get_challenge_period(&env))
+            .saturating_add(timeout);
+        if timeout == 0 || env.ledger().timestamp() < claim_by {
+            panic_with_error!(&env, Error::ClaimTimeoutNotReached);
+        }
+
+        Self::set_status(&env, project_id, &mut state, ProjectStatus::Cancelled);
+        state.refund_expiry = Self::refund_expiry_from_now(&env);
+        save_project_state(&env, project_id, &state);
+        storage::decrement_active_project_count(&env);
+        events::emit_unclaimed_reclaimed(&env, project_id, state.last_proof_time);
+    }
+
+    pub fn expire_project(env: Env, project_id: u64) {
+        let (config, state) = load_project_pair(&env, project_id);
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if env.ledger().timestamp() < config.deadline {
+            panic_with_error!(&env, Error::ProjectNotExpired);
+        }
+        Self::mark_expired(&env, project_id, &config, state);
+    }
+
+    /// Expire every project in `ids` that `expire_project` would accept,
+    /// skipping unknown IDs, projects still before their deadline and
+    /// projects not in `Funding` or `Active`. Returns how many were expired.
+    /// At most 30 IDs per call.
+    pub fn batch_expire(env: Env, ids: Vec<u64>) -> u32 {
+        if ids.len() > MAX_BATCH_EXPIRE {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+        let now = env.ledger().timestamp();
+        let mut expired = 0;
+        for id in ids.iter() {
+            let (Some(config), Some(state)) = (
+                storage::maybe_load_project_config(&env, id),
+                storage::maybe_load_project_state(&env, id),
+            ) else {
+                continue;
+            };
+            if matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
+                && now >= config.deadline
+            {
+                Self::mark_expired(&env, id, &config, state);
+                expired += 1;
+            }
+        }
+        expired
+    }
+
+    fn mark_expired(env: &Env, project_id: u64, config: &ProjectConfig, mut state: ProjectState) {
+        Self::set_status(env, project_id, &mut state, ProjectStatus::Expired);
+        state.refund_expiry = Self::refund_expiry_from_now(env);
+        save_project_state(env, project_id, &state);
+        storage::decrement_active_project_count(env);
+        events::emit_project_expired(env, project_id, config.deadline);
+        if Self::soft_cap_reached(env, project_id, config) {
+            let token = config.accepted_tokens.get_unchecked(0);
+            let balance = storage::get_token_balance(env, project_id, &token);
+            events::emit_soft_cap_met(env, project_id, config.soft_cap, balance);
+        }
+    }
+
+    /// True when the project has a soft cap and its first-token balance has
+    /// reached it.
+    fn soft_cap_reached(env: &Env, project_id: u64, config: &ProjectConfig) -> bool {
+        config.soft_cap > 0
+            && config.accepted_tokens.get(0).is_some_and(|token| {
+                storage::get_token_balance(env, project_id, &token) >= config.soft_cap
+            })
+    }
+
+    pub fn reclaim_expired_funds(env: Env, creator: Address, project_id: u64) {
+        Self::require_not_paused(&env);
+        creator.require_auth();
+
+        let (config, state) = load_project_pair(&env, project_id);
+
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        if !matches!(
+            state.status,
+            ProjectStatus::Expired | ProjectStatus::Cancelled
+        ) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        // Funds past the soft cap belong to the creator as soon as it expires.
+        let released = state.status == ProjectStatus::Expired
+            && Self::soft_cap_reached(&env, project_id, &config);
+        if !released
+            && (state.refund_expiry == 0 || env.ledger().timestamp() < state.refund_expiry)
+        {
+            panic_with_error!(&env, Error::RefundWindowActive);
+        }
+
+        let contract_address = env.current_contract_address();
+        invariants_checker::check_no_recursive_state(&env);
+        invariants_checker::acquire_lock(&env);
+        for token in config.accepted_tokens.iter() {
+            let balance = drain_token_balance(&env, project_id, &token);
+            if balance > 0 {
+                let token_client = token::Client::new(&env, &token);
+                token_client.transfer(&contract_address, &config.creator, &balance);
+                events::emit_expired_funds_reclaimed(
+                    &env,
+                    project_id,
+                    config.creator.clone(),
+                    token,
+                    balance,
+                );
+            }
+        }
+        invariants_checker::release_lock(&env);
+    }
+
+    pub fn update_protocol_config(env: Env, caller: Address, fee_recipient: Address, fee_bps: u32) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+
+        if fee_bps > 1000 {
+            panic_with_error!(&env, Error::InvalidFeeBasisPoints);
+        }
+
+        let old_config = get_protocol_config(&env);
+        let new_config = ProtocolConfig {
+            fee_recipient,
+            fee_bps,
+        };
+
+        set_protocol_config(&env, &new_config);
+        events::emit_protocol_config_updated(&env, old_config, new_config);
+    }
+
+    /// Set the minimum number of seconds a creator must wait between two
+    /// `register_project` calls. `0` (the default) disables throttling.
+    pub fn set_register_cooldown(env: Env, caller: Address, cooldown: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_register_cooldown(&env, cooldown);
+        events::emit_register_cooldown_updated(&env, caller, cooldown);
+    }
+
+    /// Set how many seconds after registration a creator must wait before
+    /// depositing into their own project, to deter wash-funding. `0` (the
+    /// default) disables the check. Admin or above only.
+    pub fn set_self_deposit_delay(env: Env, caller: Address, delay: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_self_deposit_delay(&env, delay);
+        events::emit_self_deposit_delay_updated(&env, caller, delay);
+    }
+
+    pub fn get_self_deposit_delay(env: Env) -> u64 {
+        storage::get_self_deposit_delay(&env)
+    }
+
+    /// Sweep accrued protocol fees in `token` to `to`. SuperAdmin only.
+    pub fn withdraw_treasury(env: Env, caller: Address, token: Address, to: Address, amount: i128) {
+        caller.require_auth();
+        rbac::require_super_admin(&env, &caller);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if amount > storage::get_treasury_balance(&env, &token) {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        storage::add_to_treasury(&env, &token, -amount);
+        invariants_checker::check_no_recursive_state(&env);
+        invariants_checker::acquire_lock(&env);
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+        invariants_checker::release_lock(&env);
+        events::emit_treasury_withdrawn(&env, token, to, amount);
+    }
+
+    /// Require at least `min` distinct Oracle role holders before any project
+    /// can be verified, so a single oracle cannot release funds on its own.
+    /// Independent of per-project oracle thresholds. SuperAdmin only.
+    pub fn set_min_oracles(env: Env, caller: Address, min: u32) {
+        caller.require_auth();
+        rbac::require_super_admin(&env, &caller);
+        storage::set_min_oracles(&env, min);
+        events::emit_min_oracles_updated(&env, caller, min);
+    }
+
+    pub fn get_min_oracles(env: Env) -> u32 {
+        storage::get_min_oracles(&env)
+    }
+
+    /// Set the consensus weight of `oracle` (e.g. its reputation). Oracles
+    /// without a weight count as 1. Admin or above.
+    pub fn set_oracle_weight(env: Env, caller: Address, oracle: Address, weight: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_oracle_weight(&env, &oracle, weight);
+        events::emit_oracle_weight_updated(&env, caller, oracle, weight);
+    }
+
+    pub fn get_oracle_weight(env: Env, oracle: Address) -> u32 {
+        storage::get_oracle_weight(&env, &oracle)
+    }
+
+    /// Require the summed weight of confirming oracles to reach `threshold`
+    /// before a project with `authorized_oracles` is verified, on top of its
+    /// own M-of-N threshold. Other projects are verified by one oracle, whose
+    /// own weight must reach it; a signed verification is weighed as the
+    /// signing key's account. 0 disables weighting. Admin or above.
+    pub fn set_consensus_threshold(env: Env, caller: Address, threshold: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_consensus_threshold(&env, threshold);
+        events::emit_consensus_threshold_updated(&env, caller, threshold);
+    }
+
+    pub fn get_consensus_threshold(env: Env) -> u32 {
+        storage::get_consensus_threshold(&env)
+    }
+
+    /// Require at least `gap` seconds between two distinct oracle
+    /// confirmations of the same multi-oracle project, so a quorum cannot
+    /// land in a single ledger. 0 disables the check. Admin or above.
+    pub fn set_confirmation_gap(env: Env, caller: Address, gap: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_confirmation_gap(&env, gap);
+        events::emit_confirmation_gap_updated(&env, caller, gap);
+    }
+
+    pub fn get_confirmation_gap(env: Env) -> u64 {
+        storage::get_confirmation_gap(&env)
+    }
+
+    /// Restrict `set_category` to the given categories. An empty list lets
+    /// creators use any category. Admin or above only.
+    pub fn set_allowed_categories(env: Env, caller: Address, categories: Vec<Symbol>) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_allowed_categories(&env, &categories);
+        events::emit_allowed_categories_set(&env, caller, categories);
+    }
+
+    pub fn get_allowed_categories(env: Env) -> Vec<Symbol> {
+        storage::get_allowed_categories(&env)
+    }
+
+    /// Number of distinct addresses currently holding the Oracle role.
+    pub fn get_oracle_count(env: Env) -> u32 {
+        rbac::oracle_count(&env)
+    }
+
+    /// Number of distinct addresses currently holding `role`, without
+    /// enumerating members.
+    pub fn count_role(env: Env, role: Role) -> u32 {
+        rbac::role_count(&env, &role)
+    }
+
+    /// Set how long donors may refund after a project expires or is
+    /// cancelled. Applies to projects that reach those states afterwards;
+    /// `u64::MAX` leaves refunds open indefinitely. Admin or above only.
+    pub fn set_refund_window(env: Env, caller: Address, window: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if window == 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_refund_window(&env, window);
+        events::emit_refund_window_updated(&env, caller, window);
+    }
+
+    pub fn get_refund_window(env: Env) -> u64 {
+        storage::get_refund_window(&env)
+    }
+
+    /// Give creators `timeout` seconds after the grace period to claim
+    /// verified funds; past that anyone may call `reclaim_unclaimed` to open
+    /// refunds. 0 disables the timeout. Admin or above only.
+    pub fn set_claim_timeout(env: Env, caller: Address, timeout: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_claim_timeout(&env, timeout);
+        events::emit_claim_timeout_updated(&env, caller, timeout);
+    }
+
+    pub fn get_claim_timeout(env: Env) -> u64 {
+        storage::get_claim_timeout(&env)
+    }
+
+    /// Move a project's unrefunded `token` balance into the treasury once
+    /// its refund window has closed. Admin or above only.
+    pub fn sweep_unclaimed(env: Env, caller: Address, project_id: u64, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        let state = storage::load_project_state(&env, project_id);
+
+        if !matches!(
+            state.status,
+            ProjectStatus::Expired | ProjectStatus::Cancelled
+        ) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if state.refund_expiry == 0 || env.ledger().timestamp() < state.refund_expiry {
+            panic_with_error!(&env, Error::RefundWindowActive);
+        }
+
+        let amount = drain_token_balance(&env, project_id, &token);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        storage::add_to_treasury(&env, &token, amount);
+        events::emit_unclaimed_swept(&env, project_id, token, amount);
+    }
+
+    /// Protocol fees accrued in `token` and not yet withdrawn.
+    pub fn get_treasury_balance(env: Env, token: Address) -> i128 {
+        storage::get_treasury_balance(&env, &token)
+    }
+
+    pub fn get_register_cooldown(env: Env) -> u64 {
+        storage::get_register_cooldown(&env)
+    }
+
+    /// Cap how far past registration a project deadline may be set, in
+    /// seconds. Admin or above only.
+    pub fn set_max_deadline_horizon(env: Env, caller: Address, horizon: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_max_deadline_horizon(&env, horizon);
+        events::emit_max_deadline_horizon_updated(&env, caller, horizon);
+    }
+
+    pub fn get_max_deadline_horizon(env: Env) -> Option<u64> {
+        storage::get_max_deadline_horizon(&env)
+    }
+
+    /// Allow or forbid Admin and SuperAdmin from registering projects
+    /// directly. When forbidden, only ProjectManagers may register.
+    /// SuperAdmin only.
+    pub fn set_admins_can_register(env: Env, caller: Address, allowed: bool) {
+        caller.require_auth();
+        rbac::require_super_admin(&env, &caller);
+        storage::set_admins_can_register(&env, allowed);
+        events::emit_admins_can_register_set(&env, caller, allowed);
+    }
+
+    pub fn get_admins_can_register(env: Env) -> bool {
+        storage::get_admins_can_register(&env)
+    }
+
+    /// Require the creator to still hold a role that may register projects
+    /// when a project is verified or its funds are released. Off by default.
+    pub fn set_require_creator_role(env: Env, caller: Address, required: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_require_creator_role(&env, required);
+        events::emit_creator_role_required_set(&env, caller, required);
+    }
+
+    pub fn get_require_creator_role(env: Env) -> bool {
+        storage::get_require_creator_role(&env)
+    }
+
+    /// Add `token` to the global approved-token registry. Admin or above only.
+    pub fn approve_token(env: Env, caller: Address, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_token_approved(&env, &token, true);
+        events::emit_token_approval_set(&env, caller, token, true);
+    }
+
+    /// Remove `token` from the global approved-token registry. Projects that
+    /// already accept it are unaffected. Admin or above only.
+    pub fn unapprove_token(env: Env, caller: Address, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_token_approved(&env, &token, false);
+        events::emit_token_approval_set(&env, caller, token, false);
+    }
+
+    pub fn is_token_approved(env: Env, token: Address) -> bool {
+        storage::is_token_approved(&env, &token)
+    }
+
+    /// Require every token a new project accepts to be in the global
+    /// registry. Off by default. Admin or above only.
+    pub fn set_token_gate(env: Env, caller: Address, enabled: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_token_gate_enabled(&env, enabled);
+        events::emit_token_gate_set(&env, caller, enabled);
+    }
+
+    pub fn is_token_gate_enabled(env: Env) -> bool {
+        storage::is_token_gate_enabled(&env)
+    }
+
+    /// Set how many tokens a new project may accept. Projects already
+    /// registered keep their tokens. Admin or above only; `max` must be
+    /// between 1 and `MAX_TOKENS_CEILING`.
+    pub fn set_max_tokens_per_project(env: Env, caller: Address, max: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if max == 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if max > MAX_TOKENS_CEILING {
+            panic_with_error!(&env, Error::TooManyTokens);
+        }
+        storage::set_max_tokens_per_project(&env, max);
+        events::emit_max_tokens_updated(&env, caller, max);
+    }
+
+    pub fn get_max_tokens_per_project(env: Env) -> u32 {
+        storage::get_max_tokens_per_project(&env)
+    }
+
+    /// Add `account` to, or remove it from, the institution registry that
+    /// `institutions_only` projects accept deposits from. Admin or above only.
+    pub fn set_institution(env: Env, caller: Address, account: Address, institution: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_institution(&env, &account, institution);
+        events::emit_institution_set(&env, caller, account, institution);
+    }
+
+    pub fn is_institution(env: Env, account: Address) -> bool {
+        storage::is_institution(&env, &account)
+    }
+
+    /// Cap the amount a single deposit may carry. Admin or above only;
+    /// `max` must be positive.
+    pub fn set_max_deposit_per_tx(env: Env, caller: Address, max: i128) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if max <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_max_deposit_per_tx(&env, max);
+        events::emit_max_deposit_updated(&env, caller, max);
+    }
+
+    pub fn get_max_deposit_per_tx(env: Env) -> i128 {
+        storage::get_max_deposit_per_tx(&env)
+    }
+
+    /// Set an anti-dust floor applied to deposits into every project.
+    /// Projects may set a higher minimum of their own. Admin or above only.
+    pub fn set_global_min_deposit(env: Env, caller: Address, min: i128) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if min < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_global_min_deposit(&env, min);
+        events::emit_global_min_deposit_updated(&env, caller, min);
+    }
+
+    pub fn get_global_min_deposit(env: Env) -> i128 {
+        storage::get_global_min_deposit(&env)
+    }
+
+    /// Set the share of released funds, in basis points, paid to the oracle
+    /// that verified the project. Capped at 1000 (10%) like the protocol
+    /// fee. Admin or above only.
+    pub fn set_oracle_reward_bps(env: Env, caller: Address, bps: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if bps > 1000 {
+            panic_with_error!(&env, Error::InvalidFeeBasisPoints);
+        }
+        storage::set_oracle_reward_bps(&env, bps);
+        events::emit_oracle_reward_updated(&env, caller, bps);
+    }
+
+    pub fn get_oracle_reward_bps(env: Env) -> u32 {
+        storage::get_oracle_reward_bps(&env)
+    }
+
+    /// Set the deposit fee schedule as `(min amount, bps)` tiers, sorted
+    /// ascending by threshold. A deposit pays the bps of the highest tier it
+    /// reaches, so larger donations can be charged less. Each tier is capped
+    /// at 1000 bps. Admin or above only.
+    pub fn set_fee_tiers(env: Env, caller: Address, tiers: Vec<(i128, u32)>) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if tiers.is_empty() {
+            panic_with_error!(&env, Error::InvalidFeeTiers);
+        }
+        let mut previous: Option<i128> = None;
+        for (threshold, bps) in tiers.iter() {
+            if threshold < 0 || previous.is_some_and(|p| threshold <= p) {
+                panic_with_error!(&env, Error::InvalidFeeTiers);
+            }
+            if bps > 1000 {
+                panic_with_error!(&env, Error::InvalidFeeBasisPoints);
+            }
+            previous = Some(threshold);
+        }
+        storage::set_fee_tiers(&env, &tiers);
+        events::emit_fee_tiers_updated(&env, caller, tiers);
+    }
+
+    pub fn get_fee_tiers(env: Env) -> Vec<(i128, u32)> {
+        storage::get_fee_tiers(&env)
+    }
+
+    /// Choose how fee and oracle reward cuts round. The creator always
+    /// receives whatever is left, so a release pays out the drained balance
+    /// exactly. Admin or above only.
+    pub fn set_rounding_mode(env: Env, caller: Address, mode: RoundingMode) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_rounding_mode(&env, &mode);
+        events::emit_rounding_mode_updated(&env, caller, mode);
+    }
+
+    pub fn get_rounding_mode(env: Env) -> RoundingMode {
+        storage::get_rounding_mode(&env)
+    }
+
+    pub fn add_to_whitelist(env: Env, caller: Address, project_id: u64, address: Address) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        storage::add_to_whitelist(&env, project_id, &address);
+        events::emit_whitelist_added(&env, project_id, address);
+    }
+
+    pub fn remove_from_whitelist(env: Env, caller: Address, project_id: u64, address: Address) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        storage::remove_from_whitelist(&env, project_id, &address);
+        events::emit_whitelist_removed(&env, project_id, address);
+    }
+
+    /// Public project view. `proof_hash` is zeroed; auditors and admins read
+    /// it through `get_proof_hash`.
+    pub fn get_project(env: Env, project_id: u64) -> Project {
+        Self::redact_proof(&env, storage::load_project(&env, project_id))
+    }
+
+    /// Stored `proof_hash` of `project_id`. Auditor or admin-or-above only.
+    pub fn get_proof_hash(env: Env, caller: Address, project_id: u64) -> BytesN<32> {
+        caller.require_auth();
+        rbac::require_any_of(&env, &caller, &[Role::Auditor, Role::Admin, Role::SuperAdmin]);
+        storage::load_project_config(&env, project_id).proof_hash
+    }
+
+    fn redact_proof(env: &Env, mut project: Project) -> Project {
+        project.proof_hash = BytesN::from_array(env, &[0u8; 32]);
+        project
+    }
+
+    /// Re-emit the `proj_cr` event for an existing project with its current
+    /// data and extend its storage TTL, so late indexers can backfill.
+    /// Anyone may call; limited to once per `ANNOUNCE_COOLDOWN` per project.
+    pub fn announce(env: Env, project_id: u64) {
+        // Loading the pair bumps the TTL of both config and state.
+        let (config, _) = load_project_pair(&env, project_id);
+
+        let now = env.ledger().timestamp();
+        if let Some(last) = storage::get_last_announce(&env, project_id) {
+            if now < last.saturating_add(ANNOUNCE_COOLDOWN) {
+                panic_with_error!(&env, Error::AnnounceTooSoon);
+            }
+        }
+        storage::set_last_announce(&env, project_id, now);
+
+        if let Some(token) = config.accepted_tokens.get(0) {
+            events::emit_project_created(
+                &env,
+                project_id,
+                config.creator,
+                token,
+                config.goal,
+                config.hash_algo,
+                config.category,
+                config.display_goal_usd,
+            );
+        }
+    }
+
+    /// Whether `candidate` equals the project's stored `proof_hash`, so callers
+    /// can check a proof without fetching the hash itself.
+    pub fn proof_matches(env: Env, project_id: u64, candidate: BytesN<32>) -> bool {
+        storage::load_project_config(&env, project_id).proof_hash == candidate
+    }
+
+    /// Whether the first-token balance has reached `goal`, and the ledger
+    /// time it was reached if recorded. `(false, 0)` while the goal is unmet.
+    pub fn goal_status(env: Env, project_id: u64) -> (bool, u64) {
+        let config = storage::load_project_config(&env, project_id);
+        let reached = match config.accepted_tokens.get(0) {
+            Some(token) => {
+                !config.open_ended
+                    && storage::get_token_balance(&env, project_id, &token) >= config.goal
+            }
+            None => false,
+        };
+        if !reached {
+            return (false, 0);
+        }
+        (true, storage::get_goal_reached_at(&env, project_id))
+    }
+
+    /// Creation, goal, verification and deadline timestamps in one call.
+    pub fn get_project_timeline(env: Env, project_id: u64) -> ProjectTimeline {
+        let (config, state) = load_project_pair(&env, project_id);
+        ProjectTimeline {
+            created_at: config.created_at,
+            goal_reached_at: storage::get_goal_reached_at(&env, project_id),
+            verified_at: state.last_proof_time,
+            deadline: config.deadline,
+        }
+    }
+
+    /// Creator of `project_id`, read from the project config alone.
+    pub fn get_creator(env: Env, project_id: u64) -> Address {
+        storage::load_project_config(&env, project_id).creator
+    }
+
+    /// Number of live projects: registered and not yet completed, expired
+    /// or cancelled. Unlike the project counter this goes down as projects
+    /// finish.
+    pub fn get_active_project_count(env: Env) -> u64 {
+        storage::get_active_project_count(&env)
+    }
+
+    /// ID the next `register_project` call will be assigned.
+    pub fn peek_next_project_id(env: Env) -> u64 {
+        storage::peek_next_project_id(&env)
+    }
+
+    /// Load every project in `ids` that exists, preserving the given order.
+    /// Unknown IDs are skipped. At most 50 IDs per call.
+    pub fn get_projects(env: Env, ids: Vec<u64>) -> Vec<Project> {
+        if ids.len() > MAX_BATCH_PROJECTS {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+        let mut projects = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(project) = storage::maybe_load_project(&env, id) {
+                projects.push_back(Self::redact_proof(&env, project));
+            }
+        }
+        projects
+    }
+
+    /// IDs of `Funding` projects whose deadline is at most `seconds` away
+    /// (including ones already past it but not yet expired), for keepers.
+    /// Scans IDs `start..start + limit`, with `limit` capped at 50.
+    pub fn projects_expiring_within(env: Env, seconds: u64, start: u64, limit: u32) -> Vec<u64> {
+        let horizon = env.ledger().timestamp().saturating_add(seconds);
+        let end = start
+            .saturating_add(limit.min(MAX_BATCH_PROJECTS) as u64)
+            .min(storage::peek_next_project_id(&env));
+        let mut ids = Vec::new(&env);
+        for id in start..end {
+            if let Some(project) = storage::maybe_load_project(&env, id) {
+                if project.status == ProjectStatus::Funding && project.deadline <= horizon {
+                    ids.push_back(id);
+                }
+            }
+        }
+        ids
+    }
+
+    pub fn get_balance(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_token_balance(&env, project_id, &token)
+    }
+
+    /// Like `get_balance`, but panics with `TokenNotAccepted` when `token`
+    /// is not one of the project's accepted tokens instead of returning 0.
+    pub fn get_accepted_token_balance(env: Env, project_id: u64, token: Address) -> i128 {
+        let config = storage::load_project_config(&env, project_id);
+        if !config.accepts_token(&token) {
+            panic_with_error!(&env, Error::TokenNotAccepted);
+        }
+        storage::get_token_balance(&env, project_id, &token)
+    }
+
+    /// Page through `project_id`'s donation log in deposit order, starting at
+    /// sequence `start`. Returns at most `limit` entries (capped at 50);
+    /// an empty vec past the end of the log.
+    pub fn get_donations(env: Env, project_id: u64, start: u32, limit: u32) -> Vec<DonationRecord> {
+        let end = start
+            .saturating_add(limit.min(MAX_DONATION_PAGE))
+            .min(storage::get_donation_entry_count(&env, project_id));
+        let mut records = Vec::new(&env);
+        for seq in start..end {
+            if let Some(record) = storage::get_donation_entry(&env, project_id, seq) {
+                records.push_back(record);
+            }
+        }
+        records
+    }
+
+    /// Page through the on-chain audit log of role changes, releases and
+    /// pauses, oldest first, starting at sequence `start`. Only the latest
+    /// `AUDIT_LOG_SIZE` records are kept, so a `start` older than that
+    /// begins at the oldest retained record. Returns at most `limit`
+    /// entries (capped at 50).
+    pub fn get_audit_log(env: Env, start: u32, limit: u32) -> Vec<AuditRecord> {
+        let count = storage::get_audit_count(&env);
+        let start = start.max(count.saturating_sub(AUDIT_LOG_SIZE));
+        let end = start.saturating_add(limit.min(MAX_AUDIT_PAGE)).min(count);
+        let mut records = Vec::new(&env);
+        for seq in start..end {
+            if let Some(record) = storage::get_audit_record(&env, seq) {
+                records.push_back(record);
+            }
+        }
+        records
+    }
+
+    /// Number of audit records ever written, including those the ring has
+    /// since overwritten; the next record gets this sequence.
+    pub fn get_audit_count(env: Env) -> u32 {
+        storage::get_audit_count(&env)
+    }
+
+    /// Number of entries in `project_id`'s donation log made at or after
+    /// `from_ts` and before `to_ts`. The log is in ledger-time order, so
+    /// this binary-searches it and reads O(log n) entries however large the
+    /// project is.
+    pub fn count_deposits_between(env: Env, project_id: u64, from_ts: u64, to_ts: u64) -> u32 {
+        if from_ts >= to_ts {
+            return 0;
+        }
+        let from = Self::first_donation_since(&env, project_id, from_ts);
+        let to = Self::first_donation_since(&env, project_id, to_ts);
+        to - from
+    }
+
+    /// Sequence of the first donation log entry with timestamp >= `ts`, or
+    /// the log length if there is none.
+    fn first_donation_since(env: &Env, project_id: u64, ts: u64) -> u32 {
+        let (mut low, mut high) = (0, storage::get_donation_entry_count(env, project_id));
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match storage::get_donation_entry(env, project_id, mid) {
+                Some(record) if record.timestamp < ts => low = mid + 1,
+                _ => high = mid,
+            }
+        }
+        low
+    }
+
+    /// Page through the IDs of projects that accept `token`, starting at
+    /// index `start`. Registration order, except that a project dropping
+    /// the token (`remove_token`, `migrate_token`) hands its slot to the
+    /// last one. Scans at most `limit` index entries (capped at 50);
+    /// `Unlisted` projects are skipped, so a page may hold fewer IDs.
+    /// Includes projects that are no longer funding.
+    pub fn projects_accepting(env: Env, token: Address, start: u32, limit: u32) -> Vec<u64> {
+        let end = start
+            .saturating_add(limit.min(MAX_BATCH_PROJECTS))
+            .min(storage::get_token_project_count(&env, &token));
+        let mut ids = Vec::new(&env);
+        for index in start..end {
+            if let Some(id) = storage::get_token_project(&env, &token, index) {
+                let listed = storage::maybe_load_project_config(&env, id)
+                    .is_some_and(|config| config.visibility == Visibility::Public);
+                if listed {
+                    ids.push_back(id);
+                }
+            }
+        }
+        ids
+    }
+
+    /// Page through the IDs of projects `donator` has contributed to, in
+    /// order of first donation, starting at index `start`. Returns at most
+    /// `limit` IDs (capped at 50); each project appears once. Anonymous
+    /// deposits are not indexed.
+    pub fn projects_donated_by(env: Env, donator: Address, start: u32, limit: u32) -> Vec<u64> {
+        let projects = storage::get_donor_projects(&env, &donator);
+        let end = start
+            .saturating_add(limit.min(MAX_BATCH_PROJECTS))
+            .min(projects.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        projects.slice(start..end)
+    }
+
+    /// Page through the IDs of projects tagged `tag`, in tagging order,
+    /// starting at index `start`. Scans at most `limit` entries (capped at
+    /// 50); `Unlisted` projects are skipped, so a page may hold fewer IDs.
+    pub fn projects_by_tag(env: Env, tag: Symbol, start: u32, limit: u32) -> Vec<u64> {
+        let projects = storage::get_tag_projects(&env, &tag);
+        let end = start
+            .saturating_add(limit.min(MAX_BATCH_PROJECTS))
+            .min(projects.len());
+        let mut ids = Vec::new(&env);
+        for index in start..end {
+            let id = projects.get_unchecked(index);
+            if storage::load_project_config(&env, id).visibility == Visibility::Public {
+                ids.push_back(id);
+            }
+        }
+        ids
+    }
+
+    /// Attest `donator`'s recorded contribution of `token` to `project_id`
+    /// as of the current ledger, with a sha256 over the attested fields.
+    /// The amount is the donor's refundable balance, so it drops to zero
+    /// once refunded. Read-only.
+    pub fn attest_contribution(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+    ) -> ContributionAttestation {
+        storage::load_project_config(&env, project_id);
+        let amount = storage::get_donator_balance(&env, project_id, &token, &donator);
+        let ledger = env.ledger().sequence();
+        let fields = (project_id, donator.clone(), token.clone(), amount, ledger).to_xdr(&env);
+        ContributionAttestation {
+            project_id,
+            donator,
+            token,
+            amount,
+            ledger,
+            hash: env.crypto().sha256(&fields).to_bytes(),
+        }
+    }
+
+    /// Total number of entries in `project_id`'s donation log.
+    pub fn get_donation_entry_count(env: Env, project_id: u64) -> u32 {
+        storage::get_donation_entry_count(&env, project_id)
+    }
+
+    /// Balances of `tokens` for `project_id`, aligned to the input order.
+    /// Tokens never deposited read as 0. At most 20 tokens per call.
+    pub fn get_token_balances(env: Env, project_id: u64, tokens: Vec<Address>) -> Vec<i128> {
+        if tokens.len() > MAX_BATCH_TOKENS {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+        let mut balances = Vec::new(&env);
+        for token in tokens.iter() {
+            balances.push_back(storage::get_token_balance(&env, project_id, &token));
+        }
+        balances
+    }
+
+    pub fn get_project_balances(env: Env, project_id: u64) -> ProjectBalances {
+        let project = storage::load_project(&env, project_id);
+        storage::get_all_balances(&env, &project)
+    }
+
+    /// Record the project's current per-token balances as a point-in-time
+    /// statement and return its snapshot index. The ledger sequence is
+    /// carried on the `snapshot` event. Creator only; at most
+    /// `MAX_SNAPSHOTS` per project.
+    pub fn take_snapshot(env: Env, project_id: u64) -> u32 {
+        let project = storage::load_project(&env, project_id);
+        project.creator.require_auth();
+        if storage::get_snapshot_count(&env, project_id) >= MAX_SNAPSHOTS {
+            panic_with_error!(&env, Error::SnapshotLimitReached);
+        }
+        let balances = storage::get_all_balances(&env, &project);
+        let index = storage::push_snapshot(&env, project_id, &balances);
+        events::emit_snapshot_taken(&env, project_id, index, env.ledger().sequence());
+        index
+    }
+
+    pub fn get_snapshot(env: Env, project_id: u64, index: u32) -> Option<ProjectBalances> {
+        storage::get_snapshot(&env, project_id, index)
+    }
+
+    /// Total amount of `token` currently held on behalf of all projects.
+    pub fn get_global_balance(env: Env, token: Address) -> i128 {
+        storage::get_global_token_balance(&env, &token)
+    }
+
+    pub fn pause_project(env: Env, caller: Address, project_id: u64) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        let mut state = storage::load_project_state(&env, project_id);
+        state.paused = true;
+        storage::save_project_state(&env, project_id, &state);
+        storage::push_audit_record(
+            &env,
+            &caller,
+            events::PROJECT_PAUSED,
+            AuditSubject::Project(project_id),
+        );
+        events::emit_project_paused(&env, project_id, caller);
+    }
+
+    pub fn unpause_project(env: Env, caller: Address, project_id: u64) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        let mut state = storage::load_project_state(&env, project_id);
+        state.paused = false;
+        storage::save_project_state(&env, project_id, &state);
+        storage::push_audit_record(
+            &env,
+            &caller,
+            events::PROJECT_UNPAUSED,
+            AuditSubject::Project(project_id),
+        );
+        events::emit_project_unpaused(&env, project_id, caller);
+    }
+
+    /// Operator switch for a single project's pause flag. Unlike
+    /// `pause_project`, only admin-or-above may call it and it still works
+    /// while the protocol itself is paused. Refunds are unaffected.
+    pub fn set_project_paused(env: Env, caller: Address, project_id: u64, paused: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        let mut state = storage::load_project_state(&env, project_id);
+        state.paused = paused;
+        storage::save_project_state(&env, project_id, &state);
+        if paused {
+            events::emit_project_paused(&env, project_id, caller);
+        } else {
+            events::emit_project_unpaused(&env, project_id, caller);
+        }
+    }
+
+    pub fn is_project_paused(env: Env, project_id: u64) -> bool {
+        storage::load_project_state(&env, project_id).paused
+    }
+
+    /// Let the creator temporarily stop (or resume) accepting donations,
+    /// e.g. during a compliance review. Independent of the admin project
+    /// pause; verification and refunds are unaffected.
+    pub fn set_donations_paused(env: Env, creator: Address, project_id: u64, paused: bool) {
+        creator.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        storage::set_donations_paused(&env, project_id, paused);
+        events::emit_donations_paused_set(&env, project_id, creator, paused);
+    }
+
+    pub fn is_donations_paused(env: Env, project_id: u64) -> bool {
+        storage::is_donations_paused(&env, project_id)
+    }
+
+    /// Require deposits of `token` into `project_id` to be whole multiples of
+    /// `increment`. Creator only; 0 removes the restriction.
+    pub fn set_deposit_increment(
+        env: Env,
+        creator: Address,
+        project_id: u64,
+        token: Address,
+        increment: i128,
+    ) {
+        creator.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if increment < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if !config.accepts_token(&token) {
+            panic_with_error!(&env, Error::TokenNotAccepted);
+        }
+        storage::set_deposit_increment(&env, project_id, &token, increment);
+        events::emit_deposit_increment_set(&env, project_id, token, increment);
+    }
+
+    pub fn get_deposit_increment(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_deposit_increment(&env, project_id, &token)
+    }
+
+    /// Cap how much any single donor may contribute to `project_id` in each
+    /// accepted token, summed across deposits. 0 removes the cap. Creator only.
+    pub fn set_max_per_donor(env: Env, creator: Address, project_id: u64, cap: i128) {
+        creator.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if cap < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_max_per_donor(&env, project_id, cap);
+        events::emit_max_per_donor_set(&env, project_id, cap);
+    }
+
+    pub fn get_max_per_donor(env: Env, project_id: u64) -> i128 {
+        storage::get_max_per_donor(&env, project_id)
+    }
+
+    /// Reject deposits into `project_id` below `min`. The global minimum
+    /// still applies when it is higher. 0 removes it. Creator only.
+    pub fn set_min_deposit(env: Env, creator: Address, project_id: u64, min: i128) {
+        creator.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if min < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_min_deposit(&env, project_id, min);
+        events::emit_min_deposit_set(&env, project_id, min);
+    }
+
+    /// Effective minimum for a deposit into `project_id`: the larger of the
+    /// global and the project minimum.
+    pub fn get_min_deposit(env: Env, project_id: u64) -> i128 {
+        storage::get_global_min_deposit(&env).max(storage::get_min_deposit(&env, project_id))
+    }
+
+    /// Require every hash in `proofs` to be submitted, one per `verify_proof`
+    /// call and in any order, before the project is verified. Replaces the
+    /// single `proof_hash` check; an empty list restores it. Duplicate
+    /// hashes are rejected. Replacing the list forgets proofs matched against
+    /// the old one. Creator or admin, while the project is still `Funding`.
+    pub fn set_required_proofs(env: Env, caller: Address, project_id: u64, proofs: Vec<BytesN<32>>) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        for i in 0..proofs.len() {
+            if proofs.last_index_of(proofs.get(i).unwrap()) != Some(i) {
+                panic_with_error!(&env, Error::VerificationFailed);
+            }
+        }
+        for index in 0..config.required_proofs.len() {
+            storage::clear_proof_matched(&env, project_id, index);
+        }
+        let count = proofs.len();
+        config.required_proofs = proofs;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_required_proofs_set(&env, project_id, count);
+    }
+
+    /// Whether required proof `index` of `project_id` has been matched.
+    pub fn is_proof_matched(env: Env, project_id: u64, index: u32) -> bool {
+        storage::is_proof_matched(&env, project_id, index)
+    }
+
+    /// Release funds linearly over `duration` seconds via `claim_vested`
+    /// instead of in one `claim_funds` lump. 0 restores the lump release.
+    /// Creator or admin, while the project is still `Funding`.
+    pub fn set_vesting_duration(env: Env, caller: Address, project_id: u64, duration: u64) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        storage::set_vesting_duration(&env, project_id, duration);
+        events::emit_vesting_duration_set(&env, project_id, duration);
+    }
+
+    pub fn get_vesting_duration(env: Env, project_id: u64) -> u64 {
+        storage::get_vesting_duration(&env, project_id)
+    }
+
+    /// Gross amount of `token` already paid out through `claim_vested`.
+    pub fn get_vesting_claimed(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_vesting_claimed(&env, project_id, &token)
+    }
+
+    /// Set a goal for a secondary accepted token. The first token's goal is
+    /// the project `goal`. Creator or admin, while `Funding`; 0 clears it.
+    pub fn set_token_goal(env: Env, caller: Address, project_id: u64, token: Address, goal: i128) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if goal < 0 {
+            panic_with_error!(&env, Error::InvalidGoal);
+        }
+        match config.accepted_tokens.first_index_of(&token) {
+            Some(i) if i > 0 => {}
+            _ => panic_with_error!(&env, Error::TokenNotAccepted),
+        }
+        storage::set_token_goal(&env, project_id, &token, goal);
+        events::emit_token_goal_set(&env, project_id, token, goal);
+    }
+
+    pub fn get_token_goal(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_token_goal(&env, project_id, &token)
+    }
+
+    /// Stop `project_id` accepting a secondary `token`. Refused while the
+    /// project still holds a balance in it, which must first be released or
+    /// refunded; the primary token cannot be removed, nor any token of a
+    /// project with locked tokens. Creator or admin only.
+    pub fn remove_token(env: Env, caller: Address, project_id: u64, token: Address) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let mut config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if config.tokens_locked {
+            panic_with_error!(&env, Error::TokensLocked);
+        }
+        let index = match config.accepted_tokens.first_index_of(&token) {
+            Some(i) if i > 0 => i,
+            _ => panic_with_error!(&env, Error::TokenNotAccepted),
+        };
+        if storage::get_token_balance(&env, project_id, &token) > 0 {
+            panic_with_error!(&env, Error::TokenHasBalance);
+        }
+        config.accepted_tokens.remove(index);
+        storage::set_token_goal(&env, project_id, &token, 0);
+        storage::save_project_config(&env, project_id, &config);
+        storage::remove_token_project(&env, &token, project_id);
+        events::emit_token_removed(&env, project_id, token);
+    }
+
+    /// Re-point `project_id` from `old_token` to `new_token` after the
+    /// token's contract was redeployed at a new address. Accounting only:
+    /// the recorded balance and token goal move across and the address is
+    /// swapped in place in `accepted_tokens`; no tokens are transferred.
+    /// Donor balances are re-keyed to `new_token` on first access, so
+    /// refunds are paid in `new_token` (and rejected in `old_token`). Only
+    /// while `Funding` or `Active` and the project's tokens are not locked,
+    /// and `new_token` must not already be accepted. Admin or above only.
+    pub fn migrate_token(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        old_token: Address,
+        new_token: Address,
+    ) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        let (mut config, state) = load_project_pair(&env, project_id);
+        Self::require_project_not_paused(&env, &state);
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if config.tokens_locked {
+            panic_with_error!(&env, Error::TokensLocked);
+        }
+        let Some(index) = config.accepted_tokens.first_index_of(&old_token) else {
+            panic_with_error!(&env, Error::TokenNotAccepted);
+        };
+        if config.accepts_token(&new_token) {
+            panic_with_error!(&env, Error::DuplicateToken);
+        }
+
+        let balance = storage::drain_token_balance(&env, project_id, &old_token);
+        if balance > 0 {
+            storage::add_to_token_balance(&env, project_id, &new_token, balance);
+        }
+        let goal = storage::get_token_goal(&env, project_id, &old_token);
+        if goal > 0 {
+            storage::set_token_goal(&env, project_id, &old_token, 0);
+            storage::set_token_goal(&env, project_id, &new_token, goal);
+        }
+        config.accepted_tokens.set(index, new_token.clone());
+        storage::save_project_config(&env, project_id, &config);
+        storage::remove_token_project(&env, &old_token, project_id);
+        storage::push_token_project(&env, &new_token, project_id);
+        // Migrating back to the token `old_token` replaced would link the
+        // two tokens to each other.
+        if storage::get_migrated_token(&env, project_id, &old_token).as_ref() == Some(&new_token) {
+            storage::clear_migrated_token(&env, project_id, &old_token);
+        }
+        storage::set_migrated_token(&env, project_id, &new_token, &old_token);
+        events::emit_token_migrated(&env, project_id, old_token, new_token, balance);
+    }
+
+    /// Whether every token goal of `project_id` is currently met.
+    pub fn is_goal_met(env: Env, project_id: u64) -> bool {
+        let config = storage::load_project_config(&env, project_id);
+        Self::goals_met(&env, project_id, &config)
+    }
+
+    /// Set the real proof hash of a project registered with an all-zero
+    /// one. Only allowed once, while the project still carries the default
+    /// hash and has not been verified. Oracle or admin only.
+    pub fn set_proof_hash(env: Env, caller: Address, project_id: u64, hash: BytesN<32>) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        rbac::require_any_of(&env, &caller, &[Role::Oracle, Role::Admin, Role::SuperAdmin]);
+        let (mut config, state) = load_project_pair(&env, project_id);
         if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
             panic_with_error!(&env, Error::InvalidTransition);
         }
-        if env.ledger().timestamp() < config.deadline {
-            panic_with_error!(&env, Error::ProjectNotExpired);
+        let default = Self::default_proof_hash(&env, project_id);
+        if config.proof_hash != default {
+            panic_with_error!(&env, Error::ProofHashAlreadySet);
         }
-        state.status = ProjectStatus::Expired;
-        state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-        save_project_state(&env, project_id, &state);
-        events::emit_project_expired(&env, project_id, config.deadline);
+        if hash == default || hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic_with_error!(&env, Error::VerificationFailed);
+        }
+        config.proof_hash = hash;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_proof_hash_set(&env, project_id, caller);
     }
 
-    pub fn reclaim_expired_funds(env: Env, creator: Address, project_id: u64) {
-        Self::require_not_paused(&env);
-        creator.require_auth();
-
-        let (config, state) = load_project_pair(&env, project_id);
+    /// Placeholder proof hash for projects registered without one: the
+    /// sha256 of the big-endian project ID.
+    fn default_proof_hash(env: &Env, project_id: u64) -> BytesN<32> {
+        let id = Bytes::from_array(env, &project_id.to_be_bytes());
+        env.crypto().sha256(&id).to_bytes()
+    }
 
-        if creator != config.creator {
-            panic_with_error!(&env, Error::NotAuthorized);
+    /// Record the digest algorithm (e.g. `sha256`, `keccak`) used to produce
+    /// the project's `proof_hash`. Creator or admin only, and only while the
+    /// project is still `Funding` so verifiers never see it change mid-flight.
+    pub fn set_hash_algo(env: Env, caller: Address, project_id: u64, hash_algo: Symbol) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
         }
-
-        if !matches!(
-            state.status,
-            ProjectStatus::Expired | ProjectStatus::Cancelled
-        ) {
+        if state.status != ProjectStatus::Funding {
             panic_with_error!(&env, Error::InvalidTransition);
         }
+        config.hash_algo = hash_algo.clone();
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_hash_algo_updated(&env, project_id, hash_algo);
+    }
 
-        if state.refund_expiry == 0 || env.ledger().timestamp() < state.refund_expiry {
-            panic_with_error!(&env, Error::RefundWindowActive);
+    /// Tag the project with a campaign category (e.g. `education`), which is
+    /// added as a topic on its `proj_cr` and `proj_fnd` events. Must be in
+    /// the allow-list when one is configured. Creator or admin only, while
+    /// the project is still `Funding`.
+    pub fn set_category(env: Env, caller: Address, project_id: u64, category: Symbol) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
         }
-
-        let contract_address = env.current_contract_address();
-        invariants_checker::check_no_recursive_state(&env);
-        invariants_checker::acquire_lock(&env);
-        for token in config.accepted_tokens.iter() {
-            let balance = drain_token_balance(&env, project_id, &token);
-            if balance > 0 {
-                let token_client = token::Client::new(&env, &token);
-                token_client.transfer(&contract_address, &config.creator, &balance);
-                events::emit_expired_funds_reclaimed(
-                    &env,
-                    project_id,
-                    config.creator.clone(),
-                    token,
-                    balance,
-                );
-            }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
         }
-        invariants_checker::release_lock(&env);
+        let allowed = storage::get_allowed_categories(&env);
+        if !allowed.is_empty() && !allowed.contains(&category) {
+            panic_with_error!(&env, Error::CategoryNotAllowed);
+        }
+        config.category = category.clone();
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_category_updated(&env, project_id, category);
     }
 
-    pub fn update_protocol_config(env: Env, caller: Address, fee_recipient: Address, fee_bps: u32) {
+    /// List or unlist the project in discovery queries. Unlisted projects
+    /// remain fully usable by ID. Creator only.
+    pub fn set_visibility(env: Env, caller: Address, project_id: u64, visibility: Visibility) {
+        Self::require_not_paused(&env);
         caller.require_auth();
-        rbac::require_role(&env, &caller, &Role::SuperAdmin);
-
-        if fee_bps > 1000 {
-            panic_with_error!(&env, Error::InvalidFeeBasisPoints);
+        let mut config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
         }
+        config.visibility = visibility.clone();
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_visibility_updated(&env, project_id, visibility);
+    }
 
-        let old_config = get_protocol_config(&env);
-        let new_config = ProtocolConfig {
-            fee_recipient,
-            fee_bps,
-        };
+    /// Record the goal's USD equivalent (6 decimals) for display; 0 clears
+    /// it. Purely informational. Creator or admin only, while the project is
+    /// still `Funding`; `announce` re-publishes it in `ProjectCreated`.
+    pub fn set_display_goal_usd(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        display_goal_usd: i128,
+    ) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if display_goal_usd < 0 {
+            panic_with_error!(&env, Error::InvalidGoal);
+        }
+        config.display_goal_usd = display_goal_usd;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_display_goal_set(&env, project_id, display_goal_usd);
+    }
 
-        set_protocol_config(&env, &new_config);
-        events::emit_protocol_config_updated(&env, old_config, new_config);
+    /// Set the minimum viable amount of the first accepted token; 0 removes
+    /// it. If the project expires with at least this much raised, donors
+    /// can no longer refund and the creator may reclaim the funds without
+    /// waiting out the refund window. May not exceed the goal. Creator or
+    /// admin only, while the project is still `Funding`.
+    pub fn set_soft_cap(env: Env, caller: Address, project_id: u64, soft_cap: i128) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if soft_cap < 0 || (!config.open_ended && soft_cap > config.goal) {
+            panic_with_error!(&env, Error::InvalidGoal);
+        }
+        config.soft_cap = soft_cap;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_soft_cap_set(&env, project_id, soft_cap);
     }
 
-    pub fn add_to_whitelist(env: Env, caller: Address, project_id: u64, address: Address) {
+    /// Promise donors that the project's accepted tokens will never change:
+    /// `remove_token` and `migrate_token` are refused from then on, even for
+    /// admins. There is no unlock. Creator or admin only, while the project
+    /// is still `Funding`.
+    pub fn lock_tokens(env: Env, caller: Address, project_id: u64) {
         Self::require_not_paused(&env);
         caller.require_auth();
-        let config = storage::load_project_config(&env, project_id);
+        let (mut config, state) = load_project_pair(&env, project_id);
         if caller != config.creator {
             rbac::require_admin_or_above(&env, &caller);
         }
-        storage::add_to_whitelist(&env, project_id, &address);
-        events::emit_whitelist_added(&env, project_id, address);
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        config.tokens_locked = true;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_tokens_locked(&env, project_id);
     }
 
-    pub fn remove_from_whitelist(env: Env, caller: Address, project_id: u64, address: Address) {
+    /// Replace the project's discovery tags. At most `MAX_TAGS` distinct
+    /// tags of up to nine characters each; an empty list clears them.
+    /// Creator or admin only, while the project is still `Funding`.
+    pub fn set_tags(env: Env, caller: Address, project_id: u64, tags: Vec<Symbol>) {
         Self::require_not_paused(&env);
         caller.require_auth();
-        let config = storage::load_project_config(&env, project_id);
+        let (mut config, state) = load_project_pair(&env, project_id);
         if caller != config.creator {
             rbac::require_admin_or_above(&env, &caller);
         }
-        storage::remove_from_whitelist(&env, project_id, &address);
-        events::emit_whitelist_removed(&env, project_id, address);
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if tags.len() > MAX_TAGS {
+            panic_with_error!(&env, Error::InvalidTags);
+        }
+        for (i, tag) in tags.iter().enumerate() {
+            // Symbols of nine characters or fewer are stored inline.
+            if tag.as_val().is_object() || tags.last_index_of(&tag) != Some(i as u32) {
+                panic_with_error!(&env, Error::InvalidTags);
+            }
+        }
+        for tag in config.tags.iter() {
+            storage::remove_tag_project(&env, &tag, project_id);
+        }
+        for tag in tags.iter() {
+            storage::add_tag_project(&env, &tag, project_id);
+        }
+        config.tags = tags.clone();
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_tags_updated(&env, project_id, tags);
     }
 
-    pub fn get_project(env: Env, project_id: u64) -> Project {
-        storage::load_project(&env, project_id)
+    /// Fix a goal that was registered wrongly. Admin only, while the project
+    /// is `Funding` and has taken no deposits; `reason` is recorded in the
+    /// `GoalCorrected` event for the audit trail.
+    pub fn correct_goal(env: Env, caller: Address, project_id: u64, new_goal: i128, reason: Symbol) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        let funded = state.donation_count > 0
+            || config
+                .accepted_tokens
+                .iter()
+                .any(|token| storage::get_token_balance(&env, project_id, &token) != 0);
+        if funded {
+            panic_with_error!(&env, Error::ProjectHasDeposits);
+        }
+        let min_goal = if config.open_ended { 0 } else { 1 };
+        if new_goal < min_goal || new_goal > MAX_GOAL {
+            panic_with_error!(&env, Error::InvalidGoal);
+        }
+        let old_goal = config.goal;
+        config.goal = new_goal;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_goal_corrected(&env, project_id, old_goal, new_goal, reason);
     }
 
-    pub fn get_balance(env: Env, project_id: u64, token: Address) -> i128 {
-        storage::get_token_balance(&env, project_id, &token)
+    /// Choose whether verification requires the funding goal to be met.
+    /// Open-ended projects have no goal and cannot be `AllOrNothing`.
+    /// Creator or admin only, while the project is still `Funding`.
+    pub fn set_funding_model(env: Env, caller: Address, project_id: u64, model: FundingModel) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if config.open_ended && model == FundingModel::AllOrNothing {
+            panic_with_error!(&env, Error::InvalidGoal);
+        }
+        config.funding_model = model.clone();
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_funding_model_updated(&env, project_id, model);
     }
 
-    pub fn get_project_balances(env: Env, project_id: u64) -> ProjectBalances {
-        let project = storage::load_project(&env, project_id);
-        storage::get_all_balances(&env, &project)
+    /// Refuse further deposits with `VerificationPending` once any oracle
+    /// confirmation is recorded. Creator or admin only, while the project is
+    /// still `Funding`.
+    pub fn set_lock_on_first_confirmation(env: Env, caller: Address, project_id: u64, lock: bool) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        config.lock_on_first_confirmation = lock;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_confirmation_lock_set(&env, project_id, lock);
     }
 
-    pub fn pause_project(env: Env, caller: Address, project_id: u64) {
+    /// Accept deposits only from registered institutions. Creator or admin
+    /// only, while the project is still `Funding`.
+    pub fn set_institutions_only(env: Env, caller: Address, project_id: u64, only: bool) {
         Self::require_not_paused(&env);
         caller.require_auth();
-        let config = storage::load_project_config(&env, project_id);
+        let (mut config, state) = load_project_pair(&env, project_id);
         if caller != config.creator {
             rbac::require_admin_or_above(&env, &caller);
         }
-        let mut state = storage::load_project_state(&env, project_id);
-        state.paused = true;
-        storage::save_project_state(&env, project_id, &state);
-        events::emit_project_paused(&env, project_id, caller);
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        config.institutions_only = only;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_institutions_only_set(&env, project_id, only);
     }
 
-    pub fn unpause_project(env: Env, caller: Address, project_id: u64) {
+    /// Choose how deposits that would overshoot the goal are handled.
+    /// Creator or admin only, while the project is still `Funding`.
+    pub fn set_overshoot_policy(env: Env, caller: Address, project_id: u64, policy: OvershootPolicy) {
         Self::require_not_paused(&env);
         caller.require_auth();
-        let config = storage::load_project_config(&env, project_id);
+        let (mut config, state) = load_project_pair(&env, project_id);
         if caller != config.creator {
             rbac::require_admin_or_above(&env, &caller);
         }
-        let mut state = storage::load_project_state(&env, project_id);
-        state.paused = false;
-        storage::save_project_state(&env, project_id, &state);
-        events::emit_project_unpaused(&env, project_id, caller);
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        config.overshoot_policy = policy.clone();
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_overshoot_policy_updated(&env, project_id, policy);
+    }
+
+    /// Send released funds to `release_to` instead of the creator, e.g. a
+    /// custody contract. The creator keeps control of the project.
+    /// Creator only, while the project is still `Funding`.
+    pub fn set_release_to(env: Env, creator: Address, project_id: u64, release_to: Address) {
+        Self::require_not_paused(&env);
+        creator.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        config.release_to = release_to.clone();
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_release_to_updated(&env, project_id, release_to);
     }
 
     pub fn extend_deadline(env: Env, caller: Address, project_id: u64, new_deadline: u64) {
@@ -903,10 +3905,39 @@ impl PifpProtocol {
         }
         let old = config.deadline;
         config.deadline = new_deadline;
+        // A deposit window that tracked the deadline keeps tracking it.
+        if config.deposit_deadline == old {
+            config.deposit_deadline = new_deadline;
+        }
         storage::save_project_config(&env, project_id, &config);
         events::emit_deadline_extended(&env, project_id, old, new_deadline);
     }
 
+    /// Close the deposit window at `deposit_deadline`, ahead of the project
+    /// deadline. Must lie between now and the project `deadline`.
+    pub fn set_deposit_deadline(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        deposit_deadline: u64,
+    ) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Active && state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if deposit_deadline < env.ledger().timestamp() || deposit_deadline > config.deadline {
+            panic_with_error!(&env, Error::InvalidDeadline);
+        }
+        config.deposit_deadline = deposit_deadline;
+        storage::save_project_config(&env, project_id, &config);
+        events::emit_deposit_deadline_set(&env, project_id, deposit_deadline);
+    }
+
     pub fn verify_and_release(env: Env, oracle: Address, project_id: u64, proof_hash: BytesN<32>) {
         Self::verify_proof(env.clone(), oracle, project_id, proof_hash);
         // We can't immediately claim_funds because of GRACE_PERIOD.
@@ -923,6 +3954,134 @@ impl PifpProtocol {
         if state.paused {
             panic_with_error!(env, Error::ProjectPaused);
         }
+    }
+
+    /// Reject a registration from `creator` that falls inside the configured
+    /// cooldown, then record this registration's timestamp.
+    fn enforce_register_cooldown(env: &Env, creator: &Address) {
+        let now = env.ledger().timestamp();
+        let cooldown = storage::get_register_cooldown(env);
+        if let Some(last) = storage::get_last_register(env, creator) {
+            if now < last.saturating_add(cooldown) {
+                panic_with_error!(env, Error::RegisterCooldown);
+            }
+        }
+        storage::set_last_register(env, creator, now);
+    }
+
+    /// Mark the required proof equal to `submitted` as matched. Returns true
+    /// once every required proof of the project has been matched. An already
+    /// matched proof is rejected unless `allow_matched` (the submission is a
+    /// new oracle vote).
+    fn match_required_proof(
+        env: &Env,
+        config: &ProjectConfig,
+        project_id: u64,
+        oracle: &Address,
+        submitted: &BytesN<32>,
+        allow_matched: bool,
+    ) -> bool {
+        let index = match config.required_proofs.first_index_of(submitted) {
+            Some(i) => i,
+            None => panic_with_error!(env, Error::ProofMismatch),
+        };
+        let already_matched = storage::is_proof_matched(env, project_id, index);
+        if !already_matched {
+            storage::set_proof_matched(env, project_id, index);
+        } else if !allow_matched {
+            panic_with_error!(env, Error::MilestoneAlreadyReleased);
+        }
+
+        let mut remaining = 0;
+        for i in 0..config.required_proofs.len() {
+            if !storage::is_proof_matched(env, project_id, i) {
+                remaining += 1;
+            }
+        }
+        if !already_matched {
+            events::emit_proof_matched(env, project_id, index, oracle.clone(), remaining);
+        }
+        remaining == 0
+    }
+
+    /// Refuse verification while fewer addresses hold the Oracle role than
+    /// the configured `MinOracles` floor.
+    fn require_min_oracles(env: &Env) {
+        if rbac::oracle_count(env) < storage::get_min_oracles(env) {
+            panic_with_error!(env, Error::InsufficientOracles);
+        }
+    }
+
+    /// Move `state` to `status` and emit `status`, the one event every
+    /// lifecycle transition carries. The caller saves the state.
+    fn set_status(env: &Env, project_id: u64, state: &mut ProjectState, status: ProjectStatus) {
+        let old_status = core::mem::replace(&mut state.status, status.clone());
+        events::emit_status_changed(env, project_id, old_status, status);
+    }
+
+    /// Ledger time at which a refund window opened now will close.
+    fn refund_expiry_from_now(env: &Env) -> u64 {
+        env.ledger()
+            .timestamp()
+            .saturating_add(storage::get_refund_window(env))
+    }
+
+    /// Reject refunds unless the project is `Expired` or `Cancelled` and the
+    /// refund window is still open. A project found past its deadline is
+    /// marked `Expired` first.
+    fn require_refundable(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        project_id: u64,
+    ) {
+        if (state.status == ProjectStatus::Funding || state.status == ProjectStatus::Active)
+            && env.ledger().timestamp() >= config.deadline
+        {
+            Self::set_status(env, project_id, state, ProjectStatus::Expired);
+            state.refund_expiry = Self::refund_expiry_from_now(env);
+            save_project_state(env, project_id, state);
+            storage::decrement_active_project_count(env);
+        }
+
+        if !matches!(
+            state.status,
+            ProjectStatus::Expired | ProjectStatus::Cancelled
+        ) {
+            panic_with_error!(env, Error::ProjectNotExpired);
+        }
+        if state.status == ProjectStatus::Expired
+            && Self::soft_cap_reached(env, project_id, config)
+        {
+            panic_with_error!(env, Error::SoftCapReached);
+        }
+        if state.refund_expiry > 0 && env.ledger().timestamp() >= state.refund_expiry {
+            panic_with_error!(env, Error::RefundWindowExpired);
+        }
+    }
+
+    /// Reject verification unless the project is still open and before its
+    /// deadline. A project found past its deadline is marked `Expired`.
+    fn require_verifiable(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        project_id: u64,
+    ) {
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            ProjectStatus::Verified | ProjectStatus::Completed => {
+                panic_with_error!(env, Error::MilestoneAlreadyReleased)
+            }
+            _ => panic_with_error!(env, Error::InvalidTransition),
+        }
+
+        if env.ledger().timestamp() >= config.deadline {
+            state.status = ProjectStatus::Expired;
+            state.refund_expiry = Self::refund_expiry_from_now(env);
+            save_project_state(env, project_id, state);
+            panic_with_error!(env, Error::ProjectExpired);
+        }
 =======
         let mut project = load_project(&env, project_id);
 
@@ -933,7 +4092,7 @@ impl PifpProtocol {
         }
 
         if submitted_proof_hash != project.proof_hash {
-            panic_with_error!(&env, Error::GoalMismatch);
+            panic_with_error!(&env, Error::ProofMismatch);
         }
 
         project.status = ProjectStatus::Completed;
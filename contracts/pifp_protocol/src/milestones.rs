@@ -12,7 +12,7 @@ pub fn verify_milestone(
     submitted_hash: BytesN<32>,
 ) -> Result<u32, Error> {
     if milestone_index >= milestones.len() {
-        return Err(Error::InvalidTransition); // Out of bounds
+        return Err(Error::MilestoneNotFound);
     }
 
     let milestone = milestones.get(milestone_index).unwrap();
@@ -15,6 +15,9 @@
 //!
 //! - `RbacKey::SuperAdmin` → `Address`  — the one and only super-admin.
 //! - `RbacKey::Role(addr)` → `Role`     — the role held by `addr`, if any.
+//! - `RbacKey::RoleCount(role)` → `u32` — number of addresses holding `role`.
+//! - `RbacKey::RoleLabel(role)` → `String` — display name for `role`; defaults
+//!   to the variant name.
 //!
 //! ## Event emissions
 //!
@@ -23,8 +26,9 @@
 //!
 //! | Event topic prefix | Trigger |
 //! |--------------------|---------|
-//! | `role_set`         | Role granted or replaced |
+//! | `role_set`         | Role granted or replaced (carries the role's label) |
 //! | `role_del`         | Role revoked |
+//! | `role_lbl`         | Display label of a role changed |
 //!
 //! ## Threat model notes
 //!
@@ -35,7 +39,7 @@
 #![allow(unused)]
 #![allow(deprecated)]
 
-use soroban_sdk::{contractevent, contracttype, Address, Env, Vec};
+use soroban_sdk::{contractevent, contracttype, Address, Env, String, Vec};
 
 use crate::errors::Error;
 
@@ -43,9 +47,17 @@ use crate::errors::Error;
 pub struct RoleSet {
     pub target: Address,
     pub role: Role,
+    pub label: String,
     pub by: Option<Address>,
 }
 
+#[contractevent(topics = ["role_lbl"])]
+pub struct RoleLabelSet {
+    pub role: Role,
+    pub label: String,
+    pub by: Address,
+}
+
 #[contractevent]
 pub struct RoleDel {
     pub target: Address,
@@ -114,6 +126,29 @@ pub enum Role {
     ProjectManager,
 }
 
+/// What an address may do, derived from its role. Returned by
+/// `get_permissions` so UIs can render admin panels without re-implementing
+/// the guards below.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Permissions {
+    pub is_super_admin: bool,
+    /// Admin or SuperAdmin.
+    pub is_admin: bool,
+    /// May grant and revoke roles.
+    pub can_grant: bool,
+    /// May register projects (respects `AdminsCanRegister`).
+    pub can_register: bool,
+    /// May cancel `Active` projects via `cancel_project`.
+    pub can_cancel: bool,
+    /// May submit proofs via `verify_proof`.
+    pub can_verify: bool,
+    /// May expire projects past their deadline; open to everyone.
+    pub can_expire: bool,
+    /// May pause the protocol or individual projects.
+    pub can_pause: bool,
+}
+
 // ─────────────────────────────────────────────────────────
 // Storage keys
 // ─────────────────────────────────────────────────────────
@@ -125,6 +160,10 @@ pub enum RbacKey {
     Role(Address),
     /// The one and only SuperAdmin address.
     SuperAdmin,
+    /// Number of addresses currently holding a role.
+    RoleCount(Role),
+    /// Custom display label for a role.
+    RoleLabel(Role),
 }
 
 // ─────────────────────────────────────────────────────────
@@ -133,6 +172,13 @@ pub enum RbacKey {
 
 /// Persist a role assignment. Overwrites any existing role.
 fn store_role(env: &Env, address: &Address, role: &Role) {
+    let previous = get_role(env, address);
+    if previous.as_ref() != Some(role) {
+        if let Some(old) = previous {
+            set_role_count(env, &old, role_count(env, &old).saturating_sub(1));
+        }
+        set_role_count(env, role, role_count(env, role).saturating_add(1));
+    }
     env.storage()
         .persistent()
         .set(&RbacKey::Role(address.clone()), role);
@@ -140,11 +186,33 @@ fn store_role(env: &Env, address: &Address, role: &Role) {
 
 /// Remove any role stored for `address`.
 fn clear_role(env: &Env, address: &Address) {
+    if let Some(old) = get_role(env, address) {
+        set_role_count(env, &old, role_count(env, &old).saturating_sub(1));
+    }
     env.storage()
         .persistent()
         .remove(&RbacKey::Role(address.clone()));
 }
 
+fn set_role_count(env: &Env, role: &Role, count: u32) {
+    env.storage()
+        .persistent()
+        .set(&RbacKey::RoleCount(role.clone()), &count);
+}
+
+/// Number of distinct addresses currently holding `role`.
+pub fn role_count(env: &Env, role: &Role) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&RbacKey::RoleCount(role.clone()))
+        .unwrap_or(0)
+}
+
+/// Number of distinct addresses currently holding `Role::Oracle`.
+pub fn oracle_count(env: &Env) -> u32 {
+    role_count(env, &Role::Oracle)
+}
+
 /// Read the role for `address`, returning `None` if unassigned.
 pub fn get_role(env: &Env, address: &Address) -> Option<Role> {
     env.storage()
@@ -152,6 +220,41 @@ pub fn get_role(env: &Env, address: &Address) -> Option<Role> {
         .get(&RbacKey::Role(address.clone()))
 }
 
+/// Display label for `role`: the custom one if set, else the variant name.
+pub fn role_label(env: &Env, role: &Role) -> String {
+    env.storage()
+        .persistent()
+        .get(&RbacKey::RoleLabel(role.clone()))
+        .unwrap_or_else(|| {
+            String::from_str(
+                env,
+                match role {
+                    Role::SuperAdmin => "SuperAdmin",
+                    Role::Admin => "Admin",
+                    Role::Oracle => "Oracle",
+                    Role::Auditor => "Auditor",
+                    Role::ProjectManager => "ProjectManager",
+                },
+            )
+        })
+}
+
+/// Set the display label for `role`. `caller` must be Admin or above.
+///
+/// Emits a `role_lbl` event.
+pub fn set_role_label(env: &Env, caller: &Address, role: &Role, label: &String) {
+    require_admin_or_above(env, caller);
+    env.storage()
+        .persistent()
+        .set(&RbacKey::RoleLabel(role.clone()), label);
+    RoleLabelSet {
+        role: role.clone(),
+        label: label.clone(),
+        by: caller.clone(),
+    }
+    .publish(env);
+}
+
 /// Read the SuperAdmin address, returning `None` before init.
 pub fn get_super_admin(env: &Env) -> Option<Address> {
     env.storage().persistent().get(&RbacKey::SuperAdmin)
@@ -230,8 +333,8 @@ pub fn grant_role(env: &Env, caller: &Address, target: &Address, role: Role) {
 /// - The SuperAdmin address itself cannot be revoked; use `transfer_super_admin`.
 /// - Revoking a role from an address with no role is a no-op.
 ///
-/// Emits a `role_del` event if a role existed.
-pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
+/// Emits a `role_del` event and returns `true` if a role existed.
+pub fn revoke_role(env: &Env, caller: &Address, target: &Address) -> bool {
 <<<<<<< HEAD
 =======
     caller.require_auth();
@@ -247,7 +350,9 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
     if get_role(env, target).is_some() {
         clear_role(env, target);
         emit_revoke(env, target, Some(caller.clone()));
+        return true;
     }
+    false
 }
 
 /// Transfer the SuperAdmin role to a new address.
@@ -256,7 +361,8 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
 /// - `new_super_admin` is granted the `SuperAdmin` role.
 /// - The old SuperAdmin loses the `SuperAdmin` role automatically.
 ///
-/// This is the only way to remove a SuperAdmin.
+/// This and [`decommission_super_admin`] are the only ways to remove a
+/// SuperAdmin.
 pub fn transfer_super_admin(env: &Env, current: &Address, new: &Address) {
 <<<<<<< HEAD
 =======
@@ -282,6 +388,38 @@ pub fn transfer_super_admin(env: &Env, current: &Address, new: &Address) {
 >>>>>>> origin/pr-38
 }
 
+/// Remove the SuperAdmin so the contract can be initialised again.
+///
+/// - `caller` must hold `SuperAdmin`.
+/// - Every other role, including any extra `SuperAdmin` grant, must already
+///   be revoked; role grants cannot be enumerated, so any left behind would
+///   survive into the next `init`. Panics with `Error::DecommissionBlocked`
+///   otherwise.
+/// - Role labels are reset to their defaults.
+pub fn decommission_super_admin(env: &Env, caller: &Address) {
+    require_role(env, caller, &Role::SuperAdmin);
+    if role_count(env, &Role::SuperAdmin) > 1 {
+        panic_with_error_rbac(env, Error::DecommissionBlocked);
+    }
+    for role in [Role::Admin, Role::Oracle, Role::Auditor, Role::ProjectManager] {
+        if role_count(env, &role) > 0 {
+            panic_with_error_rbac(env, Error::DecommissionBlocked);
+        }
+    }
+    for role in [
+        Role::SuperAdmin,
+        Role::Admin,
+        Role::Oracle,
+        Role::Auditor,
+        Role::ProjectManager,
+    ] {
+        env.storage().persistent().remove(&RbacKey::RoleLabel(role));
+    }
+    clear_role(env, caller);
+    env.storage().persistent().remove(&RbacKey::SuperAdmin);
+    emit_revoke(env, caller, Some(caller.clone()));
+}
+
 // ─────────────────────────────────────────────────────────
 // Access guards (called from lib.rs handlers)
 // ─────────────────────────────────────────────────────────
@@ -295,6 +433,31 @@ pub fn require_role(env: &Env, address: &Address, required_role: &Role) {
     }
 }
 
+/// Position of `role` in the hierarchy: SuperAdmin outranks Admin, which
+/// outranks the three leaf roles. Leaf roles do not outrank each other.
+fn rank(role: &Role) -> u32 {
+    match role {
+        Role::SuperAdmin => 2,
+        Role::Admin => 1,
+        Role::Oracle | Role::Auditor | Role::ProjectManager => 0,
+    }
+}
+
+/// Returns `true` if a holder of `held` may act where `required` is needed:
+/// either the same role, or one strictly above it in the hierarchy.
+pub fn role_satisfies(held: &Role, required: &Role) -> bool {
+    held == required || rank(held) > rank(required)
+}
+
+/// Assert that `address` holds `required_role` or a role above it.
+/// Panics with `Error::NotAuthorized` on failure.
+pub fn require_role_or_above(env: &Env, address: &Address, required_role: &Role) {
+    match get_role(env, address) {
+        Some(ref r) if role_satisfies(r, required_role) => {}
+        _ => panic_with_error_rbac(env, Error::NotAuthorized),
+    }
+}
+
 /// Assert that `address` holds one of the roles in `allowed`.
 /// Panics with `Error::NotAuthorized` if none match.
 pub fn require_any_of(env: &Env, address: &Address, allowed: &[Role]) {
@@ -310,7 +473,7 @@ pub fn require_any_of(env: &Env, address: &Address, allowed: &[Role]) {
 /// Convenience wrapper used on configuration-level operations.
 #[inline]
 pub fn require_admin_or_above(env: &Env, address: &Address) {
-    require_any_of(env, address, &[Role::SuperAdmin, Role::Admin]);
+    require_role_or_above(env, address, &Role::Admin);
 }
 
 /// Assert that `address` holds the Oracle role.
@@ -334,14 +497,27 @@ pub fn require_super_admin(env: &Env, address: &Address) {
 =======
 >>>>>>> origin/pr-38
 /// Assert that `address` may register and manage projects.
-/// ProjectManager, Admin, and SuperAdmin may all register projects.
+/// ProjectManager, Admin, and SuperAdmin may all register projects, unless
+/// `AdminsCanRegister` is switched off, in which case only ProjectManager may.
 #[inline]
 pub fn require_can_register(env: &Env, address: &Address) {
-    require_any_of(
-        env,
-        address,
-        &[Role::SuperAdmin, Role::Admin, Role::ProjectManager],
-    );
+    if crate::storage::get_admins_can_register(env) {
+        require_role_or_above(env, address, &Role::ProjectManager);
+    } else {
+        require_role(env, address, &Role::ProjectManager);
+    }
+}
+
+/// True if `address` currently holds a role that may register projects,
+/// under the same rules as [`require_can_register`].
+pub fn can_register(env: &Env, address: &Address) -> bool {
+    match get_role(env, address) {
+        Some(role) if crate::storage::get_admins_can_register(env) => {
+            role_satisfies(&role, &Role::ProjectManager)
+        }
+        Some(role) => role == Role::ProjectManager,
+        None => false,
+    }
 }
 
 <<<<<<< HEAD
@@ -368,6 +544,31 @@ pub fn has_role(env: &Env, address: Address, role: Role) -> bool {
     get_role(env, &address).map(|r| r == role).unwrap_or(false)
 }
 
+/// Effective permissions of `address`, mirroring the `require_*` guards.
+pub fn permissions(env: &Env, address: &Address) -> Permissions {
+    let role = get_role(env, address);
+    let satisfies = |required: &Role| role.as_ref().is_some_and(|r| role_satisfies(r, required));
+    let is = |exact: &Role| role.as_ref() == Some(exact);
+
+    let is_admin = satisfies(&Role::Admin);
+    let can_register = if crate::storage::get_admins_can_register(env) {
+        satisfies(&Role::ProjectManager)
+    } else {
+        is(&Role::ProjectManager)
+    };
+
+    Permissions {
+        is_super_admin: is(&Role::SuperAdmin),
+        is_admin,
+        can_grant: is_admin,
+        can_register,
+        can_cancel: is(&Role::SuperAdmin) || is(&Role::ProjectManager),
+        can_verify: is(&Role::Oracle),
+        can_expire: true,
+        can_pause: is_admin,
+    }
+}
+
 // ─────────────────────────────────────────────────────────
 // Internal helpers
 // ─────────────────────────────────────────────────────────
@@ -378,6 +579,7 @@ fn emit(env: &Env, target: &Address, role: &Role, by: Option<Address>) {
     RoleSet {
         target: target.clone(),
         role: role.clone(),
+        label: role_label(env, role),
         by,
     }
     .publish(env);
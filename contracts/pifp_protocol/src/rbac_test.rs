@@ -1,7 +1,7 @@
 extern crate std;
 
-use crate::{test_utils::TestContext, Role};
-use soroban_sdk::vec;
+use crate::{test_utils::TestContext, PifpProtocol, PifpProtocolClient, Role};
+use soroban_sdk::{vec, String};
 
 #[test]
 fn test_init_sets_super_admin() {
@@ -9,6 +9,32 @@ fn test_init_sets_super_admin() {
     assert!(ctx.client.has_role(&ctx.admin, &Role::SuperAdmin));
 }
 
+#[test]
+fn test_init_if_needed_is_idempotent() {
+    let ctx = TestContext::new();
+    let contract_id = ctx.env.register(PifpProtocol, ());
+    let client = PifpProtocolClient::new(&ctx.env, &contract_id);
+    let other = ctx.generate_address();
+    ctx.env.mock_all_auths();
+
+    assert!(client.init_if_needed(&ctx.admin));
+    assert!(!client.init_if_needed(&other));
+
+    assert!(client.has_role(&ctx.admin, &Role::SuperAdmin));
+    assert_eq!(client.role_of(&other), None);
+}
+
+#[test]
+fn test_init_if_needed_after_init_returns_false() {
+    let ctx = TestContext::new();
+    let other = ctx.generate_address();
+    ctx.env.mock_all_auths();
+
+    assert!(!ctx.client.init_if_needed(&other));
+    assert!(ctx.client.has_role(&ctx.admin, &Role::SuperAdmin));
+    assert!(!ctx.client.has_role(&other, &Role::SuperAdmin));
+}
+
 #[test]
 fn test_super_admin_can_grant_admin() {
     let ctx = TestContext::new();
@@ -119,3 +145,272 @@ fn test_oracle_can_verify() {
     let completed = ctx.client.get_project(&project.id);
     assert_eq!(completed.status, crate::ProjectStatus::Verified);
 }
+
+#[test]
+fn test_role_hierarchy_boundaries() {
+    use crate::rbac::role_satisfies;
+
+    let leaves = [Role::Oracle, Role::Auditor, Role::ProjectManager];
+
+    // SuperAdmin satisfies every role; nothing else satisfies SuperAdmin.
+    assert!(role_satisfies(&Role::SuperAdmin, &Role::SuperAdmin));
+    assert!(role_satisfies(&Role::SuperAdmin, &Role::Admin));
+    assert!(!role_satisfies(&Role::Admin, &Role::SuperAdmin));
+
+    // Admin sits above every leaf role.
+    assert!(role_satisfies(&Role::Admin, &Role::Admin));
+    for leaf in leaves.iter() {
+        assert!(role_satisfies(&Role::SuperAdmin, leaf));
+        assert!(role_satisfies(&Role::Admin, leaf));
+        assert!(!role_satisfies(leaf, &Role::Admin));
+        assert!(!role_satisfies(leaf, &Role::SuperAdmin));
+    }
+
+    // Leaf roles only satisfy themselves.
+    for held in leaves.iter() {
+        for required in leaves.iter() {
+            assert_eq!(role_satisfies(held, required), held == required);
+        }
+    }
+}
+
+#[test]
+fn test_require_role_or_above_accepts_higher_role() {
+    let ctx = TestContext::new();
+    ctx.env.as_contract(&ctx.client.address, || {
+        crate::rbac::require_role_or_above(&ctx.env, &ctx.admin, &Role::Oracle);
+        crate::rbac::require_role_or_above(&ctx.env, &ctx.oracle, &Role::Oracle);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_require_role_or_above_rejects_sibling_role() {
+    let ctx = TestContext::new();
+    ctx.env.as_contract(&ctx.client.address, || {
+        crate::rbac::require_role_or_above(&ctx.env, &ctx.manager, &Role::Oracle);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_require_role_or_above_rejects_unassigned() {
+    let ctx = TestContext::new();
+    let stranger = ctx.generate_address();
+    ctx.env.as_contract(&ctx.client.address, || {
+        crate::rbac::require_role_or_above(&ctx.env, &stranger, &Role::Auditor);
+    });
+}
+
+fn register_as(ctx: &TestContext, creator: &soroban_sdk::Address) -> crate::Project {
+    let tokens = vec![&ctx.env, ctx.generate_address()];
+    let mut milestones = soroban_sdk::Vec::new(&ctx.env);
+    milestones.push_back(crate::types::Milestone {
+        label: soroban_sdk::BytesN::from_array(&ctx.env, &[0u8; 32]),
+        amount_bps: 10000,
+        proof_hash: ctx.dummy_proof(),
+    });
+    ctx.client.register_project(
+        creator,
+        &tokens,
+        &1000i128,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        &soroban_sdk::Vec::new(&ctx.env),
+        &0u32,
+    )
+}
+
+#[test]
+fn test_admin_can_register_by_default() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    assert!(ctx.client.get_admins_can_register());
+    let project = register_as(&ctx, &admin);
+    assert_eq!(project.creator, admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_register_when_disabled() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client.set_admins_can_register(&ctx.admin, &false);
+
+    register_as(&ctx, &admin);
+}
+
+#[test]
+fn test_project_manager_registers_when_admins_disabled() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_admins_can_register(&ctx.admin, &false);
+
+    let project = register_as(&ctx, &ctx.manager);
+    assert_eq!(project.creator, ctx.manager);
+}
+
+#[test]
+fn test_oracle_count_tracks_grants_and_revokes() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    assert_eq!(ctx.client.get_oracle_count(), 1);
+
+    let second = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Oracle);
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Oracle);
+    assert_eq!(ctx.client.get_oracle_count(), 2);
+
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Auditor);
+    assert_eq!(ctx.client.get_oracle_count(), 1);
+
+    ctx.client.revoke_role(&ctx.admin, &ctx.oracle);
+    assert_eq!(ctx.client.get_oracle_count(), 0);
+}
+
+#[test]
+fn test_count_role_tracks_replacements() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    assert_eq!(ctx.client.count_role(&Role::SuperAdmin), 1);
+    assert_eq!(ctx.client.count_role(&Role::Admin), 0);
+    let managers = ctx.client.count_role(&Role::ProjectManager);
+
+    let alice = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &alice, &Role::Admin);
+    ctx.client.grant_role(&ctx.admin, &alice, &Role::Admin);
+    assert_eq!(ctx.client.count_role(&Role::Admin), 1);
+
+    // Replacing Admin with Auditor moves alice between counters.
+    ctx.client.grant_role(&ctx.admin, &alice, &Role::Auditor);
+    assert_eq!(ctx.client.count_role(&Role::Admin), 0);
+    assert_eq!(ctx.client.count_role(&Role::Auditor), 1);
+
+    ctx.client.revoke_role(&ctx.admin, &alice);
+    assert_eq!(ctx.client.count_role(&Role::Auditor), 0);
+
+    // The new super admin's old role is released on transfer.
+    ctx.client.grant_role(&ctx.admin, &alice, &Role::ProjectManager);
+    assert_eq!(ctx.client.count_role(&Role::ProjectManager), managers + 1);
+    ctx.client.transfer_super_admin(&ctx.admin, &alice);
+    assert_eq!(ctx.client.count_role(&Role::SuperAdmin), 1);
+    assert_eq!(ctx.client.count_role(&Role::ProjectManager), managers);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #53)")]
+fn test_verify_rejected_below_min_oracles() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_min_oracles(&ctx.admin, &2);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_verify_allowed_with_enough_oracles() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_min_oracles(&ctx.admin, &2);
+    assert_eq!(ctx.client.get_min_oracles(), 2);
+
+    let second = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Oracle);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        crate::ProjectStatus::Verified
+    );
+}
+
+#[test]
+fn test_permissions_for_each_role() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let admin = ctx.generate_address();
+    let auditor = ctx.generate_address();
+    let nobody = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+
+    let p = ctx.client.get_permissions(&ctx.admin);
+    assert!(p.is_super_admin && p.is_admin && p.can_grant && p.can_register);
+    assert!(p.can_cancel && p.can_pause && p.can_expire && !p.can_verify);
+
+    let p = ctx.client.get_permissions(&admin);
+    assert!(!p.is_super_admin && p.is_admin && p.can_grant && p.can_register);
+    assert!(!p.can_cancel && p.can_pause && !p.can_verify);
+
+    let p = ctx.client.get_permissions(&ctx.oracle);
+    assert!(p.can_verify && !p.is_admin && !p.can_grant && !p.can_register);
+
+    let p = ctx.client.get_permissions(&ctx.manager);
+    assert!(p.can_register && p.can_cancel && !p.is_admin && !p.can_verify);
+
+    let p = ctx.client.get_permissions(&auditor);
+    assert!(!p.can_register && !p.can_grant && !p.can_verify && !p.can_cancel);
+
+    let p = ctx.client.get_permissions(&nobody);
+    assert!(!p.is_admin && !p.can_register && !p.can_verify && !p.can_pause);
+    assert!(p.can_expire);
+}
+
+#[test]
+fn test_permissions_follow_admins_can_register() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_admins_can_register(&ctx.admin, &false);
+
+    assert!(!ctx.client.get_permissions(&ctx.admin).can_register);
+    assert!(ctx.client.get_permissions(&ctx.manager).can_register);
+}
+
+#[test]
+fn test_role_label_defaults_to_variant_name() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.get_role_label(&Role::ProjectManager),
+        String::from_str(&ctx.env, "ProjectManager")
+    );
+    assert_eq!(
+        ctx.client.get_role_label(&Role::Oracle),
+        String::from_str(&ctx.env, "Oracle")
+    );
+}
+
+#[test]
+fn test_set_role_label() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let label = String::from_str(&ctx.env, "Field Verifier");
+    ctx.client.set_role_label(&ctx.admin, &Role::Oracle, &label);
+
+    assert_eq!(ctx.client.get_role_label(&Role::Oracle), label);
+    assert_eq!(
+        ctx.client.get_role_label(&Role::Auditor),
+        String::from_str(&ctx.env, "Auditor")
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_role_label_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let label = String::from_str(&ctx.env, "Boss");
+    ctx.client
+        .set_role_label(&ctx.manager, &Role::SuperAdmin, &label);
+}
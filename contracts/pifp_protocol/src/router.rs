@@ -0,0 +1,23 @@
+//! # Swap Router
+//!
+//! Interface `deposit_and_swap` expects from the DEX router configured via
+//! `set_swap_router`. The contract sends `amount_in` of `from_token` to the
+//! router first; the router must then deliver at least `min_out` of
+//! `to_token` to `to` and return the amount delivered.
+//!
+//! The contract credits what actually arrived (measured by balance delta),
+//! not the router's return value.
+
+use soroban_sdk::{contractclient, Address, Env};
+
+#[contractclient(name = "SwapRouterClient")]
+pub trait SwapRouter {
+    fn swap(
+        env: Env,
+        from_token: Address,
+        to_token: Address,
+        amount_in: i128,
+        min_out: i128,
+        to: Address,
+    ) -> i128;
+}
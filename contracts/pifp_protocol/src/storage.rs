@@ -8,7 +8,30 @@
 //! | Key              | Type      | Description                        |
 //! |------------------|-----------|------------------------------------|
 //! | `ProjectCount`   | `u64`     | Auto-increment project ID counter  |
+//! | `ActiveProjectCount` | `u64` | Projects not yet completed, expired or cancelled |
 //! | `OracleKey`      | `Address` | Active trusted oracle address      |
+//! | `OraclePubKey`   | `BytesN<32>` | ed25519 key for signed verification |
+//! | `ContractVersion`| `u32`     | Version stored data was written by |
+//! | `RegisterCooldown` | `u64`   | Seconds between registrations per creator |
+//! | `SelfDepositDelay` | `u64`   | Seconds after registration before a creator may fund their own project |
+//! | `MaxDeadlineHorizon` | `u64` | Max seconds from now a deadline may be set |
+//! | `MaxDepositPerTx` | `i128`   | Largest amount accepted by a single deposit |
+//! | `GlobalMinDeposit` | `i128`  | Smallest amount any deposit may carry, across all projects |
+//! | `SwapRouter`     | `Address` | DEX router used by `deposit_and_swap` |
+//! | `RefundWindow`   | `u64`     | Seconds after expiry/cancellation during which refunds are open |
+//! | `ClaimTimeout`   | `u64`     | Seconds after the grace period a creator has to claim; 0 = none |
+//! | `ChallengePeriod` | `u64`    | Seconds after verification during which it may be challenged |
+//! | `MinOracles`     | `u32`     | Oracle role holders required before any verification |
+//! | `OracleRewardBps` | `u32`    | Cut of released funds paid to the verifying oracle |
+//! | `FeeTiers`       | `Vec<(i128, u32)>` | Deposit fee in bps by minimum donation size, ascending |
+//! | `RoundingMode`   | `RoundingMode` | Rounding of fee and reward cuts |
+//! | `ConsensusThreshold` | `u32` | Oracle weight a verification needs; 0 = off |
+//! | `ConfirmationGap` | `u64`   | Minimum seconds between distinct oracle confirmations; 0 = off |
+//! | `AdminsCanRegister` | `bool` | Whether Admin/SuperAdmin may register projects |
+//! | `RequireCreatorRole` | `bool` | Whether release needs the creator to keep a registering role |
+//! | `TokenGateEnabled` | `bool`   | Whether projects may only accept globally approved tokens |
+//! | `MaxTokensPerProject` | `u32` | Tokens a new project may accept |
+//! | `AllowedCategories` | `Vec<Symbol>` | Project categories admins allow; empty = any |
 //!
 //! Instance TTL is bumped by **7 days** whenever it falls below 1 day remaining.
 //!
@@ -19,6 +42,47 @@
 //! | `ProjConfig(id)`   | `ProjectConfig` | Immutable project configuration  |
 //! | `ProjState(id)`    | `ProjectState`  | Mutable project state            |
 //! | `DonatorBalance(id, token, donator)` | `i128` | Per-donator refundable amount |
+//! | `AnonDonor(id, token, commitment)` | `i128` | Refundable amount held for an anonymous donor |
+//! | `MigratedToken(id, new_token)` | `Address` | Token `new_token` replaced via `migrate_token` |
+//! | `LastRegister(creator)` | `u64`     | Timestamp of the creator's last registration |
+//! | `LastAnnounce(id)` | `u64`     | Timestamp of the project's last `announce` |
+//! | `GlobalTokenBalance(token)` | `i128` | Sum of `token` held across all projects |
+//! | `GlobalToken(token)` | `bool` | Token is in the admin-managed approved registry |
+//! | `Institution(addr)` | `bool` | Address is in the admin-managed institution registry |
+//! | `TreasuryBalance(token)` | `i128` | Protocol fees accrued in `token`, awaiting withdrawal |
+//! | `DonationsPaused(id)` | `bool` | Creator-set flag blocking new deposits |
+//! | `DepositIncrement(id, token)` | `i128` | Deposits must be a multiple of this; 0 = any |
+//! | `MaxPerDonor(id)` | `i128` | Cap on each donor's cumulative deposit per token; 0 = none |
+//! | `MinDeposit(id)` | `i128` | Smallest amount a single deposit to the project may carry; 0 = none |
+//! | `TokenGoal(id, token)` | `i128` | Extra per-token goal for secondary accepted tokens |
+//! | `ReadySignalled(id)` | `bool` | `ready_ver` has already been emitted for the project |
+//! | `ConfirmationRecorded(id)` | `bool` | An oracle confirmation is recorded and verification is pending |
+//! | `LastConfirmationAt(id)` | `u64` | Ledger time of the latest distinct oracle confirmation |
+//! | `GoalReachedAt(id)` | `u64` | Ledger time the first-token goal was reached |
+//! | `FundsReleased(id)` | `bool` | Some of the project's funds have been paid out |
+//! | `DonationSeq(id, donator)` | `u32` | Number of memos a donator has left on a project |
+//! | `DonationMemo(id, donator, seq)` | `String` | Memo attached to a single donation |
+//! | `DonationEntryCount(id)` | `u32` | Number of entries in a project's donation log |
+//! | `DonationEntry(id, seq)` | `DonationRecord` | One deposit in the project's donation log |
+//! | `SnapshotCount(id)` | `u32` | Number of balance snapshots taken for a project |
+//! | `Snapshot(id, index)` | `ProjectBalances` | Point-in-time balances of a project |
+//! | `Pledge(id, donator, token)` | `i128` | Amount pledged but not yet transferred |
+//! | `Pledgers(id)` | `Vec<(Address, Address)>` | (donator, token) pairs with an open pledge |
+//! | `ProofMatched(id, index)` | `bool` | Required proof `index` has been matched by an oracle |
+//! | `VestingDuration(id)` | `u64` | Seconds over which released funds vest; 0 = lump release |
+//! | `VestingClaimed(id, token)` | `i128` | Amount of `token` already paid out by `claim_vested` |
+//! | `Verifier(id)` | `Address` | Oracle whose call verified the project |
+//! | `SignedNonce(id)` | `u32` | Signed verifications accepted for the project |
+//! | `Earmarked(id, earmark)` | `i128` | Total donated toward a donor-chosen earmark |
+//! | `TokenProjectCount(token)` | `u32` | Number of projects registered accepting `token` |
+//! | `TokenProject(token, index)` | `u64` | The `index`-th project accepting `token` |
+//! | `TokenProjectPos(id)` | `Map<Address, u32>` | Slot of project `id` in each token's index |
+//! | `TotalRefunded(id, token)` | `i128` | Sum of all refunds paid out in `token` by a project |
+//! | `DonorProjects(donator)` | `Vec<u64>` | Projects a donor has funded, in order of first donation |
+//! | `OracleWeight(oracle)` | `u32` | Voting weight of an oracle in weighted consensus; unset = 1 |
+//! | `TagProjects(tag)` | `Vec<u64>` | Projects currently carrying `tag`, in tagging order |
+//! | `AuditCount` | `u32` | Audit records ever written; kept across `decommission` |
+//! | `AuditEntry(slot)` | `AuditRecord` | Audit record `seq`, held in slot `seq % AUDIT_LOG_SIZE` |
 //!
 //! Persistent TTL is bumped by **30 days** whenever it falls below 7 days remaining.
 //!
@@ -29,12 +93,14 @@
 //! ledger write costs by ~87% per deposit while keeping the public API clean via
 //! the reconstructed [`Project`] return type.
 
-use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+use soroban_sdk::{
+    contracttype, panic_with_error, Address, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 use crate::errors::Error;
 use crate::types::{
-    OracleAgreement, Project, ProjectBalances, ProjectConfig, ProjectState, ProtocolConfig,
-    TokenBalance,
+    AuditRecord, AuditSubject, DonationRecord, OracleAgreement, Project, ProjectBalances,
+    ProjectConfig, ProjectState, ProtocolConfig, RoundingMode, TokenBalance,
 };
 
 // ── TTL Constants ────────────────────────────────────────────────────
@@ -50,6 +116,10 @@ const INSTANCE_LIFETIME_THRESHOLD: u32 = DAY_IN_LEDGERS;
 const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
 
+/// Most `MigratedToken` links a balance lookup follows, so a token migrated
+/// back and forth can never make the lookup loop.
+const MAX_MIGRATION_HOPS: u32 = 8;
+
 // ── Storage Keys ─────────────────────────────────────────────────────
 
 /// All contract storage keys.
@@ -62,6 +132,8 @@ const PERSISTENT_LIFETIME_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
 pub enum DataKey {
     /// Global auto-increment counter for project IDs (Instance).
     ProjectCount,
+    /// Number of projects not yet completed, expired or cancelled (Instance).
+    ActiveProjectCount,
     /// Immutable project configuration keyed by ID (Persistent).
     ProjConfig(u64),
     /// Mutable project state keyed by ID (Persistent).
@@ -81,6 +153,133 @@ pub enum DataKey {
     IsLocked,
     /// In-flight oracle vote agreement for a project (Temporary).
     OracleAgreement(u64),
+    /// ed25519 public key accepted for signed verification (Instance).
+    OraclePubKey,
+    /// Contract version the stored data was last migrated to (Instance).
+    ContractVersion,
+    /// Anonymous donor balance keyed by (project_id, token, commitment) (Persistent).
+    AnonDonor(u64, Address, BytesN<32>),
+    /// Token a project's `new_token` replaced in `migrate_token` (Persistent).
+    MigratedToken(u64, Address),
+    /// Timestamp of a creator's most recent registration (Persistent).
+    LastRegister(Address),
+    /// Timestamp of the last `announce` for a project (Persistent).
+    LastAnnounce(u64),
+    /// Minimum seconds between registrations by the same creator (Instance).
+    RegisterCooldown,
+    /// Seconds after registration during which creators may not deposit
+    /// into their own project; unset = 0 (Instance).
+    SelfDepositDelay,
+    /// Maximum seconds between registration and deadline; unset = no limit (Instance).
+    MaxDeadlineHorizon,
+    /// Largest amount a single deposit may carry; unset = `i128::MAX` (Instance).
+    MaxDepositPerTx,
+    /// Protocol-wide minimum deposit amount; unset = 0 (Instance).
+    GlobalMinDeposit,
+    /// DEX router contract used by `deposit_and_swap` (Instance).
+    SwapRouter,
+    /// Refund window length in seconds; unset = `REFUND_WINDOW` (Instance).
+    RefundWindow,
+    /// Seconds a creator has to claim once the grace period ends; unset = 0, no timeout (Instance).
+    ClaimTimeout,
+    /// Seconds after verification before funds release; unset = `GRACE_PERIOD` (Instance).
+    ChallengePeriod,
+    /// Minimum number of Oracle role holders for verification; unset = 0 (Instance).
+    MinOracles,
+    /// Oracle reward in basis points of released funds; unset = 0 (Instance).
+    OracleRewardBps,
+    /// Deposit fee tiers as (min amount, bps); unset = flat 0 (Instance).
+    FeeTiers,
+    /// Rounding of basis-point cuts; unset = `Down` (Instance).
+    RoundingMode,
+    /// Oracle weight a verification requires; unset = 0 (Instance).
+    ConsensusThreshold,
+    /// Minimum seconds between oracle confirmations; unset = 0 (Instance).
+    ConfirmationGap,
+    /// Whether Admin and SuperAdmin may register projects; unset = true (Instance).
+    AdminsCanRegister,
+    /// Whether release requires the creator to still be able to register; unset = false (Instance).
+    RequireCreatorRole,
+    /// Whether registration is limited to approved tokens; unset = false (Instance).
+    TokenGateEnabled,
+    /// Tokens a new project may accept; unset = `DEFAULT_MAX_TOKENS` (Instance).
+    MaxTokensPerProject,
+    /// Project categories creators may choose from; unset = any (Instance).
+    AllowedCategories,
+    /// Total balance of a token held across every project (Persistent).
+    GlobalTokenBalance(Address),
+    /// Presence marks a token approved in the global registry (Persistent).
+    GlobalToken(Address),
+    /// Presence marks an address as a registered institution (Persistent).
+    Institution(Address),
+    /// Protocol fees accrued per token, awaiting withdrawal (Persistent).
+    TreasuryBalance(Address),
+    /// Creator-controlled flag that blocks new deposits (Persistent).
+    DonationsPaused(u64),
+    /// Required deposit step per (project_id, token); absent = any amount (Persistent).
+    DepositIncrement(u64, Address),
+    /// Per-donor, per-token contribution cap for a project; absent = no cap (Persistent).
+    MaxPerDonor(u64),
+    /// Per-project minimum deposit amount (Persistent).
+    MinDeposit(u64),
+    /// Per-token goal for a secondary accepted token (Persistent).
+    TokenGoal(u64, Address),
+    /// Set once every goal is met and `ready_ver` has fired (Persistent).
+    ReadySignalled(u64),
+    /// Set once an oracle confirmation is recorded for project_id (Persistent).
+    ConfirmationRecorded(u64),
+    /// Timestamp of the latest oracle confirmation for project_id (Persistent).
+    LastConfirmationAt(u64),
+    /// Ledger timestamp at which the project's goal was first reached (Persistent).
+    GoalReachedAt(u64),
+    /// Set once any of project_id's funds have been paid out (Persistent).
+    FundsReleased(u64),
+    /// Per-(project, donator) donation memo counter (Persistent).
+    DonationSeq(u64, Address),
+    /// Memo attached to a donation, keyed by (project_id, donator, seq) (Persistent).
+    DonationMemo(u64, Address, u32),
+    /// Number of entries in a project's donation log (Persistent).
+    DonationEntryCount(u64),
+    /// Donation log entry keyed by (project_id, seq) (Persistent).
+    DonationEntry(u64, u32),
+    /// Number of balance snapshots taken per project (Persistent).
+    SnapshotCount(u64),
+    /// Balance snapshot keyed by (project_id, index) (Persistent).
+    Snapshot(u64, u32),
+    /// Open pledge keyed by (project_id, donator, token) (Persistent).
+    Pledge(u64, Address, Address),
+    /// (donator, token) pairs with an open pledge on a project (Persistent).
+    Pledgers(u64),
+    /// Required proof at (project_id, index) has been matched (Persistent).
+    ProofMatched(u64, u32),
+    /// Linear vesting duration for project_id, in seconds (Persistent).
+    VestingDuration(u64),
+    /// Vested amount already claimed per (project_id, token) (Persistent).
+    VestingClaimed(u64, Address),
+    /// Oracle that completed verification of project_id (Persistent).
+    Verifier(u64),
+    /// Signed verifications accepted for project_id; signed over (Persistent).
+    SignedNonce(u64),
+    /// Running total donated per (project_id, earmark) (Persistent).
+    Earmarked(u64, Symbol),
+    /// Number of projects accepting a token (Persistent).
+    TokenProjectCount(Address),
+    /// Reverse index entry (token, index) -> project_id (Persistent).
+    TokenProject(Address, u32),
+    /// Position of project_id in each token's reverse index (Persistent).
+    TokenProjectPos(u64),
+    /// Total refunded per (project_id, token) (Persistent).
+    TotalRefunded(u64, Address),
+    /// Projects a donator has contributed to (Persistent).
+    DonorProjects(Address),
+    /// Consensus weight per oracle (Persistent).
+    OracleWeight(Address),
+    /// Projects carrying a tag (Persistent).
+    TagProjects(Symbol),
+    /// Number of audit records ever written (Persistent).
+    AuditCount,
+    /// Audit log ring-buffer slot (Persistent).
+    AuditEntry(u32),
 }
 
 // ── Instance Storage Helpers ─────────────────────────────────────────
@@ -92,6 +291,44 @@ fn bump_instance(env: &Env) {
         .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 }
 
+/// Remove every instance-tier key, returning all protocol settings to their
+/// defaults. Used by `decommission`. Persistent per-address settings
+/// (`OracleWeight`, `GlobalToken`, `Institution`) are left in place.
+pub fn clear_instance_config(env: &Env) {
+    let keys = [
+        DataKey::ProjectCount,
+        DataKey::ActiveProjectCount,
+        DataKey::IsPaused,
+        DataKey::ProtocolConfig,
+        DataKey::IsLocked,
+        DataKey::OraclePubKey,
+        DataKey::ContractVersion,
+        DataKey::RegisterCooldown,
+        DataKey::SelfDepositDelay,
+        DataKey::MaxDeadlineHorizon,
+        DataKey::MaxDepositPerTx,
+        DataKey::GlobalMinDeposit,
+        DataKey::SwapRouter,
+        DataKey::RefundWindow,
+        DataKey::ClaimTimeout,
+        DataKey::ChallengePeriod,
+        DataKey::MinOracles,
+        DataKey::OracleRewardBps,
+        DataKey::FeeTiers,
+        DataKey::RoundingMode,
+        DataKey::ConsensusThreshold,
+        DataKey::ConfirmationGap,
+        DataKey::AdminsCanRegister,
+        DataKey::RequireCreatorRole,
+        DataKey::TokenGateEnabled,
+        DataKey::MaxTokensPerProject,
+        DataKey::AllowedCategories,
+    ];
+    for key in keys {
+        env.storage().instance().remove(&key);
+    }
+}
+
 // ─────────────────────────────────────────────────────────
 // Project counter
 // ─────────────────────────────────────────────────────────
@@ -111,6 +348,41 @@ pub fn get_and_increment_project_id(env: &Env) -> u64 {
     current
 }
 
+/// Read the number of live projects (default `0`).
+pub fn get_active_project_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActiveProjectCount)
+        .unwrap_or(0)
+}
+
+/// Count a newly registered project as live.
+pub fn increment_active_project_count(env: &Env) {
+    bump_instance(env);
+    let current = get_active_project_count(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ActiveProjectCount, &current.saturating_add(1));
+}
+
+/// Stop counting a project that reached a terminal state. Never underflows.
+pub fn decrement_active_project_count(env: &Env) {
+    bump_instance(env);
+    let current = get_active_project_count(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ActiveProjectCount, &current.saturating_sub(1));
+}
+
+/// Read the ID the next registered project will receive, without
+/// incrementing the counter.
+pub fn peek_next_project_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProjectCount)
+        .unwrap_or(0)
+}
+
 /// Return true if the protocol is currently paused.
 pub fn is_paused(env: &Env) -> bool {
     env.storage()
@@ -138,6 +410,328 @@ pub fn set_protocol_config(env: &Env, config: &ProtocolConfig) {
         .set(&DataKey::ProtocolConfig, config);
 }
 
+/// Retrieve the ed25519 oracle public key used for signed verification.
+pub fn get_oracle_pubkey(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&DataKey::OraclePubKey)
+}
+
+/// Save the ed25519 oracle public key used for signed verification.
+pub fn set_oracle_pubkey(env: &Env, pubkey: &BytesN<32>) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::OraclePubKey, pubkey);
+}
+
+/// Retrieve the stored contract version, or `0` before `init`.
+pub fn get_contract_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContractVersion)
+        .unwrap_or(0)
+}
+
+/// Record the contract version the stored data conforms to.
+pub fn set_contract_version(env: &Env, version: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ContractVersion, &version);
+}
+
+/// Retrieve the per-creator registration cooldown in seconds (default `0`).
+pub fn get_register_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RegisterCooldown)
+        .unwrap_or(0)
+}
+
+/// Save the per-creator registration cooldown in seconds.
+pub fn set_register_cooldown(env: &Env, cooldown: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::RegisterCooldown, &cooldown);
+}
+
+/// Load the creator self-deposit delay in seconds (0 = none).
+pub fn get_self_deposit_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SelfDepositDelay)
+        .unwrap_or(0)
+}
+
+/// Save the creator self-deposit delay in seconds.
+pub fn set_self_deposit_delay(env: &Env, delay: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::SelfDepositDelay, &delay);
+}
+
+/// Load the maximum deadline horizon in seconds, if one is configured.
+pub fn get_max_deadline_horizon(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::MaxDeadlineHorizon)
+}
+
+/// Save the maximum deadline horizon in seconds.
+pub fn set_max_deadline_horizon(env: &Env, horizon: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxDeadlineHorizon, &horizon);
+}
+
+/// Load the per-deposit maximum, defaulting to `i128::MAX` (no limit).
+pub fn get_max_deposit_per_tx(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxDepositPerTx)
+        .unwrap_or(i128::MAX)
+}
+
+/// Save the per-deposit maximum.
+pub fn set_max_deposit_per_tx(env: &Env, max: i128) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::MaxDepositPerTx, &max);
+}
+
+/// Load the protocol-wide minimum deposit (0 = none).
+pub fn get_global_min_deposit(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::GlobalMinDeposit)
+        .unwrap_or(0)
+}
+
+/// Save the protocol-wide minimum deposit.
+pub fn set_global_min_deposit(env: &Env, min: i128) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::GlobalMinDeposit, &min);
+}
+
+/// Load the swap router address, if one is configured.
+pub fn get_swap_router(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::SwapRouter)
+}
+
+/// Save the swap router address.
+pub fn set_swap_router(env: &Env, router: &Address) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::SwapRouter, router);
+}
+
+/// Retrieve the refund window in seconds (default `REFUND_WINDOW`).
+pub fn get_refund_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RefundWindow)
+        .unwrap_or(crate::REFUND_WINDOW)
+}
+
+/// Save the refund window in seconds.
+pub fn set_refund_window(env: &Env, window: u64) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::RefundWindow, &window);
+}
+
+/// Retrieve the creator's claim timeout in seconds (default `0`, none).
+pub fn get_claim_timeout(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ClaimTimeout)
+        .unwrap_or(0)
+}
+
+/// Save the creator's claim timeout in seconds.
+pub fn set_claim_timeout(env: &Env, timeout: u64) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::ClaimTimeout, &timeout);
+}
+
+/// Retrieve the challenge period in seconds (default `GRACE_PERIOD`).
+pub fn get_challenge_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ChallengePeriod)
+        .unwrap_or(crate::GRACE_PERIOD)
+}
+
+/// Save the challenge period in seconds.
+pub fn set_challenge_period(env: &Env, period: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ChallengePeriod, &period);
+}
+
+/// Retrieve the minimum number of oracles required to verify (default `0`).
+pub fn get_min_oracles(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinOracles)
+        .unwrap_or(0)
+}
+
+/// Save the minimum number of oracles required to verify.
+pub fn set_min_oracles(env: &Env, min: u32) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::MinOracles, &min);
+}
+
+/// Load the oracle reward in basis points (0 = no reward).
+pub fn get_oracle_reward_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::OracleRewardBps)
+        .unwrap_or(0)
+}
+
+/// Save the oracle reward in basis points.
+pub fn set_oracle_reward_bps(env: &Env, bps: u32) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::OracleRewardBps, &bps);
+}
+
+/// Load the deposit fee tiers; a single flat 0 bps tier when unset.
+pub fn get_fee_tiers(env: &Env) -> Vec<(i128, u32)> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeTiers)
+        .unwrap_or_else(|| Vec::from_array(env, [(0, 0)]))
+}
+
+/// Save the deposit fee tiers.
+pub fn set_fee_tiers(env: &Env, tiers: &Vec<(i128, u32)>) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::FeeTiers, tiers);
+}
+
+/// Retrieve the rounding mode for basis-point cuts (default `Down`).
+pub fn get_rounding_mode(env: &Env) -> RoundingMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoundingMode)
+        .unwrap_or(RoundingMode::Down)
+}
+
+/// Save the rounding mode for basis-point cuts.
+pub fn set_rounding_mode(env: &Env, mode: &RoundingMode) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::RoundingMode, mode);
+}
+
+/// Retrieve the summed oracle weight verification needs (default `0`, off).
+pub fn get_consensus_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ConsensusThreshold)
+        .unwrap_or(0)
+}
+
+/// Save the summed oracle weight verification needs.
+pub fn set_consensus_threshold(env: &Env, threshold: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ConsensusThreshold, &threshold);
+}
+
+/// Retrieve the minimum seconds between oracle confirmations (default `0`, off).
+pub fn get_confirmation_gap(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ConfirmationGap)
+        .unwrap_or(0)
+}
+
+/// Save the minimum seconds between oracle confirmations.
+pub fn set_confirmation_gap(env: &Env, gap: u64) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::ConfirmationGap, &gap);
+}
+
+/// Return whether Admin and SuperAdmin may register projects (default true).
+pub fn get_admins_can_register(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AdminsCanRegister)
+        .unwrap_or(true)
+}
+
+/// Allow or forbid Admin and SuperAdmin from registering projects.
+pub fn set_admins_can_register(env: &Env, allowed: bool) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::AdminsCanRegister, &allowed);
+}
+
+/// Return whether release requires the creator to still hold a
+/// registration-capable role (default false).
+pub fn get_require_creator_role(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequireCreatorRole)
+        .unwrap_or(false)
+}
+
+/// Switch the creator role check on release on or off.
+pub fn set_require_creator_role(env: &Env, required: bool) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::RequireCreatorRole, &required);
+}
+
+/// Return whether projects may only accept globally approved tokens.
+pub fn is_token_gate_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::TokenGateEnabled)
+        .unwrap_or(false)
+}
+
+/// Turn the global approved-token gate on or off.
+pub fn set_token_gate_enabled(env: &Env, enabled: bool) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::TokenGateEnabled, &enabled);
+}
+
+/// Retrieve the per-project token cap (default `DEFAULT_MAX_TOKENS`).
+pub fn get_max_tokens_per_project(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxTokensPerProject)
+        .unwrap_or(crate::DEFAULT_MAX_TOKENS)
+}
+
+/// Save the per-project token cap.
+pub fn set_max_tokens_per_project(env: &Env, max: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxTokensPerProject, &max);
+}
+
+/// Return the admin-configured category allow-list (empty = unrestricted).
+pub fn get_allowed_categories(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowedCategories)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Replace the category allow-list.
+pub fn set_allowed_categories(env: &Env, categories: &Vec<Symbol>) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::AllowedCategories, categories);
+}
+
 // ── Persistent Storage Helpers ───────────────────────────────────────
 
 /// Extend the TTL for a persistent storage key.
@@ -167,6 +761,22 @@ pub fn save_project(env: &Env, project: &Project) {
         categories: project.categories,
         authorized_oracles: project.authorized_oracles.clone(),
         threshold: project.threshold,
+        hash_algo: project.hash_algo.clone(),
+        deposit_deadline: project.deposit_deadline,
+        open_ended: project.open_ended,
+        overshoot_policy: project.overshoot_policy.clone(),
+        release_to: project.release_to.clone(),
+        category: project.category.clone(),
+        created_at: project.created_at,
+        required_proofs: project.required_proofs.clone(),
+        funding_model: project.funding_model.clone(),
+        visibility: project.visibility.clone(),
+        lock_on_first_confirmation: project.lock_on_first_confirmation,
+        institutions_only: project.institutions_only,
+        display_goal_usd: project.display_goal_usd,
+        tags: project.tags.clone(),
+        soft_cap: project.soft_cap,
+        tokens_locked: project.tokens_locked,
     };
 
     let state = ProjectState {
@@ -285,6 +895,22 @@ pub fn load_project(env: &Env, id: u64) -> Project {
         completed_milestones: state.completed_milestones,
         authorized_oracles: config.authorized_oracles,
         threshold: config.threshold,
+        hash_algo: config.hash_algo,
+        deposit_deadline: config.deposit_deadline,
+        open_ended: config.open_ended,
+        overshoot_policy: config.overshoot_policy,
+        release_to: config.release_to,
+        category: config.category,
+        created_at: config.created_at,
+        required_proofs: config.required_proofs,
+        funding_model: config.funding_model,
+        visibility: config.visibility,
+        lock_on_first_confirmation: config.lock_on_first_confirmation,
+        institutions_only: config.institutions_only,
+        display_goal_usd: config.display_goal_usd,
+        tags: config.tags,
+        soft_cap: config.soft_cap,
+        tokens_locked: config.tokens_locked,
     }
 }
 
@@ -318,6 +944,22 @@ pub fn maybe_load_project(env: &Env, id: u64) -> Option<Project> {
         completed_milestones: state.completed_milestones,
         authorized_oracles: config.authorized_oracles,
         threshold: config.threshold,
+        hash_algo: config.hash_algo,
+        deposit_deadline: config.deposit_deadline,
+        open_ended: config.open_ended,
+        overshoot_policy: config.overshoot_policy,
+        release_to: config.release_to,
+        category: config.category,
+        created_at: config.created_at,
+        required_proofs: config.required_proofs,
+        funding_model: config.funding_model,
+        visibility: config.visibility,
+        lock_on_first_confirmation: config.lock_on_first_confirmation,
+        institutions_only: config.institutions_only,
+        display_goal_usd: config.display_goal_usd,
+        tags: config.tags,
+        soft_cap: config.soft_cap,
+        tokens_locked: config.tokens_locked,
     })
 }
 
@@ -341,6 +983,8 @@ pub fn set_token_balance(env: &Env, project_id: u64, token: &Address, balance: i
 }
 
 /// Add `amount` to the existing balance of `token` for `project_id`.
+///
+/// The contract-wide total for `token` is adjusted by the same amount.
 pub fn add_to_token_balance(env: &Env, project_id: u64, token: &Address, amount: i128) -> i128 {
     let current = get_token_balance(env, project_id, token);
     let new_balance = match current.checked_add(amount) {
@@ -348,18 +992,92 @@ pub fn add_to_token_balance(env: &Env, project_id: u64, token: &Address, amount:
         None => panic_with_error!(env, Error::Overflow),
     };
     set_token_balance(env, project_id, token, new_balance);
+    adjust_global_token_balance(env, token, amount);
+    new_balance
+}
+
+/// Subtract `amount` from the balance of `token` for `project_id`.
+///
+/// Panics with `BalanceUnderflow` rather than letting the balance go
+/// negative. The contract-wide total for `token` is reduced by the same
+/// amount.
+pub fn sub_from_token_balance(env: &Env, project_id: u64, token: &Address, amount: i128) -> i128 {
+    let current = get_token_balance(env, project_id, token);
+    let new_balance = match current.checked_sub(amount) {
+        Some(b) if b >= 0 => b,
+        _ => panic_with_error!(env, Error::BalanceUnderflow),
+    };
+    set_token_balance(env, project_id, token, new_balance);
+    adjust_global_token_balance(env, token, -amount);
     new_balance
 }
 
 /// Zero out the balance of `token` for `project_id` and return what it was.
+///
+/// The drained amount is removed from the contract-wide total for `token`.
 pub fn drain_token_balance(env: &Env, project_id: u64, token: &Address) -> i128 {
     let balance = get_token_balance(env, project_id, token);
+    if balance < 0 {
+        panic_with_error!(env, Error::BalanceUnderflow);
+    }
     if balance > 0 {
         set_token_balance(env, project_id, token, 0);
+        adjust_global_token_balance(env, token, -balance);
     }
     balance
 }
 
+/// Retrieve the total balance of `token` held across all projects.
+pub fn get_global_token_balance(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::GlobalTokenBalance(token.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(balance) => {
+            bump_persistent(env, &key);
+            balance
+        }
+        None => 0,
+    }
+}
+
+/// Apply `delta` to the contract-wide total for `token`.
+fn adjust_global_token_balance(env: &Env, token: &Address, delta: i128) {
+    let key = DataKey::GlobalTokenBalance(token.clone());
+    let current = get_global_token_balance(env, token);
+    let updated = match current.checked_add(delta) {
+        Some(b) if b >= 0 => b,
+        Some(_) => panic_with_error!(env, Error::BalanceUnderflow),
+        None => panic_with_error!(env, Error::Overflow),
+    };
+    env.storage().persistent().set(&key, &updated);
+    bump_persistent(env, &key);
+}
+
+/// Retrieve the protocol fees accrued in `token`.
+pub fn get_treasury_balance(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::TreasuryBalance(token.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(balance) => {
+            bump_persistent(env, &key);
+            balance
+        }
+        None => 0,
+    }
+}
+
+/// Apply `delta` to the accrued fees for `token` and return the new total.
+pub fn add_to_treasury(env: &Env, token: &Address, delta: i128) -> i128 {
+    let key = DataKey::TreasuryBalance(token.clone());
+    let current = get_treasury_balance(env, token);
+    let updated = match current.checked_add(delta) {
+        Some(b) if b >= 0 => b,
+        Some(_) => panic_with_error!(env, Error::BalanceUnderflow),
+        None => panic_with_error!(env, Error::Overflow),
+    };
+    env.storage().persistent().set(&key, &updated);
+    bump_persistent(env, &key);
+    updated
+}
+
 /// Build a `ProjectBalances` snapshot by reading each accepted token's balance.
 pub fn get_all_balances(env: &Env, project: &Project) -> ProjectBalances {
     let mut balances: Vec<TokenBalance> = Vec::new(env);
@@ -380,13 +1098,35 @@ pub fn get_all_balances(env: &Env, project: &Project) -> ProjectBalances {
 
 /// Retrieve a donator's contributed balance for (project_id, token).
 pub fn get_donator_balance(env: &Env, project_id: u64, token: &Address, donator: &Address) -> i128 {
+    donator_balance_within(env, project_id, token, donator, MAX_MIGRATION_HOPS)
+}
+
+fn donator_balance_within(
+    env: &Env,
+    project_id: u64,
+    token: &Address,
+    donator: &Address,
+    hops: u32,
+) -> i128 {
     let key = DataKey::DonatorBalance(project_id, token.clone(), donator.clone());
     match env.storage().persistent().get::<DataKey, i128>(&key) {
         Some(balance) => {
             bump_persistent(env, &key);
             balance
         }
-        None => 0,
+        // A balance recorded before `migrate_token` moves across on first access.
+        None => match get_migrated_token(env, project_id, token).filter(|_| hops > 0) {
+            Some(old) => {
+                let balance = donator_balance_within(env, project_id, &old, donator, hops - 1);
+                if balance != 0 {
+                    let old_key = DataKey::DonatorBalance(project_id, old, donator.clone());
+                    env.storage().persistent().remove(&old_key);
+                    set_donator_balance(env, project_id, token, donator, balance);
+                }
+                balance
+            }
+            None => 0,
+        },
     }
 }
 
@@ -420,6 +1160,851 @@ pub fn add_to_donator_balance(
     new_balance
 }
 
+// ── Registration Throttling ──────────────────────────────────────────
+
+/// Timestamp of `creator`'s most recent registration, if any.
+pub fn get_last_register(env: &Env, creator: &Address) -> Option<u64> {
+    let key = DataKey::LastRegister(creator.clone());
+    let last: Option<u64> = env.storage().persistent().get(&key);
+    if last.is_some() {
+        bump_persistent(env, &key);
+    }
+    last
+}
+
+/// Record the timestamp of `creator`'s most recent registration.
+pub fn set_last_register(env: &Env, creator: &Address, timestamp: u64) {
+    let key = DataKey::LastRegister(creator.clone());
+    env.storage().persistent().set(&key, &timestamp);
+    bump_persistent(env, &key);
+}
+
+/// Retrieve the timestamp of `project_id`'s last announcement, if any.
+pub fn get_last_announce(env: &Env, project_id: u64) -> Option<u64> {
+    let key = DataKey::LastAnnounce(project_id);
+    let last: Option<u64> = env.storage().persistent().get(&key);
+    if last.is_some() {
+        bump_persistent(env, &key);
+    }
+    last
+}
+
+/// Record the timestamp of `project_id`'s most recent announcement.
+pub fn set_last_announce(env: &Env, project_id: u64, timestamp: u64) {
+    let key = DataKey::LastAnnounce(project_id);
+    env.storage().persistent().set(&key, &timestamp);
+    bump_persistent(env, &key);
+}
+
+/// Store `memo` as the next donation memo for (`project_id`, `donator`) and
+/// return the sequence number it was filed under.
+pub fn push_donation_memo(env: &Env, project_id: u64, donator: &Address, memo: &String) -> u32 {
+    let seq_key = DataKey::DonationSeq(project_id, donator.clone());
+    let seq: u32 = env.storage().persistent().get(&seq_key).unwrap_or(0);
+    let memo_key = DataKey::DonationMemo(project_id, donator.clone(), seq);
+    env.storage().persistent().set(&memo_key, memo);
+    bump_persistent(env, &memo_key);
+    env.storage().persistent().set(&seq_key, &(seq + 1));
+    bump_persistent(env, &seq_key);
+    seq
+}
+
+/// Load the memo filed under `seq` for (`project_id`, `donator`), if any.
+pub fn get_donation_memo(env: &Env, project_id: u64, donator: &Address, seq: u32) -> Option<String> {
+    let key = DataKey::DonationMemo(project_id, donator.clone(), seq);
+    let memo: Option<String> = env.storage().persistent().get(&key);
+    if memo.is_some() {
+        bump_persistent(env, &key);
+    }
+    memo
+}
+
+/// Total donated toward `earmark` on `project_id`, across all tokens.
+pub fn get_earmark_total(env: &Env, project_id: u64, earmark: &Symbol) -> i128 {
+    let key = DataKey::Earmarked(project_id, earmark.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(total) => {
+            bump_persistent(env, &key);
+            total
+        }
+        None => 0,
+    }
+}
+
+/// Add `amount` to the running total for `earmark` on `project_id`.
+pub fn add_to_earmark(env: &Env, project_id: u64, earmark: &Symbol, amount: i128) {
+    let key = DataKey::Earmarked(project_id, earmark.clone());
+    let total = get_earmark_total(env, project_id, earmark)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+    env.storage().persistent().set(&key, &total);
+    bump_persistent(env, &key);
+}
+
+/// Number of entries in `project_id`'s donation log.
+pub fn get_donation_entry_count(env: &Env, project_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DonationEntryCount(project_id))
+        .unwrap_or(0)
+}
+
+/// Append `record` to `project_id`'s donation log; returns its sequence.
+pub fn push_donation_entry(env: &Env, project_id: u64, record: &DonationRecord) -> u32 {
+    let seq = get_donation_entry_count(env, project_id);
+    let entry_key = DataKey::DonationEntry(project_id, seq);
+    env.storage().persistent().set(&entry_key, record);
+    bump_persistent(env, &entry_key);
+    let count_key = DataKey::DonationEntryCount(project_id);
+    env.storage().persistent().set(&count_key, &(seq + 1));
+    bump_persistent(env, &count_key);
+    seq
+}
+
+/// Load the donation log entry `seq` for `project_id`, if any.
+pub fn get_donation_entry(env: &Env, project_id: u64, seq: u32) -> Option<DonationRecord> {
+    let key = DataKey::DonationEntry(project_id, seq);
+    let record: Option<DonationRecord> = env.storage().persistent().get(&key);
+    if record.is_some() {
+        bump_persistent(env, &key);
+    }
+    record
+}
+
+/// Number of audit records ever written, including overwritten ones.
+pub fn get_audit_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuditCount)
+        .unwrap_or(0)
+}
+
+/// Append an audit record, overwriting the oldest once the ring is full.
+pub fn push_audit_record(
+    env: &Env,
+    actor: &Address,
+    action: Symbol,
+    subject: AuditSubject,
+) {
+    let seq = get_audit_count(env);
+    let record = AuditRecord {
+        seq,
+        actor: actor.clone(),
+        action,
+        subject,
+        timestamp: env.ledger().timestamp(),
+    };
+    let entry_key = DataKey::AuditEntry(seq % crate::AUDIT_LOG_SIZE);
+    env.storage().persistent().set(&entry_key, &record);
+    bump_persistent(env, &entry_key);
+    env.storage().persistent().set(&DataKey::AuditCount, &(seq + 1));
+    bump_persistent(env, &DataKey::AuditCount);
+}
+
+/// Load audit record `seq`, if it is still held in the ring.
+pub fn get_audit_record(env: &Env, seq: u32) -> Option<AuditRecord> {
+    let key = DataKey::AuditEntry(seq % crate::AUDIT_LOG_SIZE);
+    let record: Option<AuditRecord> = env.storage().persistent().get(&key);
+    let record = record.filter(|r| r.seq == seq);
+    if record.is_some() {
+        bump_persistent(env, &key);
+    }
+    record
+}
+
+/// Number of projects with `token` among their accepted tokens.
+pub fn get_token_project_count(env: &Env, token: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenProjectCount(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Append `project_id` to the reverse index of projects accepting `token`.
+pub fn push_token_project(env: &Env, token: &Address, project_id: u64) {
+    let index = get_token_project_count(env, token);
+    set_token_project(env, token, index, project_id);
+    let count_key = DataKey::TokenProjectCount(token.clone());
+    env.storage().persistent().set(&count_key, &(index + 1));
+    bump_persistent(env, &count_key);
+}
+
+/// Drop `project_id` from the reverse index of projects accepting `token`.
+/// The last entry moves into its slot, so the order of the rest may change.
+pub fn remove_token_project(env: &Env, token: &Address, project_id: u64) {
+    let mut positions = get_token_project_positions(env, project_id);
+    let Some(index) = positions.get(token.clone()) else {
+        return;
+    };
+    positions.remove(token.clone());
+    set_token_project_positions(env, project_id, &positions);
+    let last = get_token_project_count(env, token) - 1;
+    if index != last {
+        if let Some(moved) = get_token_project(env, token, last) {
+            set_token_project(env, token, index, moved);
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TokenProject(token.clone(), last));
+    let count_key = DataKey::TokenProjectCount(token.clone());
+    env.storage().persistent().set(&count_key, &last);
+    bump_persistent(env, &count_key);
+}
+
+/// Store `project_id` at `index` of `token`'s reverse index.
+fn set_token_project(env: &Env, token: &Address, index: u32, project_id: u64) {
+    let entry_key = DataKey::TokenProject(token.clone(), index);
+    env.storage().persistent().set(&entry_key, &project_id);
+    bump_persistent(env, &entry_key);
+    let mut positions = get_token_project_positions(env, project_id);
+    positions.set(token.clone(), index);
+    set_token_project_positions(env, project_id, &positions);
+}
+
+fn get_token_project_positions(env: &Env, project_id: u64) -> Map<Address, u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenProjectPos(project_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+fn set_token_project_positions(env: &Env, project_id: u64, positions: &Map<Address, u32>) {
+    let key = DataKey::TokenProjectPos(project_id);
+    env.storage().persistent().set(&key, positions);
+    bump_persistent(env, &key);
+}
+
+/// Load the `index`-th project accepting `token`, if any.
+pub fn get_token_project(env: &Env, token: &Address, index: u32) -> Option<u64> {
+    let key = DataKey::TokenProject(token.clone(), index);
+    let project_id: Option<u64> = env.storage().persistent().get(&key);
+    if project_id.is_some() {
+        bump_persistent(env, &key);
+    }
+    project_id
+}
+
+/// Projects `donator` has contributed to, in order of first donation.
+pub fn get_donor_projects(env: &Env, donator: &Address) -> Vec<u64> {
+    let key = DataKey::DonorProjects(donator.clone());
+    match env.storage().persistent().get::<DataKey, Vec<u64>>(&key) {
+        Some(projects) => {
+            bump_persistent(env, &key);
+            projects
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Load the IDs of projects currently tagged with `tag`.
+pub fn get_tag_projects(env: &Env, tag: &Symbol) -> Vec<u64> {
+    let key = DataKey::TagProjects(tag.clone());
+    match env.storage().persistent().get::<DataKey, Vec<u64>>(&key) {
+        Some(projects) => {
+            bump_persistent(env, &key);
+            projects
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Add `project_id` to the index for `tag`, once per project.
+pub fn add_tag_project(env: &Env, tag: &Symbol, project_id: u64) {
+    let mut projects = get_tag_projects(env, tag);
+    if projects.contains(project_id) {
+        return;
+    }
+    projects.push_back(project_id);
+    let key = DataKey::TagProjects(tag.clone());
+    env.storage().persistent().set(&key, &projects);
+    bump_persistent(env, &key);
+}
+
+/// Drop `project_id` from the index for `tag`, removing the entry once empty.
+pub fn remove_tag_project(env: &Env, tag: &Symbol, project_id: u64) {
+    let mut projects = get_tag_projects(env, tag);
+    let Some(index) = projects.first_index_of(project_id) else {
+        return;
+    };
+    projects.remove(index);
+    let key = DataKey::TagProjects(tag.clone());
+    if projects.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &projects);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Record that `donator` contributed to `project_id`, once per project.
+pub fn add_donor_project(env: &Env, donator: &Address, project_id: u64) {
+    let mut projects = get_donor_projects(env, donator);
+    if projects.contains(project_id) {
+        return;
+    }
+    projects.push_back(project_id);
+    let key = DataKey::DonorProjects(donator.clone());
+    env.storage().persistent().set(&key, &projects);
+    bump_persistent(env, &key);
+}
+
+/// Total amount of `token` refunded to donors of `project_id`.
+pub fn get_total_refunded(env: &Env, project_id: u64, token: &Address) -> i128 {
+    let key = DataKey::TotalRefunded(project_id, token.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(total) => {
+            bump_persistent(env, &key);
+            total
+        }
+        None => 0,
+    }
+}
+
+/// Add `amount` to the refund total of `token` for `project_id`.
+pub fn add_to_total_refunded(env: &Env, project_id: u64, token: &Address, amount: i128) {
+    let key = DataKey::TotalRefunded(project_id, token.clone());
+    let total = get_total_refunded(env, project_id, token)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+    env.storage().persistent().set(&key, &total);
+    bump_persistent(env, &key);
+}
+
+/// Number of balance snapshots taken for `project_id`.
+pub fn get_snapshot_count(env: &Env, project_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SnapshotCount(project_id))
+        .unwrap_or(0)
+}
+
+/// Append `balances` as the next snapshot for `project_id`; returns its index.
+pub fn push_snapshot(env: &Env, project_id: u64, balances: &ProjectBalances) -> u32 {
+    let index = get_snapshot_count(env, project_id);
+    let snapshot_key = DataKey::Snapshot(project_id, index);
+    env.storage().persistent().set(&snapshot_key, balances);
+    bump_persistent(env, &snapshot_key);
+    let count_key = DataKey::SnapshotCount(project_id);
+    env.storage().persistent().set(&count_key, &(index + 1));
+    bump_persistent(env, &count_key);
+    index
+}
+
+/// Load the snapshot stored under `index` for `project_id`, if any.
+pub fn get_snapshot(env: &Env, project_id: u64, index: u32) -> Option<ProjectBalances> {
+    let key = DataKey::Snapshot(project_id, index);
+    let snapshot: Option<ProjectBalances> = env.storage().persistent().get(&key);
+    if snapshot.is_some() {
+        bump_persistent(env, &key);
+    }
+    snapshot
+}
+
+/// Amount `donator` has pledged in `token` to `project_id` (0 = none).
+pub fn get_pledge(env: &Env, project_id: u64, donator: &Address, token: &Address) -> i128 {
+    let key = DataKey::Pledge(project_id, donator.clone(), token.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(amount) => {
+            bump_persistent(env, &key);
+            amount
+        }
+        None => 0,
+    }
+}
+
+/// Set the open pledge of `donator` in `token`; 0 clears it. Keeps the
+/// project's `Pledgers` index in step.
+pub fn set_pledge(env: &Env, project_id: u64, donator: &Address, token: &Address, amount: i128) {
+    let key = DataKey::Pledge(project_id, donator.clone(), token.clone());
+    let had_pledge = env.storage().persistent().has(&key);
+    let mut pledgers = get_pledgers(env, project_id);
+    let pair = (donator.clone(), token.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+        if let Some(i) = pledgers.first_index_of(&pair) {
+            pledgers.remove(i);
+        }
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        bump_persistent(env, &key);
+        if !had_pledge {
+            pledgers.push_back(pair);
+        }
+    }
+    let index_key = DataKey::Pledgers(project_id);
+    if pledgers.is_empty() {
+        env.storage().persistent().remove(&index_key);
+    } else {
+        env.storage().persistent().set(&index_key, &pledgers);
+        bump_persistent(env, &index_key);
+    }
+}
+
+/// (donator, token) pairs with an open pledge on `project_id`.
+pub fn get_pledgers(env: &Env, project_id: u64) -> Vec<(Address, Address)> {
+    let key = DataKey::Pledgers(project_id);
+    match env.storage().persistent().get::<DataKey, Vec<(Address, Address)>>(&key) {
+        Some(pledgers) => {
+            bump_persistent(env, &key);
+            pledgers
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Return true if the creator has paused donations to `project_id`.
+pub fn is_donations_paused(env: &Env, project_id: u64) -> bool {
+    let key = DataKey::DonationsPaused(project_id);
+    let paused = env.storage().persistent().get(&key).unwrap_or(false);
+    if paused {
+        bump_persistent(env, &key);
+    }
+    paused
+}
+
+/// Set or clear the creator's donation pause for `project_id`.
+pub fn set_donations_paused(env: &Env, project_id: u64, paused: bool) {
+    let key = DataKey::DonationsPaused(project_id);
+    if paused {
+        env.storage().persistent().set(&key, &true);
+        bump_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Retrieve the refundable balance held for an anonymous `commitment`.
+pub fn get_anon_donor_balance(
+    env: &Env,
+    project_id: u64,
+    token: &Address,
+    commitment: &BytesN<32>,
+) -> i128 {
+    anon_donor_balance_within(env, project_id, token, commitment, MAX_MIGRATION_HOPS)
+}
+
+fn anon_donor_balance_within(
+    env: &Env,
+    project_id: u64,
+    token: &Address,
+    commitment: &BytesN<32>,
+    hops: u32,
+) -> i128 {
+    let key = DataKey::AnonDonor(project_id, token.clone(), commitment.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(balance) => {
+            bump_persistent(env, &key);
+            balance
+        }
+        // A balance recorded before `migrate_token` moves across on first access.
+        None => match get_migrated_token(env, project_id, token).filter(|_| hops > 0) {
+            Some(old) => {
+                let balance =
+                    anon_donor_balance_within(env, project_id, &old, commitment, hops - 1);
+                if balance != 0 {
+                    let old_key = DataKey::AnonDonor(project_id, old, commitment.clone());
+                    env.storage().persistent().remove(&old_key);
+                    set_anon_donor_balance(env, project_id, token, commitment, balance);
+                }
+                balance
+            }
+            None => 0,
+        },
+    }
+}
+
+/// Token that `new_token` replaced on `project_id` via `migrate_token`, if any.
+pub fn get_migrated_token(env: &Env, project_id: u64, new_token: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MigratedToken(project_id, new_token.clone()))
+}
+
+/// Record that `new_token` replaced `old_token` on `project_id`.
+pub fn set_migrated_token(env: &Env, project_id: u64, new_token: &Address, old_token: &Address) {
+    let key = DataKey::MigratedToken(project_id, new_token.clone());
+    env.storage().persistent().set(&key, old_token);
+    bump_persistent(env, &key);
+}
+
+/// Forget which token `new_token` replaced on `project_id`.
+pub fn clear_migrated_token(env: &Env, project_id: u64, new_token: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MigratedToken(project_id, new_token.clone()));
+}
+
+/// Save the refundable balance held for an anonymous `commitment`.
+pub fn set_anon_donor_balance(
+    env: &Env,
+    project_id: u64,
+    token: &Address,
+    commitment: &BytesN<32>,
+    amount: i128,
+) {
+    let key = DataKey::AnonDonor(project_id, token.clone(), commitment.clone());
+    env.storage().persistent().set(&key, &amount);
+    bump_persistent(env, &key);
+}
+
+/// Load the deposit increment for `token` on `project_id` (0 = any amount).
+pub fn get_deposit_increment(env: &Env, project_id: u64, token: &Address) -> i128 {
+    let key = DataKey::DepositIncrement(project_id, token.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(increment) => {
+            bump_persistent(env, &key);
+            increment
+        }
+        None => 0,
+    }
+}
+
+/// Save the deposit increment for `token` on `project_id`; 0 clears it.
+pub fn set_deposit_increment(env: &Env, project_id: u64, token: &Address, increment: i128) {
+    let key = DataKey::DepositIncrement(project_id, token.clone());
+    if increment == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &increment);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Load the per-donor contribution cap for `project_id` (0 = no cap).
+pub fn get_max_per_donor(env: &Env, project_id: u64) -> i128 {
+    let key = DataKey::MaxPerDonor(project_id);
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(cap) => {
+            bump_persistent(env, &key);
+            cap
+        }
+        None => 0,
+    }
+}
+
+/// Save the per-donor contribution cap for `project_id`; 0 clears it.
+pub fn set_max_per_donor(env: &Env, project_id: u64, cap: i128) {
+    let key = DataKey::MaxPerDonor(project_id);
+    if cap == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &cap);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Load the minimum deposit for `project_id` (0 = none).
+pub fn get_min_deposit(env: &Env, project_id: u64) -> i128 {
+    let key = DataKey::MinDeposit(project_id);
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(min) => {
+            bump_persistent(env, &key);
+            min
+        }
+        None => 0,
+    }
+}
+
+/// Save the minimum deposit for `project_id`; 0 clears it.
+pub fn set_min_deposit(env: &Env, project_id: u64, min: i128) {
+    let key = DataKey::MinDeposit(project_id);
+    if min == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &min);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Return true if required proof `index` of `project_id` has been matched.
+pub fn is_proof_matched(env: &Env, project_id: u64, index: u32) -> bool {
+    let key = DataKey::ProofMatched(project_id, index);
+    let matched = env.storage().persistent().get(&key).unwrap_or(false);
+    if matched {
+        bump_persistent(env, &key);
+    }
+    matched
+}
+
+/// Mark required proof `index` of `project_id` as matched.
+pub fn set_proof_matched(env: &Env, project_id: u64, index: u32) {
+    let key = DataKey::ProofMatched(project_id, index);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Forget that required proof `index` of `project_id` was matched.
+pub fn clear_proof_matched(env: &Env, project_id: u64, index: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ProofMatched(project_id, index));
+}
+
+/// Load the vesting duration of `project_id` in seconds (0 = lump release).
+pub fn get_vesting_duration(env: &Env, project_id: u64) -> u64 {
+    let key = DataKey::VestingDuration(project_id);
+    match env.storage().persistent().get::<DataKey, u64>(&key) {
+        Some(duration) => {
+            bump_persistent(env, &key);
+            duration
+        }
+        None => 0,
+    }
+}
+
+/// Save the vesting duration of `project_id`; 0 clears it.
+pub fn set_vesting_duration(env: &Env, project_id: u64, duration: u64) {
+    let key = DataKey::VestingDuration(project_id);
+    if duration == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &duration);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Load how much `token` has been claimed through vesting for `project_id`.
+pub fn get_vesting_claimed(env: &Env, project_id: u64, token: &Address) -> i128 {
+    let key = DataKey::VestingClaimed(project_id, token.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(claimed) => {
+            bump_persistent(env, &key);
+            claimed
+        }
+        None => 0,
+    }
+}
+
+/// Save how much `token` has been claimed through vesting for `project_id`.
+pub fn set_vesting_claimed(env: &Env, project_id: u64, token: &Address, claimed: i128) {
+    let key = DataKey::VestingClaimed(project_id, token.clone());
+    env.storage().persistent().set(&key, &claimed);
+    bump_persistent(env, &key);
+}
+
+/// Load the oracle that verified `project_id`, if it was verified by one.
+pub fn get_verifier(env: &Env, project_id: u64) -> Option<Address> {
+    let key = DataKey::Verifier(project_id);
+    let verifier = env.storage().persistent().get(&key);
+    if verifier.is_some() {
+        bump_persistent(env, &key);
+    }
+    verifier
+}
+
+/// Record the oracle that verified `project_id`.
+pub fn set_verifier(env: &Env, project_id: u64, oracle: &Address) {
+    let key = DataKey::Verifier(project_id);
+    env.storage().persistent().set(&key, oracle);
+    bump_persistent(env, &key);
+}
+
+/// Load how many signed verifications `project_id` has accepted.
+pub fn get_signed_nonce(env: &Env, project_id: u64) -> u32 {
+    let key = DataKey::SignedNonce(project_id);
+    match env.storage().persistent().get::<DataKey, u32>(&key) {
+        Some(nonce) => {
+            bump_persistent(env, &key);
+            nonce
+        }
+        None => 0,
+    }
+}
+
+/// Advance `project_id`'s signed-verification nonce.
+pub fn increment_signed_nonce(env: &Env, project_id: u64) {
+    let key = DataKey::SignedNonce(project_id);
+    let nonce = get_signed_nonce(env, project_id);
+    env.storage().persistent().set(&key, &(nonce + 1));
+    bump_persistent(env, &key);
+}
+
+/// Forget the oracle that verified `project_id`.
+pub fn clear_verifier(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Verifier(project_id));
+}
+
+/// Load the consensus weight of `oracle` (default `1`).
+pub fn get_oracle_weight(env: &Env, oracle: &Address) -> u32 {
+    let key = DataKey::OracleWeight(oracle.clone());
+    match env.storage().persistent().get::<DataKey, u32>(&key) {
+        Some(weight) => {
+            bump_persistent(env, &key);
+            weight
+        }
+        None => 1,
+    }
+}
+
+/// Set the consensus weight of `oracle`.
+pub fn set_oracle_weight(env: &Env, oracle: &Address, weight: u32) {
+    let key = DataKey::OracleWeight(oracle.clone());
+    env.storage().persistent().set(&key, &weight);
+    bump_persistent(env, &key);
+}
+
+/// Load the per-token goal for `token` on `project_id` (0 = none).
+pub fn get_token_goal(env: &Env, project_id: u64, token: &Address) -> i128 {
+    let key = DataKey::TokenGoal(project_id, token.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(goal) => {
+            bump_persistent(env, &key);
+            goal
+        }
+        None => 0,
+    }
+}
+
+/// Save the per-token goal for `token` on `project_id`; 0 clears it.
+pub fn set_token_goal(env: &Env, project_id: u64, token: &Address, goal: i128) {
+    let key = DataKey::TokenGoal(project_id, token.clone());
+    if goal == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &goal);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Return true once `ready_ver` has been emitted for `project_id`.
+pub fn is_ready_signalled(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ReadySignalled(project_id))
+}
+
+/// Record that `ready_ver` has been emitted for `project_id`.
+pub fn set_ready_signalled(env: &Env, project_id: u64) {
+    let key = DataKey::ReadySignalled(project_id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Whether an oracle confirmation has been recorded for `project_id`.
+pub fn is_confirmation_recorded(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ConfirmationRecorded(project_id))
+}
+
+/// Record that an oracle confirmation is pending for `project_id`.
+pub fn set_confirmation_recorded(env: &Env, project_id: u64) {
+    let key = DataKey::ConfirmationRecorded(project_id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Forget any recorded oracle confirmation for `project_id`.
+pub fn clear_confirmation_recorded(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ConfirmationRecorded(project_id));
+}
+
+/// Whether any of `project_id`'s funds have been paid out.
+pub fn is_funds_released(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::FundsReleased(project_id))
+}
+
+/// Record that some of `project_id`'s funds have been paid out.
+pub fn set_funds_released(env: &Env, project_id: u64) {
+    let key = DataKey::FundsReleased(project_id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Load when an oracle last confirmed `project_id` (0 = none pending).
+pub fn get_last_confirmation_at(env: &Env, project_id: u64) -> u64 {
+    let key = DataKey::LastConfirmationAt(project_id);
+    match env.storage().persistent().get::<DataKey, u64>(&key) {
+        Some(timestamp) => {
+            bump_persistent(env, &key);
+            timestamp
+        }
+        None => 0,
+    }
+}
+
+/// Record that an oracle confirmed `project_id` at `timestamp`.
+pub fn set_last_confirmation_at(env: &Env, project_id: u64, timestamp: u64) {
+    let key = DataKey::LastConfirmationAt(project_id);
+    env.storage().persistent().set(&key, &timestamp);
+    bump_persistent(env, &key);
+}
+
+/// Forget the latest confirmation time for `project_id`.
+pub fn clear_last_confirmation_at(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LastConfirmationAt(project_id));
+}
+
+/// Load the time `project_id` first reached its goal (0 = not recorded).
+pub fn get_goal_reached_at(env: &Env, project_id: u64) -> u64 {
+    let key = DataKey::GoalReachedAt(project_id);
+    match env.storage().persistent().get::<DataKey, u64>(&key) {
+        Some(timestamp) => {
+            bump_persistent(env, &key);
+            timestamp
+        }
+        None => 0,
+    }
+}
+
+/// Record the time `project_id` reached its goal.
+pub fn set_goal_reached_at(env: &Env, project_id: u64, timestamp: u64) {
+    let key = DataKey::GoalReachedAt(project_id);
+    env.storage().persistent().set(&key, &timestamp);
+    bump_persistent(env, &key);
+}
+
+/// Return true if `token` is in the global approved-token registry.
+pub fn is_token_approved(env: &Env, token: &Address) -> bool {
+    let key = DataKey::GlobalToken(token.clone());
+    let exists = env.storage().persistent().has(&key);
+    if exists {
+        bump_persistent(env, &key);
+    }
+    exists
+}
+
+/// Add `token` to, or remove it from, the global approved-token registry.
+pub fn set_token_approved(env: &Env, token: &Address, approved: bool) {
+    let key = DataKey::GlobalToken(token.clone());
+    if approved {
+        env.storage().persistent().set(&key, &true);
+        bump_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Return true if `address` is in the institution registry.
+pub fn is_institution(env: &Env, address: &Address) -> bool {
+    let key = DataKey::Institution(address.clone());
+    let exists = env.storage().persistent().has(&key);
+    if exists {
+        bump_persistent(env, &key);
+    }
+    exists
+}
+
+/// Add `address` to, or remove it from, the institution registry.
+pub fn set_institution(env: &Env, address: &Address, institution: bool) {
+    let key = DataKey::Institution(address.clone());
+    if institution {
+        env.storage().persistent().set(&key, &true);
+        bump_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 /// Return true if `address` is on the whitelist for `project_id`.
 pub fn is_whitelisted(env: &Env, project_id: u64, address: &Address) -> bool {
     let key = DataKey::Whitelist(project_id, address.clone());
@@ -472,6 +2057,7 @@ pub fn load_oracle_agreement(env: &Env, project_id: u64) -> OracleAgreement {
         .unwrap_or(OracleAgreement {
             votes: 0,
             voter_count: 0,
+            weight: 0,
         })
 }
 
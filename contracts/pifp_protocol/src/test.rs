@@ -584,7 +584,6 @@ fn test_non_oracle_cannot_verify() {
 }
 
 #[test]
-#[should_panic]
 fn test_verify_wrong_proof_panics() {
     let (env, client, super_admin) = setup_with_init();
     let pm     = Address::generate(&env);
@@ -600,8 +599,9 @@ fn test_verify_wrong_proof_panics() {
         &pm, &token, &100i128, &proof, &future_deadline(&env),
     );
 
-    // Wrong proof hash — must panic
-    client.verify_and_release(&oracle, &project.id, &bad_proof);
+    // Wrong proof hash — must fail with ProofMismatch, not GoalMismatch
+    let result = client.try_verify_and_release(&oracle, &project.id, &bad_proof);
+    assert_eq!(result, Err(Ok(Error::ProofMismatch)));
 }
 
 // ─── 7. deposit: no role required ────────────────────────
@@ -0,0 +1,90 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_admin_cancel_funded_project_enables_refunds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donor_a = ctx.generate_address();
+    let donor_b = ctx.generate_address();
+    sac.mint(&donor_a, &300);
+    sac.mint(&donor_b, &900);
+    ctx.client.deposit(&project.id, &donor_a, &token.address, &300);
+    ctx.client.deposit(&project.id, &donor_b, &token.address, &900);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+
+    ctx.client.admin_cancel_project(&ctx.admin, &project.id);
+    let cancelled = ctx.client.get_project(&project.id);
+    assert_eq!(cancelled.status, ProjectStatus::Cancelled);
+    assert!(cancelled.refund_expiry > 0);
+
+    ctx.client.refund(&donor_a, &project.id, &token.address);
+    ctx.client.refund(&donor_b, &project.id, &token.address);
+    assert_eq!(token.balance(&donor_a), 300);
+    assert_eq!(token.balance(&donor_b), 900);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_admin_cancel_partially_funded_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donor = ctx.generate_address();
+    sac.mint(&donor, &200);
+    ctx.client.deposit(&project.id, &donor, &token.address, &200);
+
+    ctx.client.admin_cancel_project(&ctx.admin, &project.id);
+    ctx.client.refund(&donor, &project.id, &token.address);
+    assert_eq!(token.balance(&donor), 200);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_admin_cancel_during_grace_period_blocks_claim() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(500);
+    ctx.env.mock_all_auths();
+
+    let donor = ctx.generate_address();
+    sac.mint(&donor, &500);
+    ctx.client.deposit(&project.id, &donor, &token.address, &500);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.admin_cancel_project(&ctx.admin, &project.id);
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_creator_cannot_force_cancel() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client.admin_cancel_project(&ctx.manager, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_admin_cancel_completed_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    ctx.client.admin_cancel_project(&ctx.admin, &project.id);
+}
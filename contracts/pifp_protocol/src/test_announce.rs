@@ -0,0 +1,106 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::Events,
+    xdr::{ContractEventBody, ScVal},
+    IntoVal, TryFromVal, Val,
+};
+
+use crate::{events::ProjectCreated, test_utils::TestContext, DEFAULT_HASH_ALGO};
+
+#[test]
+fn test_announce_reemits_project_created() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+
+    ctx.client.announce(&project.id);
+
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    assert_eq!(events.events().len(), 1);
+    let event = events.events().last().unwrap().clone();
+    let ContractEventBody::V0(body) = event.body;
+    assert_eq!(
+        body.topics[0],
+        ScVal::try_from_val(&ctx.env, &symbol_short!("proj_cr")).unwrap()
+    );
+    let expected = ProjectCreated {
+        project_id: project.id,
+        creator: ctx.manager.clone(),
+        token: token.address.clone(),
+        goal: 1000,
+        hash_algo: DEFAULT_HASH_ALGO,
+        display_goal_usd: 0,
+    };
+    let expected_val: Val = expected.into_val(&ctx.env);
+    assert_eq!(body.data, ScVal::try_from_val(&ctx.env, &expected_val).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #50)")]
+fn test_announce_rate_limited() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.announce(&project.id);
+    ctx.jump_time(60);
+    ctx.client.announce(&project.id);
+}
+
+#[test]
+fn test_announce_allowed_after_cooldown() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.announce(&project.id);
+    ctx.jump_time(3_600);
+    ctx.client.announce(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_announce_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.client.announce(&42);
+}
+
+#[test]
+fn test_display_goal_round_trips_through_project_and_event() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    assert_eq!(project.display_goal_usd, 0);
+
+    // $2,500.00 with 6 decimals.
+    ctx.client
+        .set_display_goal_usd(&ctx.manager, &project.id, &2_500_000_000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).display_goal_usd,
+        2_500_000_000
+    );
+
+    ctx.client.announce(&project.id);
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    let event = events.events().last().unwrap().clone();
+    let ContractEventBody::V0(body) = event.body;
+    let expected: Val = ProjectCreated {
+        project_id: project.id,
+        creator: ctx.manager.clone(),
+        token: token.address.clone(),
+        goal: 1000,
+        hash_algo: DEFAULT_HASH_ALGO,
+        display_goal_usd: 2_500_000_000,
+    }
+    .into_val(&ctx.env);
+    assert_eq!(body.data, ScVal::try_from_val(&ctx.env, &expected).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_display_goal_requires_creator_or_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_display_goal_usd(&ctx.oracle, &project.id, &2_500_000_000);
+}
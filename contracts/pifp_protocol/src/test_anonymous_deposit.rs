@@ -0,0 +1,119 @@
+extern crate std;
+
+use soroban_sdk::{vec, Bytes, BytesN};
+
+use crate::test_utils::TestContext;
+
+fn commit(ctx: &TestContext, secret: &[u8]) -> (Bytes, BytesN<32>) {
+    let preimage = Bytes::from_slice(&ctx.env, secret);
+    let commitment: BytesN<32> = ctx.env.crypto().sha256(&preimage).into();
+    (preimage, commitment)
+}
+
+#[test]
+fn test_anonymous_deposit_and_reveal_refund() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let payer = ctx.generate_address();
+    sac.mint(&payer, &400);
+    let (preimage, commitment) = commit(&ctx, b"donor-secret-1");
+    ctx.client
+        .deposit_anonymous(&payer, &project.id, &token.address, &400, &commitment);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 400);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 1);
+
+    ctx.jump_time(86_401);
+    let recipient = ctx.generate_address();
+    ctx.client
+        .refund_anonymous(&recipient, &project.id, &token.address, &preimage);
+    assert_eq!(token.balance(&recipient), 400);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_anonymous_wrong_preimage() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let payer = ctx.generate_address();
+    sac.mint(&payer, &400);
+    let (_, commitment) = commit(&ctx, b"donor-secret-1");
+    ctx.client
+        .deposit_anonymous(&payer, &project.id, &token.address, &400, &commitment);
+
+    ctx.jump_time(86_401);
+    let (wrong, _) = commit(&ctx, b"guess");
+    ctx.client
+        .refund_anonymous(&payer, &project.id, &token.address, &wrong);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_anonymous_cannot_be_replayed() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let payer = ctx.generate_address();
+    sac.mint(&payer, &400);
+    let (preimage, commitment) = commit(&ctx, b"donor-secret-1");
+    ctx.client
+        .deposit_anonymous(&payer, &project.id, &token.address, &400, &commitment);
+
+    ctx.jump_time(86_401);
+    let recipient = ctx.generate_address();
+    ctx.client
+        .refund_anonymous(&recipient, &project.id, &token.address, &preimage);
+    ctx.client
+        .refund_anonymous(&recipient, &project.id, &token.address, &preimage);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_payer_cannot_refund_anonymous_deposit_directly() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let payer = ctx.generate_address();
+    sac.mint(&payer, &400);
+    let (_, commitment) = commit(&ctx, b"donor-secret-1");
+    ctx.client
+        .deposit_anonymous(&payer, &project.id, &token.address, &400, &commitment);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&payer, &project.id, &token.address);
+}
+
+#[test]
+fn test_anonymous_deposit_pays_fee_and_is_logged() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_fee_tiers(&ctx.admin, &vec![&ctx.env, (0i128, 300u32)]);
+
+    let payer = ctx.generate_address();
+    sac.mint(&payer, &400);
+    let (preimage, commitment) = commit(&ctx, b"donor-secret-1");
+    ctx.client
+        .deposit_anonymous(&payer, &project.id, &token.address, &400, &commitment);
+
+    // 3% of 400 goes to the treasury, as for a named deposit.
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 12);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 388);
+    let log = ctx.client.get_donations(&project.id, &0, &10);
+    let entry = log.get(0).unwrap();
+    assert_eq!((entry.donor, entry.amount), (None, 388));
+
+    ctx.jump_time(86_401);
+    let recipient = ctx.generate_address();
+    ctx.client
+        .refund_anonymous(&recipient, &project.id, &token.address, &preimage);
+    assert_eq!(token.balance(&recipient), 388);
+}
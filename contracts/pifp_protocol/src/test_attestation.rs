@@ -0,0 +1,66 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_attestation_reflects_donor_balance() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &700);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+
+    let attestation = ctx
+        .client
+        .attest_contribution(&project.id, &donator, &token.address);
+    assert_eq!(attestation.project_id, project.id);
+    assert_eq!(attestation.donator, donator);
+    assert_eq!(attestation.token, token.address);
+    assert_eq!(attestation.amount, 700);
+    assert_eq!(attestation.ledger, ctx.env.ledger().sequence());
+
+    // Same fields, same hash; a different donor gets a different one.
+    let again = ctx
+        .client
+        .attest_contribution(&project.id, &donator, &token.address);
+    assert_eq!(again.hash, attestation.hash);
+    let other =
+        ctx.client
+            .attest_contribution(&project.id, &ctx.generate_address(), &token.address);
+    assert_eq!(other.amount, 0);
+    assert_ne!(other.hash, attestation.hash);
+}
+
+#[test]
+fn test_attestation_after_refund_is_zero() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    let attestation = ctx
+        .client
+        .attest_contribution(&project.id, &donator, &token.address);
+    assert_eq!(attestation.amount, 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_attestation_unknown_project_fails() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.client
+        .attest_contribution(&99, &ctx.generate_address(), &token.address);
+}
@@ -0,0 +1,113 @@
+extern crate std;
+
+use soroban_sdk::symbol_short;
+
+use crate::{events, test_utils::TestContext, AuditSubject, Role, AUDIT_LOG_SIZE};
+
+#[test]
+fn test_grants_appear_in_audit_log() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    // TestContext already granted the oracle and manager roles.
+    let before = ctx.client.get_audit_count();
+    assert_eq!(before, 2);
+
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+    ctx.client.revoke_role(&ctx.admin, &auditor);
+
+    let log = ctx.client.get_audit_log(&before, &10);
+    assert_eq!(log.len(), 2);
+    let granted = log.get(0).unwrap();
+    assert_eq!(granted.seq, before);
+    assert_eq!(granted.actor, ctx.admin);
+    assert_eq!(granted.action, events::ROLE_SET);
+    assert_eq!(granted.subject, AuditSubject::Account(auditor.clone()));
+    assert_eq!(granted.timestamp, ctx.env.ledger().timestamp());
+    assert_eq!(log.get(1).unwrap().action, events::ROLE_DEL);
+    assert_eq!(log.get(1).unwrap().subject, AuditSubject::Account(auditor));
+}
+
+#[test]
+fn test_revoking_no_role_is_not_audited() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let before = ctx.client.get_audit_count();
+
+    ctx.client.revoke_role(&ctx.admin, &ctx.generate_address());
+    assert_eq!(ctx.client.get_audit_count(), before);
+}
+
+#[test]
+fn test_super_admin_transfer_has_own_audit_action() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let before = ctx.client.get_audit_count();
+
+    let successor = ctx.generate_address();
+    ctx.client.transfer_super_admin(&ctx.admin, &successor);
+    let record = ctx.client.get_audit_log(&before, &1).get(0).unwrap();
+    assert_eq!(record.action, events::SUPER_ADMIN_TRANSFERRED);
+    assert_eq!(record.action, symbol_short!("su_xfer"));
+    assert_eq!(record.actor, ctx.admin);
+}
+
+#[test]
+fn test_release_appears_in_audit_log() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    let count = ctx.client.get_audit_count();
+    let released = ctx.client.get_audit_log(&(count - 1), &1).get(0).unwrap();
+    assert_eq!(released.action, events::FUNDS_CLAIMED);
+    // Anyone may trigger the release, so the contract is the actor.
+    assert_eq!(released.actor, ctx.client.address);
+    assert_eq!(released.subject, AuditSubject::Project(project.id));
+}
+
+#[test]
+fn test_pauses_appear_in_audit_log() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let before = ctx.client.get_audit_count();
+
+    ctx.client.pause(&ctx.admin);
+    ctx.client.unpause(&ctx.admin);
+    let log = ctx.client.get_audit_log(&before, &10);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get(0).unwrap().action, events::PROTOCOL_PAUSED);
+    assert_eq!(log.get(1).unwrap().action, events::PROTOCOL_UNPAUSED);
+}
+
+#[test]
+fn test_audit_log_keeps_only_latest_records() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    while ctx.client.get_audit_count() < AUDIT_LOG_SIZE + 10 {
+        ctx.client.pause(&ctx.admin);
+        ctx.client.unpause(&ctx.admin);
+    }
+    let count = ctx.client.get_audit_count();
+    let oldest = count - AUDIT_LOG_SIZE;
+
+    // A start before the retained window begins at the oldest record.
+    let page = ctx.client.get_audit_log(&0, &100);
+    assert_eq!(page.len(), 50);
+    assert_eq!(page.get(0).unwrap().seq, oldest);
+    assert_eq!(page.get(49).unwrap().seq, oldest + 49);
+
+    let tail = ctx.client.get_audit_log(&(count - 5), &50);
+    assert_eq!(tail.len(), 5);
+    assert_eq!(tail.get(4).unwrap().seq, count - 1);
+    assert!(ctx.client.get_audit_log(&count, &10).is_empty());
+}
@@ -0,0 +1,125 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::Events,
+    vec,
+    xdr::{ContractEventBody, ScVal},
+    Symbol, TryFromVal,
+};
+
+use crate::{test_utils::TestContext, DEFAULT_CATEGORY};
+
+/// Topics of the last event published by the contract under `name`.
+fn topics_of(ctx: &TestContext, name: Symbol) -> std::vec::Vec<ScVal> {
+    let name = ScVal::try_from_val(&ctx.env, &name).unwrap();
+    let events = ctx
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&ctx.client.address);
+    let event = events
+        .events()
+        .iter()
+        .rev()
+        .find(|e| {
+            let ContractEventBody::V0(body) = &e.body;
+            body.topics[0] == name
+        })
+        .expect("event not published")
+        .clone();
+    let ContractEventBody::V0(body) = event.body;
+    body.topics.to_vec()
+}
+
+#[test]
+fn test_project_created_topic_carries_default_category() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(project.category, DEFAULT_CATEGORY);
+    let topics = topics_of(&ctx, symbol_short!("proj_cr"));
+    assert_eq!(
+        topics[2],
+        ScVal::try_from_val(&ctx.env, &DEFAULT_CATEGORY).unwrap()
+    );
+}
+
+#[test]
+fn test_set_category_tags_later_events() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let health = symbol_short!("health");
+
+    ctx.client.set_category(&ctx.manager, &project.id, &health);
+    assert_eq!(ctx.client.get_project(&project.id).category, health);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    let topics = topics_of(&ctx, symbol_short!("proj_fnd"));
+    assert_eq!(topics[2], ScVal::try_from_val(&ctx.env, &health).unwrap());
+
+    ctx.client.announce(&project.id);
+    let topics = topics_of(&ctx, symbol_short!("proj_cr"));
+    assert_eq!(topics[2], ScVal::try_from_val(&ctx.env, &health).unwrap());
+}
+
+#[test]
+fn test_allowed_category_accepted() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let allowed = vec![
+        &ctx.env,
+        symbol_short!("education"),
+        symbol_short!("health"),
+    ];
+
+    ctx.client.set_allowed_categories(&ctx.admin, &allowed);
+    assert_eq!(ctx.client.get_allowed_categories(), allowed);
+
+    ctx.client
+        .set_category(&ctx.manager, &project.id, &symbol_short!("education"));
+    assert_eq!(
+        ctx.client.get_project(&project.id).category,
+        symbol_short!("education")
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #54)")]
+fn test_category_outside_allow_list_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .set_allowed_categories(&ctx.admin, &vec![&ctx.env, symbol_short!("health")]);
+    ctx.client
+        .set_category(&ctx.manager, &project.id, &symbol_short!("arts"));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_category_requires_creator_or_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let stranger = ctx.generate_address();
+    ctx.client
+        .set_category(&stranger, &project.id, &symbol_short!("health"));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_allowed_categories_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .set_allowed_categories(&ctx.manager, &vec![&ctx.env, symbol_short!("health")]);
+}
@@ -0,0 +1,124 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::Events,
+    token,
+    xdr::{ContractEventBody, ScVal},
+    Address, Symbol, TryFromVal,
+};
+
+use crate::{events, test_utils::TestContext, ProjectStatus, Role};
+
+/// Whether the last invocation published an event with first topic `topic`.
+fn published(ctx: &TestContext, topic: Symbol) -> bool {
+    let topic = ScVal::try_from_val(&ctx.env, &topic).unwrap();
+    ctx.env
+        .events()
+        .all()
+        .filter_by_contract(&ctx.client.address)
+        .events()
+        .iter()
+        .any(|e| {
+            let ContractEventBody::V0(body) = &e.body;
+            body.topics.first() == Some(&topic)
+        })
+}
+
+/// Fund a project to its goal, verify it and return it with an auditor.
+fn verified_project(
+    ctx: &TestContext,
+) -> (
+    u64,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+    Address,
+) {
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    (project.id, token, sac, auditor)
+}
+
+#[test]
+fn test_unchallenged_verification_finalizes() {
+    let ctx = TestContext::new();
+    let (project_id, token, _, _) = verified_project(&ctx);
+    assert_eq!(ctx.client.get_challenge_period(), 86_400);
+
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project_id);
+    assert_eq!(
+        ctx.client.get_project(&project_id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+fn test_challenge_reverts_for_reverification() {
+    let ctx = TestContext::new();
+    let (project_id, token, _, auditor) = verified_project(&ctx);
+
+    ctx.jump_time(3_600);
+    ctx.client.challenge_verification(&auditor, &project_id);
+    assert!(published(&ctx, events::VERIFICATION_CHALLENGED));
+    let project = ctx.client.get_project(&project_id);
+    assert_eq!(project.status, ProjectStatus::Active);
+    assert_eq!(project.last_proof_time, 0);
+    assert_eq!(ctx.client.get_balance(&project_id, &token.address), 1000);
+
+    // A fresh verification starts a new challenge period.
+    ctx.client
+        .verify_proof(&ctx.oracle, &project_id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project_id);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_challenged_project_cannot_be_claimed() {
+    let ctx = TestContext::new();
+    let (project_id, _, _, auditor) = verified_project(&ctx);
+    ctx.client.challenge_verification(&auditor, &project_id);
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #72)")]
+fn test_challenge_after_period_rejected() {
+    let ctx = TestContext::new();
+    let (project_id, _, _, auditor) = verified_project(&ctx);
+    ctx.jump_time(86_400);
+    ctx.client.challenge_verification(&auditor, &project_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_challenge_requires_auditor() {
+    let ctx = TestContext::new();
+    let (project_id, _, _, _) = verified_project(&ctx);
+    ctx.client.challenge_verification(&ctx.manager, &project_id);
+}
+
+#[test]
+fn test_challenge_period_is_configurable() {
+    let ctx = TestContext::new();
+    let (project_id, token, _, _) = verified_project(&ctx);
+    ctx.client.set_challenge_period(&ctx.admin, &3_600);
+    assert!(published(&ctx, events::CHALLENGE_PERIOD_UPDATED));
+    assert_eq!(ctx.client.get_challenge_period(), 3_600);
+
+    ctx.jump_time(3_600);
+    ctx.client.claim_funds(&project_id);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
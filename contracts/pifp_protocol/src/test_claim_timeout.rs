@@ -0,0 +1,86 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+const TIMEOUT: u64 = 7 * 24 * 60 * 60;
+
+#[test]
+fn test_claimed_in_time_cannot_be_reclaimed() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_claim_timeout(&ctx.admin, &TIMEOUT);
+    assert_eq!(ctx.client.get_claim_timeout(), TIMEOUT);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    // Claim just before the timeout runs out.
+    ctx.jump_time(86_400 + TIMEOUT - 1);
+    ctx.client.claim_funds(&project.id);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+
+    ctx.jump_time(1);
+    let result = ctx.client.try_reclaim_unclaimed(&project.id);
+    assert!(result.is_err());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_timed_out_claim_opens_refunds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_claim_timeout(&ctx.admin, &TIMEOUT);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(86_400 + TIMEOUT);
+    ctx.client.reclaim_unclaimed(&project.id);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Cancelled
+    );
+
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #67)")]
+fn test_reclaim_before_timeout_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_claim_timeout(&ctx.admin, &TIMEOUT);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(86_400 + TIMEOUT - 1);
+    ctx.client.reclaim_unclaimed(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #67)")]
+fn test_reclaim_disabled_without_timeout() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(365 * 86_400);
+    ctx.client.reclaim_unclaimed(&project.id);
+}
@@ -0,0 +1,156 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::Events,
+    xdr::{ContractEventBody, ScVal},
+    BytesN, IntoVal, TryFromVal, Val, Vec,
+};
+
+use crate::{
+    events::{self, ProjectCreated},
+    test_utils::TestContext,
+    OvershootPolicy, ProjectStatus,
+};
+
+#[test]
+fn test_clone_copies_config_with_independent_balances() {
+    let ctx = TestContext::new();
+    let (template, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_overshoot_policy(&ctx.manager, &template.id, &OvershootPolicy::Reject);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client.deposit(&template.id, &donator, &token.address, &600);
+
+    let new_deadline = ctx.env.ledger().timestamp() + 172_800;
+    let new_proof = BytesN::from_array(&ctx.env, &[0xcdu8; 32]);
+    let clone = ctx
+        .client
+        .clone_project(&ctx.manager, &template.id, &new_deadline, &new_proof);
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    assert_eq!(events.events().len(), 1);
+
+    assert_ne!(clone.id, template.id);
+    assert_eq!(clone.accepted_tokens, template.accepted_tokens);
+    assert_eq!(clone.goal, template.goal);
+    assert_eq!(clone.metadata_uri, template.metadata_uri);
+    assert_eq!(clone.milestones, template.milestones);
+    assert_eq!(clone.overshoot_policy, OvershootPolicy::Reject);
+    assert_eq!(clone.deadline, new_deadline);
+    assert_eq!(clone.proof_hash, new_proof);
+    assert_eq!(clone.status, ProjectStatus::Funding);
+    assert_eq!(clone.donation_count, 0);
+    assert_eq!(ctx.client.get_balance(&clone.id, &token.address), 0);
+
+    ctx.client.deposit(&clone.id, &donator, &token.address, &400);
+    assert_eq!(ctx.client.get_balance(&clone.id, &token.address), 400);
+    assert_eq!(ctx.client.get_balance(&template.id, &token.address), 600);
+}
+
+#[test]
+fn test_clone_copies_settings_stored_outside_project() {
+    let ctx = TestContext::new();
+    let (primary, _) = ctx.create_token();
+    let (secondary, _) = ctx.create_token();
+    let tokens = Vec::from_array(
+        &ctx.env,
+        [primary.address.clone(), secondary.address.clone()],
+    );
+    let template = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+    ctx.client.set_min_deposit(&ctx.manager, &template.id, &10);
+    ctx.client
+        .set_max_per_donor(&ctx.manager, &template.id, &500);
+    ctx.client
+        .set_deposit_increment(&ctx.manager, &template.id, &primary.address, &5);
+    ctx.client
+        .set_token_goal(&ctx.manager, &template.id, &secondary.address, &300);
+    ctx.client
+        .set_vesting_duration(&ctx.manager, &template.id, &86_400);
+
+    let new_deadline = ctx.env.ledger().timestamp() + 172_800;
+    let new_proof = BytesN::from_array(&ctx.env, &[0xcdu8; 32]);
+    let clone = ctx
+        .client
+        .clone_project(&ctx.manager, &template.id, &new_deadline, &new_proof);
+
+    assert_eq!(ctx.client.get_min_deposit(&clone.id), 10);
+    assert_eq!(ctx.client.get_max_per_donor(&clone.id), 500);
+    assert_eq!(
+        ctx.client
+            .get_deposit_increment(&clone.id, &primary.address),
+        5
+    );
+    assert_eq!(
+        ctx.client.get_token_goal(&clone.id, &secondary.address),
+        300
+    );
+    assert_eq!(ctx.client.get_vesting_duration(&clone.id), 86_400);
+}
+
+#[test]
+fn test_clone_created_event_carries_copied_fields() {
+    let ctx = TestContext::new();
+    let (template, token, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let health = symbol_short!("health");
+    ctx.client.set_category(&ctx.manager, &template.id, &health);
+    ctx.client
+        .set_display_goal_usd(&ctx.manager, &template.id, &2_500_000_000);
+
+    let deadline = ctx.env.ledger().timestamp() + 86_400;
+    let clone = ctx
+        .client
+        .clone_project(&ctx.manager, &template.id, &deadline, &ctx.dummy_proof());
+
+    let events = ctx
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&ctx.client.address);
+    assert_eq!(events.events().len(), 1);
+    let ContractEventBody::V0(body) = events.events()[0].body.clone();
+    let topic = |val: Val| ScVal::try_from_val(&ctx.env, &val).unwrap();
+    assert_eq!(
+        body.topics[0],
+        topic(events::PROJECT_CREATED.into_val(&ctx.env))
+    );
+    assert_eq!(body.topics[2], topic(health.into_val(&ctx.env)));
+    let data: Val = ProjectCreated {
+        project_id: clone.id,
+        creator: ctx.manager.clone(),
+        token: token.address.clone(),
+        goal: 1000,
+        hash_algo: template.hash_algo.clone(),
+        display_goal_usd: 2_500_000_000,
+    }
+    .into_val(&ctx.env);
+    assert_eq!(body.data, ScVal::try_from_val(&ctx.env, &data).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_clone_requires_register_role() {
+    let ctx = TestContext::new();
+    let (template, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let stranger = ctx.generate_address();
+    let deadline = ctx.env.ledger().timestamp() + 86_400;
+    ctx.client
+        .clone_project(&stranger, &template.id, &deadline, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_clone_unknown_template_fails() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+
+    let deadline = ctx.env.ledger().timestamp() + 86_400;
+    ctx.client
+        .clone_project(&ctx.manager, &99, &deadline, &ctx.dummy_proof());
+}
@@ -0,0 +1,107 @@
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Vec};
+
+use crate::{test_utils::TestContext, types::Milestone, Project, ProjectStatus, Role};
+
+const GAP: u64 = 600;
+
+/// Register a 2-of-3 project whose oracles all hold the Oracle role.
+fn register_two_of_three(ctx: &TestContext) -> (Project, Vec<Address>) {
+    ctx.env.mock_all_auths();
+    let oracles = Vec::from_array(
+        &ctx.env,
+        [
+            ctx.generate_address(),
+            ctx.generate_address(),
+            ctx.generate_address(),
+        ],
+    );
+    for oracle in oracles.iter() {
+        ctx.client.grant_role(&ctx.admin, &oracle, &Role::Oracle);
+    }
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [Milestone {
+            label: BytesN::from_array(&ctx.env, &[0u8; 32]),
+            amount_bps: 10000,
+            proof_hash: ctx.dummy_proof(),
+        }],
+    );
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &tokens,
+        &1000i128,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        &oracles,
+        &2u32,
+    );
+    (project, oracles)
+}
+
+#[test]
+fn test_confirmation_gap_defaults_to_zero() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_confirmation_gap(), 0);
+
+    // With no gap, back-to-back confirmations verify in one ledger.
+    let (project, oracles) = register_two_of_three(&ctx);
+    ctx.client
+        .verify_proof(&oracles.get(0).unwrap(), &project.id, &ctx.dummy_proof());
+    ctx.client
+        .verify_proof(&oracles.get(1).unwrap(), &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #75)")]
+fn test_back_to_back_confirmations_rejected() {
+    let ctx = TestContext::new();
+    let (project, oracles) = register_two_of_three(&ctx);
+    ctx.client.set_confirmation_gap(&ctx.admin, &GAP);
+
+    ctx.client
+        .verify_proof(&oracles.get(0).unwrap(), &project.id, &ctx.dummy_proof());
+    ctx.jump_time(GAP - 1);
+    ctx.client
+        .verify_proof(&oracles.get(1).unwrap(), &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_spaced_confirmations_verify() {
+    let ctx = TestContext::new();
+    let (project, oracles) = register_two_of_three(&ctx);
+    ctx.client.set_confirmation_gap(&ctx.admin, &GAP);
+    assert_eq!(ctx.client.get_confirmation_gap(), GAP);
+
+    ctx.client
+        .verify_proof(&oracles.get(0).unwrap(), &project.id, &ctx.dummy_proof());
+    // A repeat vote from the same oracle is not a new confirmation.
+    ctx.client
+        .verify_proof(&oracles.get(0).unwrap(), &project.id, &ctx.dummy_proof());
+    ctx.jump_time(GAP);
+    ctx.client
+        .verify_proof(&oracles.get(1).unwrap(), &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_confirmation_gap_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_confirmation_gap(&ctx.manager, &GAP);
+}
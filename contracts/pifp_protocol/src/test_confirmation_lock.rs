@@ -0,0 +1,109 @@
+extern crate std;
+
+use soroban_sdk::{token, Address, BytesN, Vec};
+
+use crate::{test_utils::TestContext, types::Milestone, Project, ProjectStatus, Role};
+
+/// Register a 2-of-2 project and fund `donator` with 1000 of its token.
+fn setup_two_of_two(
+    ctx: &TestContext,
+    lock: bool,
+) -> (Project, Address, token::Client<'static>, Address) {
+    ctx.env.mock_all_auths();
+    let o1 = ctx.generate_address();
+    let o2 = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &o1, &Role::Oracle);
+    ctx.client.grant_role(&ctx.admin, &o2, &Role::Oracle);
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [Milestone {
+            label: BytesN::from_array(&ctx.env, &[0u8; 32]),
+            amount_bps: 10000,
+            proof_hash: ctx.dummy_proof(),
+        }],
+    );
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &tokens,
+        &1000i128,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        &Vec::from_array(&ctx.env, [o1.clone(), o2]),
+        &2u32,
+    );
+    ctx.client
+        .set_lock_on_first_confirmation(&ctx.manager, &project.id, &lock);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    (project, o1, token, donator)
+}
+
+#[test]
+fn test_unlocked_project_accepts_deposits_after_confirmation() {
+    let ctx = TestContext::new();
+    let (project, o1, token, donator) = setup_two_of_two(&ctx, false);
+    assert!(
+        !ctx.client
+            .get_project(&project.id)
+            .lock_on_first_confirmation
+    );
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    ctx.client
+        .verify_proof(&o1, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+}
+
+#[test]
+fn test_locked_project_accepts_deposits_before_confirmation() {
+    let ctx = TestContext::new();
+    let (project, _, token, donator) = setup_two_of_two(&ctx, true);
+    assert!(
+        ctx.client
+            .get_project(&project.id)
+            .lock_on_first_confirmation
+    );
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #68)")]
+fn test_locked_project_rejects_deposits_after_confirmation() {
+    let ctx = TestContext::new();
+    let (project, o1, token, donator) = setup_two_of_two(&ctx, true);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    ctx.client
+        .verify_proof(&o1, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_lock_requires_creator_or_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let stranger = ctx.generate_address();
+    ctx.client
+        .set_lock_on_first_confirmation(&stranger, &project.id, &true);
+}
@@ -0,0 +1,78 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::Events,
+    xdr::{ContractEventBody, ScVal},
+    IntoVal, TryFromVal, Val,
+};
+
+use crate::{events::GoalCorrected, test_utils::TestContext};
+
+#[test]
+fn test_correct_goal_before_deposits() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let reason = symbol_short!("typo");
+    ctx.client
+        .correct_goal(&ctx.admin, &project.id, &10_000, &reason);
+
+    let expected: Val = GoalCorrected {
+        project_id: project.id,
+        old: 1000,
+        new: 10_000,
+        reason,
+    }
+    .into_val(&ctx.env);
+    let expected = ScVal::try_from_val(&ctx.env, &expected).unwrap();
+    let emitted = ctx
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&ctx.client.address)
+        .events()
+        .iter()
+        .any(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            body.data == expected
+        });
+    assert!(emitted);
+    assert_eq!(ctx.client.get_project(&project.id).goal, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #66)")]
+fn test_correct_goal_rejected_after_deposit() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    ctx.client
+        .correct_goal(&ctx.admin, &project.id, &500, &symbol_short!("typo"));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_correct_goal_rejects_creator() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .correct_goal(&ctx.manager, &project.id, &500, &symbol_short!("typo"));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_correct_goal_rejects_zero_goal() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .correct_goal(&ctx.admin, &project.id, &0, &symbol_short!("typo"));
+}
@@ -0,0 +1,96 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+/// Fund a project to its goal and strip its creator of every role.
+fn funded_with_revoked_creator(ctx: &TestContext) -> (u64, soroban_sdk::token::Client<'static>) {
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client.revoke_role(&ctx.admin, &ctx.manager);
+    (project.id, token)
+}
+
+#[test]
+fn test_creator_role_check_defaults_off() {
+    let ctx = TestContext::new();
+    assert!(!ctx.client.get_require_creator_role());
+}
+
+#[test]
+fn test_release_ignores_revoked_role_when_flag_off() {
+    let ctx = TestContext::new();
+    let (project_id, token) = funded_with_revoked_creator(&ctx);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project_id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project_id);
+    assert_eq!(
+        ctx.client.get_project(&project_id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #73)")]
+fn test_release_rejects_revoked_creator_when_flag_on() {
+    let ctx = TestContext::new();
+    let (project_id, _) = funded_with_revoked_creator(&ctx);
+    ctx.client.set_require_creator_role(&ctx.admin, &true);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project_id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #73)")]
+fn test_claim_rejects_creator_revoked_after_verification() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_require_creator_role(&ctx.admin, &true);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.revoke_role(&ctx.admin, &ctx.manager);
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+}
+
+#[test]
+fn test_release_allowed_while_creator_keeps_role() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_require_creator_role(&ctx.admin, &true);
+    assert!(ctx.client.get_require_creator_role());
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_creator_role_check_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_require_creator_role(&ctx.manager, &true);
+}
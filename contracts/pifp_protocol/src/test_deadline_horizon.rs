@@ -0,0 +1,48 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::test_utils::TestContext;
+
+// `TestContext::register_project` sets the deadline one day out.
+const ONE_DAY: u64 = 86_400;
+
+fn set_horizon(ctx: &TestContext, horizon: u64) {
+    ctx.mock_auth(&ctx.admin, "set_max_deadline_horizon", (&ctx.admin, horizon));
+    ctx.client.set_max_deadline_horizon(&ctx.admin, &horizon);
+}
+
+#[test]
+fn test_no_horizon_by_default() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_max_deadline_horizon(), None);
+}
+
+#[test]
+fn test_deadline_at_horizon_allowed() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+    set_horizon(&ctx, ONE_DAY);
+
+    assert_eq!(ctx.client.get_max_deadline_horizon(), Some(ONE_DAY));
+    let project = ctx.register_project(&tokens, 1000, false);
+    assert_eq!(project.deadline, ctx.env.ledger().timestamp() + ONE_DAY);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #43)")]
+fn test_deadline_beyond_horizon_rejected() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+    set_horizon(&ctx, ONE_DAY - 1);
+
+    ctx.register_project(&tokens, 1000, false);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_horizon_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.mock_auth(&ctx.manager, "set_max_deadline_horizon", (&ctx.manager, ONE_DAY));
+    ctx.client.set_max_deadline_horizon(&ctx.manager, &ONE_DAY);
+}
@@ -0,0 +1,83 @@
+extern crate std;
+
+use soroban_sdk::String;
+
+use crate::{test_utils::TestContext, Role, REFUND_WINDOW};
+
+/// Revoke the roles `TestContext` grants besides SuperAdmin.
+fn revoke_test_roles(ctx: &TestContext) {
+    ctx.client.revoke_role(&ctx.admin, &ctx.oracle);
+    ctx.client.revoke_role(&ctx.admin, &ctx.manager);
+}
+
+#[test]
+fn test_decommission_clean_contract_allows_reinit() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_refund_window(&ctx.admin, &3600);
+    ctx.client.pause(&ctx.admin);
+    let default_label = ctx.client.get_role_label(&Role::Auditor);
+    ctx.client.set_role_label(
+        &ctx.admin,
+        &Role::Auditor,
+        &String::from_str(&ctx.env, "Reviewer"),
+    );
+    revoke_test_roles(&ctx);
+
+    ctx.client.decommission(&ctx.admin);
+    let health = ctx.client.health();
+    assert!(!health.initialized);
+    assert!(!health.paused);
+    assert_eq!(ctx.client.get_refund_window(), REFUND_WINDOW);
+    assert_eq!(ctx.client.role_of(&ctx.admin), None);
+    assert_eq!(ctx.client.get_role_label(&Role::Auditor), default_label);
+
+    let new_admin = ctx.generate_address();
+    ctx.client.init(&new_admin);
+    assert!(ctx.client.health().initialized);
+    assert_eq!(ctx.client.get_version(), crate::CONTRACT_VERSION);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #69)")]
+fn test_decommission_rejected_with_projects() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    revoke_test_roles(&ctx);
+
+    ctx.client.decommission(&ctx.admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #69)")]
+fn test_decommission_rejected_while_roles_held() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.decommission(&ctx.admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #69)")]
+fn test_decommission_rejected_with_second_super_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    revoke_test_roles(&ctx);
+    let second = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &second, &Role::SuperAdmin);
+
+    ctx.client.decommission(&ctx.admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_decommission_requires_super_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.decommission(&ctx.manager);
+}
@@ -0,0 +1,45 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_deposit_accepts_auth_scoped_to_args() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    ctx.env.mock_all_auths();
+    sac.mint(&donator, &500);
+
+    ctx.mock_deposit_auth(&donator, project.id, &token.address, 500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_deposit_rejects_auth_for_different_amount() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    ctx.env.mock_all_auths();
+    sac.mint(&donator, &900);
+
+    ctx.mock_deposit_auth(&donator, project.id, &token.address, 100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &900);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_deposit_rejects_auth_for_different_token() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, _) = ctx.create_token();
+    let tokens = soroban_sdk::vec![&ctx.env, token_a.address.clone(), token_b.address.clone()];
+    let project = ctx.register_project(&tokens, 1000, false);
+    let donator = ctx.generate_address();
+    ctx.env.mock_all_auths();
+    sac_a.mint(&donator, &500);
+
+    ctx.mock_deposit_auth(&donator, project.id, &token_b.address, 500);
+    ctx.client.deposit(&project.id, &donator, &token_a.address, &500);
+}
@@ -0,0 +1,190 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_default_max_deposit_is_unbounded() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_max_deposit_per_tx(), i128::MAX);
+}
+
+#[test]
+fn test_deposit_at_max_allowed() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_deposit_per_tx(&ctx.admin, &500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #45)")]
+fn test_deposit_above_max_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_deposit_per_tx(&ctx.admin, &500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &501);
+    ctx.client.deposit(&project.id, &donator, &token.address, &501);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_max_deposit_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_deposit_per_tx(&ctx.manager, &500);
+}
+
+#[test]
+fn test_deposit_increment_accepts_aligned_amounts() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+
+    assert_eq!(ctx.client.get_deposit_increment(&project.id, &token.address), 0);
+    ctx.client
+        .set_deposit_increment(&project.creator, &project.id, &token.address, &100);
+    assert_eq!(
+        ctx.client.get_deposit_increment(&project.id, &token.address),
+        100
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &200);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 300);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #48)")]
+fn test_deposit_increment_rejects_misaligned_amount() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_deposit_increment(&project.creator, &project.id, &token.address, &100);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &150);
+    ctx.client.deposit(&project.id, &donator, &token.address, &150);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_deposit_increment_creator_only() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_deposit_increment(&ctx.admin, &project.id, &token.address, &100);
+}
+
+#[test]
+fn test_max_per_donor_allows_deposits_up_to_cap() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+
+    assert_eq!(ctx.client.get_max_per_donor(&project.id), 0);
+    ctx.client.set_max_per_donor(&project.creator, &project.id, &500);
+    assert_eq!(ctx.client.get_max_per_donor(&project.id), 500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &200);
+    ctx.client.deposit(&project.id, &donator, &token.address, &300);
+    assert_eq!(token.balance(&donator), 0);
+
+    let other = ctx.generate_address();
+    sac.mint(&other, &500);
+    ctx.client.deposit(&project.id, &other, &token.address, &500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #49)")]
+fn test_max_per_donor_rejects_cumulative_overshoot() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_per_donor(&project.creator, &project.id, &500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &501);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+    ctx.client.deposit(&project.id, &donator, &token.address, &101);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_max_per_donor_creator_only() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_per_donor(&ctx.admin, &project.id, &500);
+}
+
+#[test]
+fn test_global_min_deposit_defaults_to_zero() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(10_000);
+    assert_eq!(ctx.client.get_global_min_deposit(), 0);
+    assert_eq!(ctx.client.get_min_deposit(&project.id), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #57)")]
+fn test_deposit_below_global_min_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_global_min_deposit(&ctx.admin, &100);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &99);
+    ctx.client.deposit(&project.id, &donator, &token.address, &99);
+}
+
+#[test]
+fn test_effective_min_is_larger_of_global_and_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_global_min_deposit(&ctx.admin, &100);
+    ctx.client.set_min_deposit(&ctx.manager, &project.id, &250);
+    assert_eq!(ctx.client.get_min_deposit(&project.id), 250);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    assert!(ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &200)
+        .is_err());
+    ctx.client.deposit(&project.id, &donator, &token.address, &250);
+
+    // A lower project minimum does not undercut the global floor.
+    ctx.client.set_min_deposit(&ctx.manager, &project.id, &50);
+    assert_eq!(ctx.client.get_min_deposit(&project.id), 100);
+    assert!(ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &75)
+        .is_err());
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 350);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_global_min_deposit_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_global_min_deposit(&ctx.manager, &100);
+}
@@ -0,0 +1,89 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_deposit_deadline_defaults_to_deadline() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(project.deposit_deadline, project.deadline);
+}
+
+#[test]
+fn test_deposit_before_window_closes_succeeds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let window = ctx.env.ledger().timestamp() + 3_600;
+    ctx.client
+        .set_deposit_deadline(&ctx.manager, &project.id, &window);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.jump_time(3_600);
+    ctx.client.deposit(&project.id, &donator, &token.address, &500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #40)")]
+fn test_deposit_after_window_closes_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let window = ctx.env.ledger().timestamp() + 3_600;
+    ctx.client
+        .set_deposit_deadline(&ctx.manager, &project.id, &window);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.jump_time(3_601);
+    ctx.client.deposit(&project.id, &donator, &token.address, &500);
+}
+
+#[test]
+fn test_verification_allowed_after_window_closes() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let window = ctx.env.ledger().timestamp() + 3_600;
+    ctx.client
+        .set_deposit_deadline(&ctx.manager, &project.id, &window);
+    ctx.jump_time(7_200);
+
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_deposit_deadline_after_deadline_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .set_deposit_deadline(&ctx.manager, &project.id, &(project.deadline + 1));
+}
+
+#[test]
+fn test_extend_deadline_moves_default_window() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let new_deadline = project.deadline + 86_400;
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &new_deadline);
+    assert_eq!(
+        ctx.client.get_project(&project.id).deposit_deadline,
+        new_deadline
+    );
+}
@@ -149,3 +149,25 @@ fn test_donation_count_complex_scenario() {
     ctx.client.deposit(&project.id, &d2, &token2.address, &100i128);
     assert_eq!(ctx.client.get_project(&project.id).donation_count, 5);
 }
+
+#[test]
+fn test_donation_count_saturates_at_u32_max() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    let contract_id = ctx.client.address.clone();
+    ctx.env.as_contract(&contract_id, || {
+        let mut state = crate::storage::load_project_state(&ctx.env, project.id);
+        state.donation_count = u32::MAX - 2;
+        crate::storage::save_project_state(&ctx.env, project.id, &state);
+    });
+    ctx.env.mock_all_auths();
+
+    for _ in 0..5 {
+        let donator = ctx.generate_address();
+        sac.mint(&donator, &100i128);
+        ctx.client.deposit(&project.id, &donator, &token.address, &100i128);
+    }
+
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, u32::MAX);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+}
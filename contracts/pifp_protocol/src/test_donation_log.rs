@@ -0,0 +1,107 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_donation_log_records_each_deposit() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac.mint(&alice, &300);
+    sac.mint(&bob, &500);
+    ctx.client.deposit(&project.id, &alice, &token.address, &100);
+    ctx.jump_time(60);
+    ctx.client.deposit(&project.id, &bob, &token.address, &500);
+    ctx.client.deposit(&project.id, &alice, &token.address, &200);
+
+    assert_eq!(ctx.client.get_donation_entry_count(&project.id), 3);
+    let log = ctx.client.get_donations(&project.id, &0, &10);
+    assert_eq!(log.len(), 3);
+
+    let first = log.get(0).unwrap();
+    assert_eq!(first.donor, Some(alice.clone()));
+    assert_eq!(first.token, token.address);
+    assert_eq!(first.amount, 100);
+    assert_eq!(first.timestamp, 100_000);
+
+    let second = log.get(1).unwrap();
+    assert_eq!((second.donor, second.amount), (Some(bob), 500));
+    assert_eq!(second.timestamp, 100_060);
+
+    let third = log.get(2).unwrap();
+    assert_eq!((third.donor, third.amount), (Some(alice), 200));
+}
+
+#[test]
+fn test_get_donations_paginates() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    for amount in 1..=5i128 {
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &amount);
+    }
+
+    let page = ctx.client.get_donations(&project.id, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().amount, 1);
+    assert_eq!(page.get(1).unwrap().amount, 2);
+
+    let page = ctx.client.get_donations(&project.id, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().amount, 3);
+    assert_eq!(page.get(1).unwrap().amount, 4);
+
+    let page = ctx.client.get_donations(&project.id, &4, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().amount, 5);
+
+    assert_eq!(ctx.client.get_donations(&project.id, &5, &2).len(), 0);
+    assert_eq!(ctx.client.get_donations(&project.id, &0, &0).len(), 0);
+}
+
+#[test]
+fn test_get_donations_caps_page_size() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(100_000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    for _ in 0..55 {
+        ctx.client.deposit(&project.id, &donator, &token.address, &1);
+    }
+
+    assert_eq!(ctx.client.get_donations(&project.id, &0, &100).len(), 50);
+    assert_eq!(ctx.client.get_donations(&project.id, &50, &100).len(), 5);
+}
+
+#[test]
+fn test_count_deposits_between() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    // Deposits at t = 100_000, 100_000, 100_100, 100_300, 100_600.
+    for gap in [0, 0, 100, 200, 300] {
+        ctx.jump_time(gap);
+        ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    }
+
+    let count = |from: u64, to: u64| ctx.client.count_deposits_between(&project.id, &from, &to);
+    assert_eq!(count(0, u64::MAX), 5);
+    assert_eq!(count(100_000, 100_001), 2);
+    assert_eq!(count(100_000, 100_300), 3);
+    assert_eq!(count(100_100, 100_600), 2);
+    assert_eq!(count(100_301, 100_600), 0);
+    assert_eq!(count(100_600, 100_000), 0);
+    assert_eq!(count(200_000, 300_000), 0);
+}
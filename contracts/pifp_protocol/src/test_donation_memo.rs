@@ -0,0 +1,82 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::Events,
+    xdr::{ContractEventBody, ScVal},
+    IntoVal, String, TryFromVal, Val,
+};
+
+use crate::{events::DonationReceived, test_utils::TestContext};
+
+#[test]
+fn test_deposit_with_memo_emits_and_stores_memo() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    let memo = String::from_str(&ctx.env, "grant-42/Q3");
+    ctx.client
+        .deposit_with_memo(&project.id, &donator, &token.address, &300, &memo);
+
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    let last = events.events().last().unwrap().clone();
+    let ContractEventBody::V0(body) = last.body;
+    let expected = DonationReceived {
+        project_id: project.id,
+        donator: donator.clone(),
+        token: token.address.clone(),
+        amount: 300,
+        memo: memo.clone(),
+        seq: 0,
+    };
+    let expected_val: Val = expected.into_val(&ctx.env);
+    assert_eq!(body.data, ScVal::try_from_val(&ctx.env, &expected_val).unwrap());
+
+    assert_eq!(
+        ctx.client.get_donation_memo(&project.id, &donator, &0),
+        Some(memo)
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 300);
+}
+
+#[test]
+fn test_memo_sequence_increments_per_donation() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &200);
+    let first = String::from_str(&ctx.env, "first");
+    let second = String::from_str(&ctx.env, "second");
+    ctx.client
+        .deposit_with_memo(&project.id, &donator, &token.address, &100, &first);
+    ctx.client
+        .deposit_with_memo(&project.id, &donator, &token.address, &100, &second);
+
+    assert_eq!(
+        ctx.client.get_donation_memo(&project.id, &donator, &0),
+        Some(first)
+    );
+    assert_eq!(
+        ctx.client.get_donation_memo(&project.id, &donator, &1),
+        Some(second)
+    );
+    assert_eq!(ctx.client.get_donation_memo(&project.id, &donator, &2), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_memo_too_long_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    let memo = String::from_str(&ctx.env, &"x".repeat(65));
+    ctx.client
+        .deposit_with_memo(&project.id, &donator, &token.address, &100, &memo);
+}
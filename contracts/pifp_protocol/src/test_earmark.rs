@@ -0,0 +1,77 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, vec};
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_earmark_totals_accumulate_per_earmark() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = vec![&ctx.env, token_a.address.clone(), token_b.address.clone()];
+    let project = ctx.register_project(&tokens, 10_000, false);
+    ctx.env.mock_all_auths();
+
+    let wells = symbol_short!("wells");
+    let school = symbol_short!("school");
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac_a.mint(&alice, &500);
+    sac_b.mint(&bob, &700);
+
+    ctx.client
+        .deposit_earmarked(&project.id, &alice, &token_a.address, &200, &wells);
+    ctx.client
+        .deposit_earmarked(&project.id, &bob, &token_b.address, &300, &wells);
+    ctx.client
+        .deposit_earmarked(&project.id, &alice, &token_a.address, &300, &school);
+    ctx.client
+        .deposit(&project.id, &bob, &token_b.address, &400);
+
+    assert_eq!(ctx.client.get_earmark_total(&project.id, &wells), 500);
+    assert_eq!(ctx.client.get_earmark_total(&project.id, &school), 300);
+    assert_eq!(
+        ctx.client
+            .get_earmark_total(&project.id, &symbol_short!("roads")),
+        0
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token_a.address), 500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token_b.address), 700);
+}
+
+#[test]
+fn test_earmark_totals_are_per_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let other = ctx.register_project(&project.accepted_tokens, 1_000, false);
+    ctx.env.mock_all_auths();
+
+    let wells = symbol_short!("wells");
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client
+        .deposit_earmarked(&project.id, &donator, &token.address, &300, &wells);
+
+    assert_eq!(ctx.client.get_earmark_total(&project.id, &wells), 300);
+    assert_eq!(ctx.client.get_earmark_total(&other.id, &wells), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_earmarked_deposit_validated_like_deposit() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.jump_time(86_401);
+    ctx.client.deposit_earmarked(
+        &project.id,
+        &donator,
+        &token.address,
+        &300,
+        &symbol_short!("wells"),
+    );
+}
@@ -1,7 +1,7 @@
 extern crate std;
 
-use crate::test_utils::TestContext;
-use soroban_sdk::{BytesN, Vec};
+use crate::{test_utils::TestContext, Error};
+use soroban_sdk::{Address, BytesN, Vec};
 
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #1)")]
@@ -75,6 +75,16 @@ fn test_register_too_many_tokens_fails() {
     ctx.register_project(&tokens, 1000, false);
 }
 
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_register_duplicate_tokens_fails() {
+    let ctx = TestContext::new();
+    let token = ctx.generate_address();
+    let other = ctx.generate_address();
+    let tokens = Vec::from_array(&ctx.env, [token.clone(), other, token]);
+    ctx.register_project(&tokens, 1000, false);
+}
+
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #13)")]
 fn test_register_deadline_too_far_in_future_fails() {
@@ -101,7 +111,7 @@ fn test_register_deadline_too_far_in_future_fails() {
         &false,
         &milestones,
         &0u32,
-        &Vec::new(&ctx.env),
+        &Vec::<Address>::new(&ctx.env),
         &0u32,
     ));
     ctx.client.register_project(
@@ -128,7 +138,7 @@ fn test_register_deadline_too_far_in_future_fails() {
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #16)")]
+#[should_panic(expected = "HostError: Error(Contract, #77)")]
 fn test_verify_wrong_proof_hash_fails() {
     let ctx = TestContext::new();
     let (project, _, _) = ctx.setup_project(1000);
@@ -138,6 +148,18 @@ fn test_verify_wrong_proof_hash_fails() {
         .verify_proof(&ctx.oracle, &project.id, &wrong_proof);
 }
 
+#[test]
+fn test_verify_wrong_proof_returns_proof_mismatch() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let wrong_proof = BytesN::from_array(&ctx.env, &[0xffu8; 32]);
+    let result = ctx
+        .client
+        .try_verify_proof(&ctx.oracle, &project.id, &wrong_proof);
+    assert_eq!(result, Err(Ok(Error::ProofMismatch.into())));
+}
+
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #17)")]
 fn test_register_empty_tokens_fails() {
@@ -1,8 +1,13 @@
 extern crate std;
 
-use soroban_sdk::{vec, BytesN, Vec};
+use soroban_sdk::{
+    testutils::Events,
+    vec,
+    xdr::{ContractEventBody, ScVal},
+    BytesN, String, Symbol, TryFromVal, Vec,
+};
 
-use crate::test_utils::TestContext;
+use crate::{events, test_utils::TestContext, Role};
 
 #[test]
 fn test_project_created_event() {
@@ -105,3 +110,157 @@ fn test_refunded_event() {
     ctx.mock_auth(&donator, "refund", (&donator, project.id, &token.address));
     ctx.client.refund(&donator, &project.id, &token.address);
 }
+
+#[test]
+fn test_funds_claimed_event_summarises_release() {
+    use soroban_sdk::{
+        testutils::Events,
+        xdr::{ContractEventBody, ScVal},
+        IntoVal, TryFromVal, Val,
+    };
+
+    use crate::{events::FundsClaimed, types::TokenBalance};
+
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, _) = ctx.create_token();
+    let tokens = vec![&ctx.env, token_a.address.clone(), token_b.address.clone()];
+    let project = ctx.register_project(&tokens, 1_000i128, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1_000i128);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &1_000i128);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    let last = events.events().last().unwrap().clone();
+    let ContractEventBody::V0(body) = last.body;
+    let expected = FundsClaimed {
+        project_id: project.id,
+        creator: ctx.manager.clone(),
+        tokens_released: 1,
+        released: vec![
+            &ctx.env,
+            TokenBalance {
+                token: token_a.address.clone(),
+                balance: 1_000i128,
+            },
+        ],
+    };
+    let expected_val: Val = expected.into_val(&ctx.env);
+    assert_eq!(body.data, ScVal::try_from_val(&ctx.env, &expected_val).unwrap());
+}
+
+/// First topic of every event the contract published in the last call.
+fn first_topics(ctx: &TestContext) -> std::vec::Vec<ScVal> {
+    ctx.env
+        .events()
+        .all()
+        .filter_by_contract(&ctx.client.address)
+        .events()
+        .iter()
+        .map(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            body.topics[0].clone()
+        })
+        .collect()
+}
+
+fn published(ctx: &TestContext, topic: Symbol) -> bool {
+    let topic = ScVal::try_from_val(&ctx.env, &topic).unwrap();
+    first_topics(ctx).contains(&topic)
+}
+
+#[test]
+fn test_deposit_topics_match_constants() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    assert!(published(&ctx, events::PROJECT_FUNDED));
+    assert!(published(&ctx, events::STATUS_CHANGED));
+    assert!(published(&ctx, events::PROJECT_ACTIVE));
+}
+
+#[test]
+fn test_verify_topics_match_constants() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert!(published(&ctx, events::PROJECT_VERIFIED));
+}
+
+#[test]
+fn test_token_registry_topics_match_constants() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let token = ctx.generate_address();
+    ctx.client.approve_token(&ctx.admin, &token);
+    assert!(published(&ctx, events::TOKEN_APPROVAL_SET));
+    ctx.client.unapprove_token(&ctx.admin, &token);
+    assert!(published(&ctx, events::TOKEN_APPROVAL_SET));
+}
+
+#[test]
+fn test_whitelist_topics_match_constants() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000, true);
+    ctx.env.mock_all_auths();
+    let donor = ctx.generate_address();
+    ctx.client
+        .add_to_whitelist(&ctx.manager, &project.id, &donor);
+    assert!(published(&ctx, events::WHITELIST_ADDED));
+    ctx.client
+        .remove_from_whitelist(&ctx.manager, &project.id, &donor);
+    assert!(published(&ctx, events::WHITELIST_REMOVED));
+}
+
+#[test]
+fn test_role_event_topics_match_constants() {
+    // Role events come from `#[contractevent]` structs rather than the
+    // constants; their topics must still equal the constants.
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+    assert!(published(&ctx, events::ROLE_SET));
+    ctx.client.revoke_role(&ctx.admin, &auditor);
+    assert!(published(&ctx, events::ROLE_DEL));
+    ctx.client.set_role_label(
+        &ctx.admin,
+        &Role::Auditor,
+        &String::from_str(&ctx.env, "Reviewer"),
+    );
+    assert!(published(&ctx, events::ROLE_LABEL_SET));
+}
+
+#[test]
+fn test_topic_constants_are_the_documented_strings() {
+    let ctx = TestContext::new();
+    for (topic, expected) in [
+        (events::PROJECT_CREATED, "proj_cr"),
+        (events::PROJECT_FUNDED, "proj_fnd"),
+        (events::PROJECT_VERIFIED, "proj_ver"),
+        (events::FUNDS_RELEASED, "fnd_rel"),
+        (events::WHITELIST_ADDED, "whl_add"),
+        (events::WHITELIST_REMOVED, "whl_rem"),
+        (events::TOKEN_APPROVAL_SET, "tok_appr"),
+        (events::ROLE_SET, "role_set"),
+        (events::ROLE_DEL, "role_del"),
+        (events::ROLE_LABEL_SET, "role_lbl"),
+    ] {
+        assert_eq!(topic, Symbol::new(&ctx.env, expected));
+    }
+}
@@ -82,3 +82,34 @@ fn test_expire_active_project_success() {
     let expired_project = ctx.client.get_project(&project.id);
     assert_eq!(expired_project.status, ProjectStatus::Expired);
 }
+
+#[test]
+fn test_batch_expire_skips_ineligible_projects() {
+    let ctx = TestContext::new();
+    let (due, _, _) = ctx.setup_project(1000);
+    let (already_expired, _, _) = ctx.setup_project(1000);
+    ctx.jump_time(86_401);
+    ctx.client.expire_project(&already_expired.id);
+    let (not_due, _, _) = ctx.setup_project(1000);
+
+    let ids = soroban_sdk::vec![&ctx.env, due.id, already_expired.id, not_due.id, 999];
+    assert_eq!(ctx.client.batch_expire(&ids), 1);
+
+    assert_eq!(ctx.client.get_project(&due.id).status, ProjectStatus::Expired);
+    assert_eq!(
+        ctx.client.get_project(&not_due.id).status,
+        ProjectStatus::Funding
+    );
+    assert_eq!(ctx.client.batch_expire(&ids), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #41)")]
+fn test_batch_expire_rejects_oversized_batch() {
+    let ctx = TestContext::new();
+    let mut ids = soroban_sdk::Vec::new(&ctx.env);
+    for id in 0..31u64 {
+        ids.push_back(id);
+    }
+    ctx.client.batch_expire(&ids);
+}
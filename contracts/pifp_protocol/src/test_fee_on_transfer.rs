@@ -0,0 +1,92 @@
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, Address, Env, MuxedAddress, Vec};
+
+use crate::test_utils::TestContext;
+
+/// Minimal token that burns 1% of every transfer.
+#[contract]
+pub struct FeeToken;
+
+#[contractimpl]
+impl FeeToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) {
+        from.require_auth();
+        let to = to.address();
+        let fee = amount / 100;
+        let from_balance = Self::balance(env.clone(), from.clone());
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&from, &(from_balance - amount));
+        env.storage()
+            .persistent()
+            .set(&to, &(to_balance + amount - fee));
+    }
+}
+
+fn setup(ctx: &TestContext) -> (Address, FeeTokenClient<'static>) {
+    let address = ctx.env.register(FeeToken, ());
+    let client = FeeTokenClient::new(&ctx.env, &address);
+    (address, client)
+}
+
+#[test]
+fn test_deposit_credits_amount_actually_received() {
+    let ctx = TestContext::new();
+    let (token, fee_token) = setup(&ctx);
+    let project = ctx.register_project(&Vec::from_array(&ctx.env, [token.clone()]), 10_000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    fee_token.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token, &1_000);
+
+    let received = fee_token.balance(&ctx.client.address);
+    assert_eq!(received, 990);
+    assert_eq!(ctx.client.get_balance(&project.id, &token), received);
+    assert_eq!(ctx.client.get_global_balance(&token), received);
+}
+
+#[test]
+fn test_refund_returns_credited_amount() {
+    let ctx = TestContext::new();
+    let (token, fee_token) = setup(&ctx);
+    let project = ctx.register_project(&Vec::from_array(&ctx.env, [token.clone()]), 10_000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    fee_token.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token, &1_000);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &project.id, &token);
+    // The contract pays back exactly what it credited; the token takes its
+    // cut on the way out as well.
+    assert_eq!(fee_token.balance(&ctx.client.address), 0);
+    assert_eq!(fee_token.balance(&donator), 981);
+    assert_eq!(ctx.client.get_balance(&project.id, &token), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #46)")]
+fn test_split_deposit_rejects_shortfall() {
+    let ctx = TestContext::new();
+    let (token, fee_token) = setup(&ctx);
+    let tokens = Vec::from_array(&ctx.env, [token.clone()]);
+    let a = ctx.register_project(&tokens, 10_000, false);
+    let b = ctx.register_project(&tokens, 10_000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    fee_token.mint(&donator, &1_000);
+    let allocations = Vec::from_array(&ctx.env, [(a.id, 500i128), (b.id, 500i128)]);
+    ctx.client.deposit_split(&donator, &token, &allocations);
+}
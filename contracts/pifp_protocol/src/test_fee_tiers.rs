@@ -0,0 +1,89 @@
+extern crate std;
+
+use soroban_sdk::vec;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_default_fee_tier_is_flat_zero() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    assert_eq!(ctx.client.get_fee_tiers(), vec![&ctx.env, (0i128, 0u32)]);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1_000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1_000);
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 0);
+}
+
+#[test]
+fn test_fee_tier_selected_by_donation_size() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    ctx.env.mock_all_auths();
+    // 3% below 1_000, 2% from 1_000, 1% from 10_000.
+    let tiers = vec![&ctx.env, (0i128, 300u32), (1_000, 200), (10_000, 100)];
+    ctx.client.set_fee_tiers(&ctx.admin, &tiers);
+    assert_eq!(ctx.client.get_fee_tiers(), tiers);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100_000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 15);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1_000);
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 35);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &20_000);
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 235);
+
+    assert_eq!(
+        ctx.client.get_balance(&project.id, &token.address),
+        21_500 - 235
+    );
+    let log = ctx.client.get_donations(&project.id, &0, &3);
+    assert_eq!(log.get(2).unwrap().amount, 19_800);
+}
+
+#[test]
+fn test_deposit_below_first_tier_pays_no_fee() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_fee_tiers(&ctx.admin, &vec![&ctx.env, (1_000i128, 100u32)]);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &999);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &999);
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 999);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #62)")]
+fn test_unsorted_fee_tiers_rejected() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_fee_tiers(
+        &ctx.admin,
+        &vec![&ctx.env, (1_000i128, 200u32), (1_000, 100)],
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #25)")]
+fn test_fee_tier_bps_capped() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_fee_tiers(&ctx.admin, &vec![&ctx.env, (0i128, 1_001u32)]);
+}
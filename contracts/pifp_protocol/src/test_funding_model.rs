@@ -0,0 +1,78 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, FundingModel, ProjectStatus};
+
+#[test]
+fn test_keep_it_all_verifies_below_goal() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    assert_eq!(project.funding_model, FundingModel::KeepItAll);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #64)")]
+fn test_all_or_nothing_rejects_verification_below_goal() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_funding_model(&ctx.manager, &project.id, &FundingModel::AllOrNothing);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_all_or_nothing_verifies_once_goal_met() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_funding_model(&ctx.manager, &project.id, &FundingModel::AllOrNothing);
+    assert_eq!(
+        ctx.client.get_project(&project.id).funding_model,
+        FundingModel::AllOrNothing
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_funding_model_locked_after_funding() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .set_funding_model(&ctx.manager, &project.id, &FundingModel::AllOrNothing);
+}
@@ -0,0 +1,318 @@
+extern crate std;
+
+use soroban_sdk::{BytesN, Vec};
+
+use crate::{test_utils::TestContext, Visibility};
+
+#[test]
+fn test_get_projects_skips_missing_ids_and_keeps_order() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+    let p0 = ctx.register_project(&tokens, 1000, false);
+    let p1 = ctx.register_project(&tokens, 2000, false);
+    let p2 = ctx.register_project(&tokens, 3000, false);
+
+    let ids = Vec::from_array(&ctx.env, [p2.id, 99u64, p0.id, 1_000u64]);
+    let projects = ctx.client.get_projects(&ids);
+
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects.get(0).unwrap().id, p2.id);
+    assert_eq!(projects.get(1).unwrap().id, p0.id);
+    assert_ne!(projects.get(0).unwrap().id, p1.id);
+}
+
+#[test]
+fn test_get_projects_empty_input() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_projects(&Vec::new(&ctx.env)).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #41)")]
+fn test_get_projects_caps_ids() {
+    let ctx = TestContext::new();
+    let mut ids = Vec::new(&ctx.env);
+    for id in 0..51u64 {
+        ids.push_back(id);
+    }
+    ctx.client.get_projects(&ids);
+}
+
+#[test]
+fn test_peek_next_project_id_matches_registration() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+
+    let predicted = ctx.client.peek_next_project_id();
+    assert_eq!(ctx.client.peek_next_project_id(), predicted);
+    let project = ctx.register_project(&tokens, 1000, false);
+    assert_eq!(project.id, predicted);
+    assert_eq!(ctx.client.peek_next_project_id(), predicted + 1);
+}
+
+#[test]
+fn test_get_creator_returns_owner() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_creator(&project.id), project.creator);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_get_creator_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.client.get_creator(&42);
+}
+
+#[test]
+fn test_proof_matches() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert!(ctx.client.proof_matches(&project.id, &ctx.dummy_proof()));
+    let other = BytesN::from_array(&ctx.env, &[0x11u8; 32]);
+    assert!(!ctx.client.proof_matches(&project.id, &other));
+}
+
+#[test]
+fn test_get_token_balances_aligned_to_input() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &250);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &250);
+
+    let stranger = ctx.generate_address();
+    let query = Vec::from_array(
+        &ctx.env,
+        [token_b.address.clone(), token_a.address.clone(), stranger],
+    );
+    let balances = ctx.client.get_token_balances(&project.id, &query);
+    assert_eq!(balances, Vec::from_array(&ctx.env, [0i128, 250, 0]));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #41)")]
+fn test_get_token_balances_caps_tokens() {
+    let ctx = TestContext::new();
+    let mut tokens = Vec::new(&ctx.env);
+    for _ in 0..21 {
+        tokens.push_back(ctx.generate_address());
+    }
+    ctx.client.get_token_balances(&0, &tokens);
+}
+
+#[test]
+fn test_active_project_count_tracks_lifecycle() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_active_project_count(), 0);
+
+    let (completed, token_a, sac_a) = ctx.setup_project(1000);
+    let (cancelled, token_b, sac_b) = ctx.setup_project(1000);
+    let (expired, _, _) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_active_project_count(), 3);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    sac_b.mint(&donator, &1000);
+    ctx.client
+        .deposit(&completed.id, &donator, &token_a.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &completed.id, &ctx.dummy_proof());
+    assert_eq!(ctx.client.get_active_project_count(), 3);
+
+    ctx.client
+        .deposit(&cancelled.id, &donator, &token_b.address, &1000);
+    ctx.client.cancel_project(&ctx.manager, &cancelled.id);
+    assert_eq!(ctx.client.get_active_project_count(), 2);
+
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&completed.id);
+    assert_eq!(ctx.client.get_active_project_count(), 1);
+
+    ctx.client.expire_project(&expired.id);
+    assert_eq!(ctx.client.get_active_project_count(), 0);
+
+    ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_active_project_count(), 1);
+    assert_eq!(ctx.client.peek_next_project_id(), 4);
+}
+
+#[test]
+fn test_projects_expiring_within_returns_near_funding_projects() {
+    let ctx = TestContext::new();
+    // Each project's deadline is 86_400s after its registration.
+    let (soon, _, _) = ctx.setup_project(1000);
+    let (funded, funded_token, funded_sac) = ctx.setup_project(1000);
+    ctx.jump_time(40_000);
+    let (later, _, _) = ctx.setup_project(1000);
+    ctx.jump_time(40_000);
+    let (far, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    funded_sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&funded.id, &donator, &funded_token.address, &1000);
+
+    // now = t0 + 80_000: `soon` is 6_400s out, `later` 46_400s, `far` 86_400s.
+    let ids = ctx.client.projects_expiring_within(&10_000, &0, &50);
+    assert_eq!(ids, Vec::from_array(&ctx.env, [soon.id]));
+
+    let ids = ctx.client.projects_expiring_within(&50_000, &0, &50);
+    assert_eq!(ids, Vec::from_array(&ctx.env, [soon.id, later.id]));
+
+    // The scan window is bounded by `start` and `limit`.
+    let ids = ctx.client.projects_expiring_within(&100_000, &2, &1);
+    assert_eq!(ids, Vec::from_array(&ctx.env, [later.id]));
+    let ids = ctx.client.projects_expiring_within(&100_000, &0, &50);
+    assert_eq!(ids.len(), 3);
+    assert!(!ids.contains(funded.id));
+    assert!(ids.contains(far.id));
+
+}
+
+#[test]
+fn test_projects_accepting_lists_projects_per_token() {
+    let ctx = TestContext::new();
+    let (token_a, _) = ctx.create_token();
+    let (token_b, _) = ctx.create_token();
+    let (token_c, _) = ctx.create_token();
+    let only_a = ctx.register_project(
+        &Vec::from_array(&ctx.env, [token_a.address.clone()]),
+        100,
+        false,
+    );
+    let both = ctx.register_project(
+        &Vec::from_array(&ctx.env, [token_b.address.clone(), token_a.address.clone()]),
+        100,
+        false,
+    );
+    let only_b = ctx.register_project(
+        &Vec::from_array(&ctx.env, [token_b.address.clone()]),
+        100,
+        false,
+    );
+
+    assert_eq!(
+        ctx.client.projects_accepting(&token_a.address, &0, &10),
+        Vec::from_array(&ctx.env, [only_a.id, both.id])
+    );
+    assert_eq!(
+        ctx.client.projects_accepting(&token_b.address, &0, &10),
+        Vec::from_array(&ctx.env, [both.id, only_b.id])
+    );
+    assert_eq!(
+        ctx.client
+            .projects_accepting(&token_c.address, &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_projects_accepting_paginates() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let mut ids = Vec::new(&ctx.env);
+    for _ in 0..5 {
+        ids.push_back(ctx.register_project(&tokens, 100, false).id);
+    }
+
+    let page = ctx.client.projects_accepting(&token.address, &0, &2);
+    assert_eq!(page, ids.slice(0..2));
+    let page = ctx.client.projects_accepting(&token.address, &4, &2);
+    assert_eq!(page, ids.slice(4..5));
+    assert_eq!(ctx.client.projects_accepting(&token.address, &5, &2).len(), 0);
+}
+
+#[test]
+fn test_unlisted_projects_hidden_from_listing_but_fetchable() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let public = ctx.register_project(&tokens, 100, false);
+    let unlisted = ctx.register_project(&tokens, 100, false);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_visibility(&ctx.manager, &unlisted.id, &Visibility::Unlisted);
+
+    assert_eq!(
+        ctx.client.projects_accepting(&token.address, &0, &10),
+        Vec::from_array(&ctx.env, [public.id])
+    );
+    let fetched = ctx.client.get_project(&unlisted.id);
+    assert_eq!(fetched.visibility, Visibility::Unlisted);
+    assert_eq!(
+        ctx.client
+            .get_projects(&Vec::from_array(&ctx.env, [unlisted.id]))
+            .len(),
+        1
+    );
+
+    ctx.client
+        .set_visibility(&ctx.manager, &unlisted.id, &Visibility::Public);
+    assert_eq!(
+        ctx.client.projects_accepting(&token.address, &0, &10),
+        Vec::from_array(&ctx.env, [public.id, unlisted.id])
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_visibility_creator_only() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(100);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_visibility(&ctx.admin, &project.id, &Visibility::Unlisted);
+}
+
+#[test]
+fn test_projects_donated_by_lists_each_project_once() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let (other, other_sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone(), other.address.clone()]);
+    let first = ctx.register_project(&tokens, 1000, false);
+    let second = ctx.register_project(&tokens, 1000, false);
+    let untouched = ctx.register_project(&tokens, 1000, false);
+    let third = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    other_sac.mint(&donator, &1000);
+    ctx.client.deposit(&second.id, &donator, &token.address, &100);
+    ctx.client.deposit(&first.id, &donator, &token.address, &100);
+    // Repeat donations, including in a second token, are not re-indexed.
+    ctx.client.deposit(&second.id, &donator, &token.address, &100);
+    ctx.client.deposit(&second.id, &donator, &other.address, &100);
+    ctx.client.deposit(&third.id, &donator, &other.address, &100);
+
+    assert_eq!(
+        ctx.client.projects_donated_by(&donator, &0, &10),
+        Vec::from_array(&ctx.env, [second.id, first.id, third.id])
+    );
+    assert_eq!(
+        ctx.client.projects_donated_by(&donator, &1, &1),
+        Vec::from_array(&ctx.env, [first.id])
+    );
+    assert!(ctx.client.projects_donated_by(&donator, &3, &10).is_empty());
+    assert!(!ctx
+        .client
+        .projects_donated_by(&donator, &0, &10)
+        .contains(untouched.id));
+    assert!(ctx
+        .client
+        .projects_donated_by(&ctx.generate_address(), &0, &10)
+        .is_empty());
+}
@@ -0,0 +1,114 @@
+extern crate std;
+
+use soroban_sdk::{Address, Vec};
+
+use crate::{test_utils::TestContext, Project};
+
+fn sum_project_balances(ctx: &TestContext, projects: &[&Project], token: &Address) -> i128 {
+    projects
+        .iter()
+        .map(|p| ctx.client.get_balance(&p.id, token))
+        .sum()
+}
+
+#[test]
+fn test_global_balance_tracks_deposits_across_projects() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let first = ctx.register_project(&tokens, 5_000, false);
+    let second = ctx.register_project(&tokens, 5_000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&first.id, &donator, &token.address, &300);
+    ctx.client.deposit(&second.id, &donator, &token.address, &700);
+
+    assert_eq!(ctx.client.get_global_balance(&token.address), 1_000);
+    assert_eq!(
+        ctx.client.get_global_balance(&token.address),
+        sum_project_balances(&ctx, &[&first, &second], &token.address)
+    );
+}
+
+#[test]
+fn test_global_balance_consistent_through_release_and_refund() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let released = ctx.register_project(&tokens, 500, false);
+    let refunded = ctx.register_project(&tokens, 5_000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&released.id, &donator, &token.address, &500);
+    ctx.client.deposit(&refunded.id, &donator, &token.address, &400);
+    assert_eq!(ctx.client.get_global_balance(&token.address), 900);
+
+    ctx.client
+        .verify_proof(&ctx.oracle, &released.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&released.id);
+    assert_eq!(ctx.client.get_global_balance(&token.address), 400);
+
+    ctx.jump_time(86_400);
+    ctx.client.refund(&donator, &refunded.id, &token.address);
+    assert_eq!(ctx.client.get_global_balance(&token.address), 0);
+    assert_eq!(
+        ctx.client.get_global_balance(&token.address),
+        sum_project_balances(&ctx, &[&released, &refunded], &token.address)
+    );
+    assert_eq!(
+        token.balance(&ctx.client.address),
+        ctx.client.get_global_balance(&token.address)
+    );
+}
+
+#[test]
+fn test_global_balance_is_per_token() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 5_000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &250);
+    ctx.client.deposit(&project.id, &donator, &token_a.address, &250);
+
+    assert_eq!(ctx.client.get_global_balance(&token_a.address), 250);
+    assert_eq!(ctx.client.get_global_balance(&token_b.address), 0);
+}
+
+#[test]
+fn test_accepted_token_balance_matches_get_balance() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+
+    assert_eq!(
+        ctx.client
+            .get_accepted_token_balance(&project.id, &token.address),
+        400
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_accepted_token_balance_rejects_unaccepted_token() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    let (other, _) = ctx.create_token();
+
+    // The tolerant query reports zero; the strict one refuses the token.
+    assert_eq!(ctx.client.get_balance(&project.id, &other.address), 0);
+    ctx.client
+        .get_accepted_token_balance(&project.id, &other.address);
+}
@@ -180,3 +180,32 @@ fn test_claim_funds_permissionless() {
     assert_eq!(completed.status, ProjectStatus::Completed);
     assert_eq!(token.balance(&ctx.manager), 500);
 }
+
+#[test]
+fn test_claim_funds_leaves_no_residual_balance() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = soroban_sdk::vec![&ctx.env, token_a.address.clone(), token_b.address.clone()];
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    sac_b.mint(&donator, &250);
+    ctx.client.deposit(&project.id, &donator, &token_a.address, &1000);
+    ctx.client.deposit(&project.id, &donator, &token_b.address, &250);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    for token in tokens.iter() {
+        assert_eq!(ctx.client.get_balance(&project.id, &token), 0);
+    }
+    assert_eq!(token_b.balance(&ctx.manager), 250);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
@@ -0,0 +1,54 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, Symbol};
+
+use crate::{test_utils::TestContext, DEFAULT_HASH_ALGO};
+
+#[test]
+fn test_hash_algo_defaults_to_sha256() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(project.hash_algo, DEFAULT_HASH_ALGO);
+    assert_eq!(
+        ctx.client.get_project(&project.id).hash_algo,
+        symbol_short!("sha256")
+    );
+}
+
+#[test]
+fn test_hash_algo_round_trips() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let keccak = Symbol::new(&ctx.env, "keccak");
+
+    ctx.mock_auth(&ctx.manager, "set_hash_algo", (&ctx.manager, project.id, &keccak));
+    ctx.client.set_hash_algo(&ctx.manager, &project.id, &keccak);
+
+    assert_eq!(ctx.client.get_project(&project.id).hash_algo, keccak);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_hash_algo_rejects_stranger() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+    let keccak = Symbol::new(&ctx.env, "keccak");
+
+    ctx.mock_auth(&stranger, "set_hash_algo", (&stranger, project.id, &keccak));
+    ctx.client.set_hash_algo(&stranger, &project.id, &keccak);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_set_hash_algo_after_verification_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .set_hash_algo(&ctx.manager, &project.id, &Symbol::new(&ctx.env, "keccak"));
+}
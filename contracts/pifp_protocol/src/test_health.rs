@@ -0,0 +1,41 @@
+extern crate std;
+
+use soroban_sdk::Env;
+
+use crate::{test_utils::TestContext, PifpProtocol, PifpProtocolClient};
+
+#[test]
+fn test_health_before_init() {
+    let env = Env::default();
+    let contract_id = env.register(PifpProtocol, ());
+    let client = PifpProtocolClient::new(&env, &contract_id);
+
+    let health = client.health();
+    assert!(!health.initialized);
+    assert!(!health.paused);
+    assert_eq!(health.project_count, 0);
+    assert_eq!(health.fee_bps, 0);
+}
+
+#[test]
+fn test_health_after_init_and_pause() {
+    let ctx = TestContext::new();
+    let health = ctx.client.health();
+    assert!(health.initialized);
+    assert!(!health.paused);
+    assert_eq!(health.project_count, 0);
+    assert_eq!(health.fee_bps, 0);
+
+    ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let fee_recipient = ctx.generate_address();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &fee_recipient, &250);
+    ctx.client.pause(&ctx.admin);
+
+    let health = ctx.client.health();
+    assert!(health.initialized);
+    assert!(health.paused);
+    assert_eq!(health.project_count, 1);
+    assert_eq!(health.fee_bps, 250);
+}
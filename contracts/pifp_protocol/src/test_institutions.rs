@@ -0,0 +1,93 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_institutions_only_accepts_registered_institution() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_institutions_only(&ctx.manager, &project.id, &true);
+    assert!(ctx.client.get_project(&project.id).institutions_only);
+
+    let treasury = ctx.generate_address();
+    ctx.client.set_institution(&ctx.admin, &treasury, &true);
+    assert!(ctx.client.is_institution(&treasury));
+
+    sac.mint(&treasury, &500);
+    ctx.client
+        .deposit(&project.id, &treasury, &token.address, &500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #70)")]
+fn test_institutions_only_rejects_individual_donor() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_institutions_only(&ctx.manager, &project.id, &true);
+
+    let wallet = ctx.generate_address();
+    sac.mint(&wallet, &500);
+    ctx.client
+        .deposit(&project.id, &wallet, &token.address, &500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #70)")]
+fn test_removed_institution_is_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_institutions_only(&ctx.manager, &project.id, &true);
+
+    let treasury = ctx.generate_address();
+    ctx.client.set_institution(&ctx.admin, &treasury, &true);
+    ctx.client.set_institution(&ctx.admin, &treasury, &false);
+    assert!(!ctx.client.is_institution(&treasury));
+
+    sac.mint(&treasury, &500);
+    ctx.client
+        .deposit(&project.id, &treasury, &token.address, &500);
+}
+
+#[test]
+fn test_without_flag_both_donor_types_deposit() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let treasury = ctx.generate_address();
+    let wallet = ctx.generate_address();
+    ctx.client.set_institution(&ctx.admin, &treasury, &true);
+    sac.mint(&treasury, &300);
+    sac.mint(&wallet, &200);
+    ctx.client
+        .deposit(&project.id, &treasury, &token.address, &300);
+    ctx.client
+        .deposit(&project.id, &wallet, &token.address, &200);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_institution_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let treasury = ctx.generate_address();
+    ctx.client.set_institution(&ctx.manager, &treasury, &true);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_institutions_only_requires_creator_or_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_institutions_only(&ctx.oracle, &project.id, &true);
+}
@@ -0,0 +1,213 @@
+extern crate std;
+
+use soroban_sdk::{Bytes, BytesN, Vec};
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_migrate_moves_balance_and_accepted_token() {
+    let ctx = TestContext::new();
+    let (project, old, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &old.address, &400);
+
+    let (new, _) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &old.address, &new.address);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &new.address), 400);
+    assert_eq!(ctx.client.get_balance(&project.id, &old.address), 0);
+    let migrated = ctx.client.get_project(&project.id);
+    assert_eq!(
+        migrated.accepted_tokens,
+        Vec::from_array(&ctx.env, [new.address.clone()])
+    );
+    assert_eq!(
+        ctx.client.projects_accepting(&new.address, &0, &10),
+        Vec::from_array(&ctx.env, [project.id])
+    );
+    assert!(ctx
+        .client
+        .projects_accepting(&old.address, &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_migrate_keeps_position_and_token_goal() {
+    let ctx = TestContext::new();
+    let (primary, _) = ctx.create_token();
+    let (old, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [primary.address.clone(), old.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_token_goal(&project.creator, &project.id, &old.address, &500);
+
+    let (new, _) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &old.address, &new.address);
+    assert_eq!(
+        ctx.client.get_project(&project.id).accepted_tokens,
+        Vec::from_array(&ctx.env, [primary.address.clone(), new.address.clone()])
+    );
+    assert_eq!(ctx.client.get_token_goal(&project.id, &new.address), 500);
+    assert_eq!(ctx.client.get_token_goal(&project.id, &old.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_migrate_rejects_already_accepted_token() {
+    let ctx = TestContext::new();
+    let (primary, _) = ctx.create_token();
+    let (secondary, _) = ctx.create_token();
+    let tokens = Vec::from_array(
+        &ctx.env,
+        [primary.address.clone(), secondary.address.clone()],
+    );
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+    ctx.client.migrate_token(
+        &ctx.admin,
+        &project.id,
+        &primary.address,
+        &secondary.address,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_migrate_rejects_unaccepted_token() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let (stray, _) = ctx.create_token();
+    let (new, _) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &stray.address, &new.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_migrate_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, old, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let (new, _) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.manager, &project.id, &old.address, &new.address);
+}
+
+#[test]
+fn test_refund_after_migration_uses_new_token() {
+    let ctx = TestContext::new();
+    let (project, old, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &old.address, &400);
+
+    let (new, new_sac) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &old.address, &new.address);
+    // The redeployed token contract holds the project's funds.
+    new_sac.mint(&ctx.client.address, &400);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&donator, &project.id, &new.address);
+    assert_eq!(new.balance(&donator), 400);
+    assert_eq!(ctx.client.get_balance(&project.id, &new.address), 0);
+}
+
+#[test]
+fn test_anonymous_refund_after_migration_uses_new_token() {
+    let ctx = TestContext::new();
+    let (project, old, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let payer = ctx.generate_address();
+    sac.mint(&payer, &400);
+    let preimage = Bytes::from_slice(&ctx.env, b"migrated-donor");
+    let commitment: BytesN<32> = ctx.env.crypto().sha256(&preimage).into();
+    ctx.client
+        .deposit_anonymous(&payer, &project.id, &old.address, &400, &commitment);
+
+    let (new, new_sac) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &old.address, &new.address);
+    new_sac.mint(&ctx.client.address, &400);
+
+    ctx.jump_time(86_401);
+    let recipient = ctx.generate_address();
+    ctx.client
+        .refund_anonymous(&recipient, &project.id, &new.address, &preimage);
+    assert_eq!(new.balance(&recipient), 400);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_refund_with_old_token_after_migration_fails() {
+    let ctx = TestContext::new();
+    let (project, old, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &old.address, &400);
+
+    let (new, _) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &old.address, &new.address);
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&donator, &project.id, &old.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_migrate_rejects_verified_project() {
+    let ctx = TestContext::new();
+    let (project, old, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &old.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &project.proof_hash);
+
+    let (new, _) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &old.address, &new.address);
+}
+
+#[test]
+fn test_migrate_back_and_forth_keeps_balances_reachable() {
+    let ctx = TestContext::new();
+    let (project, a, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let early = ctx.generate_address();
+    sac.mint(&early, &400);
+    ctx.client.deposit(&project.id, &early, &a.address, &400);
+
+    let (b, _) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &a.address, &b.address);
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &b.address, &a.address);
+
+    // A donor without a balance must not follow the links A -> B -> A forever.
+    let late = ctx.generate_address();
+    sac.mint(&late, &100);
+    ctx.client.deposit(&project.id, &late, &a.address, &100);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&early, &project.id, &a.address);
+    ctx.client.refund(&late, &project.id, &a.address);
+    assert_eq!(a.balance(&early), 400);
+    assert_eq!(a.balance(&late), 100);
+}
@@ -0,0 +1,110 @@
+extern crate std;
+
+use soroban_sdk::{BytesN, Vec};
+
+use crate::{test_utils::TestContext, Milestone, Project, ProjectStatus};
+
+fn proof(ctx: &TestContext, byte: u8) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[byte; 32])
+}
+
+fn register_staged(ctx: &TestContext) -> Project {
+    let (token, _) = ctx.create_token();
+    let mut milestones = Vec::new(&ctx.env);
+    for (i, bps) in [(1u8, 3000u32), (2, 3000), (3, 4000)] {
+        milestones.push_back(Milestone {
+            label: BytesN::from_array(&ctx.env, &[i; 32]),
+            amount_bps: bps,
+            proof_hash: proof(ctx, 0x10 + i),
+        });
+    }
+    ctx.env.mock_all_auths();
+    ctx.client.register_project(
+        &ctx.manager,
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        &1000i128,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86_400),
+        &false,
+        &milestones,
+        &0u32,
+        &Vec::new(&ctx.env),
+        &0u32,
+    )
+}
+
+#[test]
+fn test_acknowledge_single_milestone() {
+    let ctx = TestContext::new();
+    let project = register_staged(&ctx);
+
+    ctx.client
+        .acknowledge_milestone(&ctx.oracle, &project.id, &1, &proof(&ctx, 0x12));
+
+    let updated = ctx.client.get_project(&project.id);
+    assert_eq!(
+        updated.completed_milestones,
+        Vec::from_array(&ctx.env, [false, true, false])
+    );
+    assert_eq!(updated.status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_acknowledge_milestones_independently() {
+    let ctx = TestContext::new();
+    let project = register_staged(&ctx);
+
+    ctx.client
+        .acknowledge_milestone(&ctx.oracle, &project.id, &0, &proof(&ctx, 0x11));
+    ctx.client
+        .acknowledge_milestone(&ctx.oracle, &project.id, &2, &proof(&ctx, 0x13));
+
+    let updated = ctx.client.get_project(&project.id);
+    assert_eq!(
+        updated.completed_milestones,
+        Vec::from_array(&ctx.env, [true, false, true])
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_acknowledge_rejects_other_milestone_proof() {
+    let ctx = TestContext::new();
+    let project = register_staged(&ctx);
+
+    ctx.client
+        .acknowledge_milestone(&ctx.oracle, &project.id, &0, &proof(&ctx, 0x12));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_acknowledge_twice_fails() {
+    let ctx = TestContext::new();
+    let project = register_staged(&ctx);
+
+    ctx.client
+        .acknowledge_milestone(&ctx.oracle, &project.id, &0, &proof(&ctx, 0x11));
+    ctx.client
+        .acknowledge_milestone(&ctx.oracle, &project.id, &0, &proof(&ctx, 0x11));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+fn test_acknowledge_out_of_range_index() {
+    let ctx = TestContext::new();
+    let project = register_staged(&ctx);
+
+    ctx.client
+        .acknowledge_milestone(&ctx.oracle, &project.id, &3, &proof(&ctx, 0x11));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_acknowledge_requires_oracle() {
+    let ctx = TestContext::new();
+    let project = register_staged(&ctx);
+
+    ctx.client
+        .acknowledge_milestone(&ctx.manager, &project.id, &0, &proof(&ctx, 0x11));
+}
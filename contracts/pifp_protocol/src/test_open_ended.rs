@@ -0,0 +1,89 @@
+extern crate std;
+
+use soroban_sdk::{BytesN, Vec};
+
+use crate::{test_utils::TestContext, types::Milestone, Project, ProjectStatus};
+
+fn register_open(ctx: &TestContext, token: &soroban_sdk::Address) -> Project {
+    ctx.env.mock_all_auths();
+    let proof_hash = ctx.dummy_proof();
+    let mut milestones = Vec::new(&ctx.env);
+    milestones.push_back(Milestone {
+        label: BytesN::from_array(&ctx.env, &[0u8; 32]),
+        amount_bps: 10000,
+        proof_hash: proof_hash.clone(),
+    });
+    ctx.client.register_open_project(
+        &ctx.manager,
+        &Vec::from_array(&ctx.env, [token.clone()]),
+        &proof_hash,
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        &Vec::new(&ctx.env),
+        &0u32,
+    )
+}
+
+#[test]
+fn test_open_ended_project_registers_with_zero_goal() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let project = register_open(&ctx, &token.address);
+
+    assert_eq!(project.goal, 0);
+    assert!(project.open_ended);
+    assert_eq!(project.status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_open_ended_project_stays_funding_after_deposits() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let project = register_open(&ctx, &token.address);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &600_000);
+
+    let loaded = ctx.client.get_project(&project.id);
+    assert_eq!(loaded.status, ProjectStatus::Funding);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1_000_000);
+}
+
+#[test]
+fn test_open_ended_project_expires_and_refunds() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let project = register_open(&ctx, &token.address);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &500);
+
+    ctx.jump_time(86401);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 500);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Expired
+    );
+}
+
+#[test]
+fn test_regular_project_not_open_ended() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert!(!project.open_ended);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_regular_project_still_rejects_zero_goal() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.register_project(&Vec::from_array(&ctx.env, [token.address]), 0, false);
+}
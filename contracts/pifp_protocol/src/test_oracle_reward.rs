@@ -0,0 +1,111 @@
+extern crate std;
+
+use soroban_sdk::{testutils::IssuerFlags, token, Vec};
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_oracle_reward_paid_on_release() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_oracle_reward_bps(&ctx.admin, &200);
+    assert_eq!(ctx.client.get_oracle_reward_bps(), 200);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    assert_eq!(token.balance(&ctx.oracle), 20);
+    assert_eq!(token.balance(&ctx.manager), 980);
+}
+
+#[test]
+fn test_unpayable_oracle_reward_goes_to_creator() {
+    let ctx = TestContext::new();
+    // The issuer must be able to revoke authorization to freeze the oracle.
+    let asset = ctx
+        .env
+        .register_stellar_asset_contract_v2(ctx.admin.clone());
+    asset.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = token::Client::new(&ctx.env, &asset.address());
+    let sac = token::StellarAssetClient::new(&ctx.env, &asset.address());
+    let project = ctx.register_project(&Vec::from_array(&ctx.env, [asset.address()]), 1000, false);
+    ctx.env.mock_all_auths();
+    ctx.client.set_oracle_reward_bps(&ctx.admin, &200);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    // The oracle's trustline is frozen, so it cannot receive the reward.
+    sac.set_authorized(&ctx.oracle, &false);
+    ctx.client.claim_funds(&project.id);
+
+    assert_eq!(token.balance(&ctx.oracle), 0);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_oracle_reward_composes_with_protocol_fee() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_oracle_reward_bps(&ctx.admin, &100);
+    let fee_recipient = ctx.generate_address();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &fee_recipient, &500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 50);
+    assert_eq!(token.balance(&ctx.oracle), 10);
+    assert_eq!(token.balance(&ctx.manager), 940);
+}
+
+#[test]
+fn test_no_oracle_reward_by_default() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    assert_eq!(ctx.client.get_oracle_reward_bps(), 0);
+    assert_eq!(token.balance(&ctx.oracle), 0);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #25)")]
+fn test_oracle_reward_capped() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_oracle_reward_bps(&ctx.admin, &1001);
+}
@@ -0,0 +1,142 @@
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Vec};
+
+use crate::{test_utils::TestContext, types::Milestone, Project, ProjectStatus, Role};
+
+/// Register a 1-of-N project whose oracles all hold the Oracle role.
+fn register_with_oracles(ctx: &TestContext, oracles: &Vec<Address>) -> Project {
+    ctx.env.mock_all_auths();
+    for oracle in oracles.iter() {
+        ctx.client.grant_role(&ctx.admin, &oracle, &Role::Oracle);
+    }
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [Milestone {
+            label: BytesN::from_array(&ctx.env, &[0u8; 32]),
+            amount_bps: 10000,
+            proof_hash: ctx.dummy_proof(),
+        }],
+    );
+    ctx.client.register_project(
+        &ctx.manager,
+        &tokens,
+        &1000i128,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        oracles,
+        &1u32,
+    )
+}
+
+#[test]
+fn test_oracle_weight_defaults_to_one() {
+    let ctx = TestContext::new();
+    let oracle = ctx.generate_address();
+    assert_eq!(ctx.client.get_oracle_weight(&oracle), 1);
+    assert_eq!(ctx.client.get_consensus_threshold(), 0);
+}
+
+#[test]
+fn test_combined_weight_crosses_threshold() {
+    let ctx = TestContext::new();
+    let o1 = ctx.generate_address();
+    let o2 = ctx.generate_address();
+    let o3 = ctx.generate_address();
+    let oracles = Vec::from_array(&ctx.env, [o1.clone(), o2.clone(), o3.clone()]);
+    let project = register_with_oracles(&ctx, &oracles);
+
+    ctx.client.set_oracle_weight(&ctx.admin, &o1, &4);
+    ctx.client.set_oracle_weight(&ctx.admin, &o2, &3);
+    ctx.client.set_oracle_weight(&ctx.admin, &o3, &5);
+    ctx.client.set_consensus_threshold(&ctx.admin, &7);
+
+    // Each oracle alone meets the 1-of-3 count but not the weight.
+    ctx.client
+        .verify_proof(&o1, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    // 4 + 3 reaches 7.
+    ctx.client
+        .verify_proof(&o2, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+fn test_repeat_vote_does_not_add_weight() {
+    let ctx = TestContext::new();
+    let o1 = ctx.generate_address();
+    let o2 = ctx.generate_address();
+    let oracles = Vec::from_array(&ctx.env, [o1.clone(), o2.clone()]);
+    let project = register_with_oracles(&ctx, &oracles);
+
+    ctx.client.set_oracle_weight(&ctx.admin, &o1, &5);
+    ctx.client.set_consensus_threshold(&ctx.admin, &6);
+
+    ctx.client
+        .verify_proof(&o1, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .verify_proof(&o1, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    // o2 carries the default weight of 1.
+    ctx.client
+        .verify_proof(&o2, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_oracle_weight_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let oracle = ctx.generate_address();
+    ctx.client.set_oracle_weight(&ctx.manager, &oracle, &10);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #78)")]
+fn test_single_oracle_below_threshold_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_consensus_threshold(&ctx.admin, &2);
+
+    // The global oracle carries the default weight of 1.
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_single_oracle_meeting_threshold_verifies() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_consensus_threshold(&ctx.admin, &2);
+    ctx.client.set_oracle_weight(&ctx.admin, &ctx.oracle, &2);
+
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
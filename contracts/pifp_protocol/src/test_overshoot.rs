@@ -0,0 +1,89 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, OvershootPolicy, ProjectStatus};
+
+#[test]
+fn test_keep_policy_accepts_overshoot() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    assert_eq!(project.overshoot_policy, OvershootPolicy::Keep);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1500);
+}
+
+#[test]
+fn test_reject_policy_allows_exact_goal() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_overshoot_policy(&project.creator, &project.id, &OvershootPolicy::Reject);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &600);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_reject_policy_refuses_overshoot() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_overshoot_policy(&project.creator, &project.id, &OvershootPolicy::Reject);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1001);
+    ctx.client.deposit(&project.id, &donator, &token.address, &600);
+    ctx.client.deposit(&project.id, &donator, &token.address, &401);
+}
+
+#[test]
+fn test_refund_excess_policy_returns_remainder() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_overshoot_policy(
+        &project.creator,
+        &project.id,
+        &OvershootPolicy::RefundExcess,
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1300);
+    ctx.client.deposit(&project.id, &donator, &token.address, &700);
+    ctx.client.deposit(&project.id, &donator, &token.address, &600);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+    assert_eq!(token.balance(&ctx.client.address), 1000);
+    assert_eq!(token.balance(&donator), 300);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_refund_excess_policy_rejects_when_already_full() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_overshoot_policy(
+        &project.creator,
+        &project.id,
+        &OvershootPolicy::RefundExcess,
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+}
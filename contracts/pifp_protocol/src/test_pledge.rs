@@ -0,0 +1,97 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, Pledge};
+
+#[test]
+fn test_pledge_records_without_transfer() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .pledge(&donator, &project.id, &token.address, &200);
+    ctx.client
+        .pledge(&donator, &project.id, &token.address, &100);
+
+    assert_eq!(token.balance(&donator), 500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+    let pledges = ctx.client.get_pledges(&project.id);
+    assert_eq!(pledges.len(), 1);
+    assert_eq!(
+        pledges.get(0).unwrap(),
+        Pledge {
+            donor: donator,
+            token: token.address.clone(),
+            amount: 300,
+        }
+    );
+}
+
+#[test]
+fn test_fulfill_pledge_transfers_and_clears() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac.mint(&alice, &500);
+    ctx.client.pledge(&alice, &project.id, &token.address, &300);
+    ctx.client.pledge(&bob, &project.id, &token.address, &100);
+
+    ctx.client
+        .fulfill_pledge(&alice, &project.id, &token.address);
+
+    assert_eq!(token.balance(&alice), 200);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 300);
+    let pledges = ctx.client.get_pledges(&project.id);
+    assert_eq!(pledges.len(), 1);
+    assert_eq!(pledges.get(0).unwrap().donor, bob);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #55)")]
+fn test_fulfill_without_pledge_fails() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    ctx.client
+        .fulfill_pledge(&donator, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_pledge_unaccepted_token_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let (other, _) = ctx.create_token();
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    ctx.client
+        .pledge(&donator, &project.id, &other.address, &100);
+}
+
+#[test]
+fn test_unfulfilled_pledge_remains_after_expiry() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .pledge(&donator, &project.id, &token.address, &100);
+    ctx.jump_time(86_401);
+
+    let result = ctx
+        .client
+        .try_fulfill_pledge(&donator, &project.id, &token.address);
+    assert!(result.is_err());
+    assert_eq!(token.balance(&donator), 100);
+    assert_eq!(ctx.client.get_pledges(&project.id).len(), 1);
+}
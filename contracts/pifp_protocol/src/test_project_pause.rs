@@ -76,3 +76,89 @@ fn test_project_queries_still_work_when_project_paused() {
     assert_eq!(loaded.id, project.id);
     assert!(loaded.paused);
 }
+
+#[test]
+fn test_set_project_paused_toggles_flag() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    assert!(!ctx.client.is_project_paused(&project.id));
+    ctx.client.set_project_paused(&ctx.admin, &project.id, &true);
+    assert!(ctx.client.is_project_paused(&project.id));
+    ctx.client.set_project_paused(&ctx.admin, &project.id, &false);
+    assert!(!ctx.client.is_project_paused(&project.id));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_project_paused_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .set_project_paused(&project.creator, &project.id, &true);
+}
+
+#[test]
+fn test_refund_allowed_while_project_paused() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+    ctx.client.set_project_paused(&ctx.admin, &project.id, &true);
+
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 400);
+}
+
+#[test]
+fn test_creator_can_pause_and_resume_donations() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .set_donations_paused(&project.creator, &project.id, &true);
+    assert!(ctx.client.is_donations_paused(&project.id));
+    assert!(!ctx.client.is_project_paused(&project.id));
+
+    ctx.client
+        .set_donations_paused(&project.creator, &project.id, &false);
+    assert!(!ctx.client.is_donations_paused(&project.id));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #44)")]
+fn test_deposit_rejected_while_donations_paused() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .set_donations_paused(&project.creator, &project.id, &true);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_toggle_donations_pause() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .set_donations_paused(&ctx.admin, &project.id, &true);
+}
@@ -0,0 +1,49 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_timeline_tracks_full_lifecycle() {
+    let ctx = TestContext::new();
+    let created = ctx.env.ledger().timestamp();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let timeline = ctx.client.get_project_timeline(&project.id);
+    assert_eq!(timeline.created_at, created);
+    assert_eq!(timeline.goal_reached_at, 0);
+    assert_eq!(timeline.verified_at, 0);
+    assert_eq!(timeline.deadline, project.deadline);
+
+    ctx.jump_time(100);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    let funded_at = ctx.env.ledger().timestamp();
+
+    ctx.jump_time(200);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    let verified_at = ctx.env.ledger().timestamp();
+
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+
+    let timeline = ctx.client.get_project_timeline(&project.id);
+    assert_eq!(timeline.created_at, created);
+    assert_eq!(timeline.goal_reached_at, funded_at);
+    assert_eq!(timeline.verified_at, verified_at);
+    assert_eq!(timeline.deadline, project.deadline);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_timeline_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.client.get_project_timeline(&42);
+}
@@ -0,0 +1,158 @@
+extern crate std;
+
+use soroban_sdk::{Bytes, BytesN, Vec};
+
+use crate::{test_utils::TestContext, Role};
+
+#[test]
+fn test_get_project_redacts_proof_hash() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let zero = BytesN::from_array(&ctx.env, &[0u8; 32]);
+    assert_eq!(ctx.client.get_project(&project.id).proof_hash, zero);
+    let listed = ctx
+        .client
+        .get_projects(&Vec::from_array(&ctx.env, [project.id]));
+    assert_eq!(listed.get(0).unwrap().proof_hash, zero);
+}
+
+#[test]
+fn test_auditor_reads_proof_hash() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+
+    assert_eq!(
+        ctx.client.get_proof_hash(&auditor, &project.id),
+        ctx.dummy_proof()
+    );
+}
+
+#[test]
+fn test_admin_reads_proof_hash() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    assert_eq!(
+        ctx.client.get_proof_hash(&ctx.admin, &project.id),
+        ctx.dummy_proof()
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_project_manager_cannot_read_proof_hash() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client.get_proof_hash(&ctx.manager, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_unprivileged_cannot_read_proof_hash() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let stranger = ctx.generate_address();
+    ctx.client.get_proof_hash(&stranger, &project.id);
+}
+
+fn register_without_proof(ctx: &TestContext) -> crate::types::Project {
+    let (token, _) = ctx.create_token();
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [crate::types::Milestone {
+            label: BytesN::from_array(&ctx.env, &[0u8; 32]),
+            amount_bps: 10000,
+            proof_hash: ctx.dummy_proof(),
+        }],
+    );
+    ctx.client.register_project(
+        &ctx.manager,
+        &Vec::from_array(&ctx.env, [token.address]),
+        &1000,
+        &BytesN::from_array(&ctx.env, &[0u8; 32]),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        &Vec::new(&ctx.env),
+        &0u32,
+    )
+}
+
+fn default_hash(ctx: &TestContext, project_id: u64) -> BytesN<32> {
+    let id = Bytes::from_array(&ctx.env, &project_id.to_be_bytes());
+    ctx.env.crypto().sha256(&id).into()
+}
+
+#[test]
+fn test_default_proof_hash_then_set() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let project = register_without_proof(&ctx);
+    assert_eq!(
+        ctx.client.get_proof_hash(&ctx.admin, &project.id),
+        default_hash(&ctx, project.id)
+    );
+
+    ctx.client
+        .set_proof_hash(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_proof_hash(&ctx.admin, &project.id),
+        ctx.dummy_proof()
+    );
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #60)")]
+fn test_verify_rejected_while_proof_hash_default() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let project = register_without_proof(&ctx);
+    let default = default_hash(&ctx, project.id);
+    ctx.client.verify_proof(&ctx.oracle, &project.id, &default);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #61)")]
+fn test_set_proof_hash_only_once() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let project = register_without_proof(&ctx);
+
+    ctx.client
+        .set_proof_hash(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .set_proof_hash(&ctx.admin, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #61)")]
+fn test_set_proof_hash_rejected_when_registered_with_hash() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_proof_hash(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_proof_hash_requires_oracle_or_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let project = register_without_proof(&ctx);
+    ctx.client
+        .set_proof_hash(&ctx.manager, &project.id, &ctx.dummy_proof());
+}
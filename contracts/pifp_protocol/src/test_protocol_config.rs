@@ -167,7 +167,7 @@ fn test_verify_and_release_with_fees() {
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "deposit",
-                args: (project.id, &donor, &token.address, 1000i128).into_val(&env),
+                args: (project.id, &token.address, 1000i128).into_val(&env),
                 sub_invocations: &[
                     MockAuthInvoke {
                         contract: &token.address,
@@ -205,9 +205,27 @@ fn test_verify_and_release_with_fees() {
     // Fee = 1000 * 500 / 10000 = 50 tokens
     // Creator = 1000 - 50 = 950 tokens
 
-    assert_eq!(token.balance(&fee_recipient), 50);
+    // The fee stays in the contract's treasury until swept.
+    assert_eq!(client.get_treasury_balance(&token.address), 50);
     assert_eq!(token.balance(&creator), 950);
+    assert_eq!(token.balance(&client.address), 50);
+
+    env.mock_auths(&[
+        MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "withdraw_treasury",
+                args: (&admin, &token.address, &fee_recipient, 50i128).into_val(&env),
+                sub_invocations: &[],
+            },
+        },
+    ]);
+    client.withdraw_treasury(&admin, &token.address, &fee_recipient, &50);
+
+    assert_eq!(token.balance(&fee_recipient), 50);
     assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_treasury_balance(&token.address), 0);
 }
 
 #[test]
@@ -190,7 +190,7 @@ fn test_deposit_blocked_when_locked() {
             invoke: &MockAuthInvoke {
                 contract: &ctx.client.address,
                 fn_name: "deposit",
-                args: (project_id, &ctx.manager, &token.address, 500i128).into_val(&ctx.env),
+                args: (project_id, &token.address, 500i128).into_val(&ctx.env),
                 sub_invocations: &[
                     MockAuthInvoke {
                         contract: &token.address,
@@ -284,7 +284,7 @@ fn test_lock_released_after_successful_deposit() {
             invoke: &MockAuthInvoke {
                 contract: &ctx.client.address,
                 fn_name: "deposit",
-                args: (project_id, &ctx.manager, &token.address, 500i128).into_val(&ctx.env),
+                args: (project_id, &token.address, 500i128).into_val(&ctx.env),
                 sub_invocations: &[
                     MockAuthInvoke {
                         contract: &token.address,
@@ -52,7 +52,7 @@ fn mock_deposit_auth(env: &Env, client: &Address, donator: &Address, project_id:
             invoke: &MockAuthInvoke {
                 contract: client,
                 fn_name: "deposit",
-                args: (project_id, donator, token, amount).into_val(env),
+                args: (project_id, token, amount).into_val(env),
                 sub_invocations: &[
                     MockAuthInvoke {
                         contract: token,
@@ -0,0 +1,111 @@
+extern crate std;
+
+use soroban_sdk::{token, Address, Vec};
+
+use crate::test_utils::TestContext;
+
+/// Expire a project funded by three donors (100, 200, 300) and return it
+/// with its token and the donors.
+fn expired_with_donors(ctx: &TestContext) -> (u64, token::Client<'static>, [Address; 3]) {
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    let donors = [
+        ctx.generate_address(),
+        ctx.generate_address(),
+        ctx.generate_address(),
+    ];
+    for (donor, amount) in donors.iter().zip([100i128, 200, 300]) {
+        sac.mint(donor, &amount);
+        ctx.client
+            .deposit(&project.id, donor, &token.address, &amount);
+    }
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    (project.id, token, donors)
+}
+
+#[test]
+fn test_refund_batch_in_chunks() {
+    let ctx = TestContext::new();
+    let (project_id, token, [alice, bob, carol]) = expired_with_donors(&ctx);
+
+    let first = Vec::from_array(&ctx.env, [alice.clone(), bob.clone()]);
+    assert_eq!(
+        ctx.client.refund_batch(&project_id, &token.address, &first),
+        2
+    );
+    assert_eq!(token.balance(&alice), 100);
+    assert_eq!(token.balance(&bob), 200);
+    assert_eq!(ctx.client.get_balance(&project_id, &token.address), 300);
+
+    let second = Vec::from_array(&ctx.env, [carol.clone()]);
+    assert_eq!(
+        ctx.client
+            .refund_batch(&project_id, &token.address, &second),
+        1
+    );
+    assert_eq!(token.balance(&carol), 300);
+    assert_eq!(ctx.client.get_balance(&project_id, &token.address), 0);
+    assert_eq!(
+        ctx.client.get_total_refunded(&project_id, &token.address),
+        600
+    );
+}
+
+#[test]
+fn test_repeated_batch_skips_refunded_donors() {
+    let ctx = TestContext::new();
+    let (project_id, token, [alice, bob, carol]) = expired_with_donors(&ctx);
+
+    // Alice reclaims on her own before the keeper runs.
+    ctx.client.refund(&alice, &project_id, &token.address);
+
+    let stranger = ctx.generate_address();
+    let batch = Vec::from_array(
+        &ctx.env,
+        [alice.clone(), bob.clone(), carol.clone(), stranger],
+    );
+    assert_eq!(
+        ctx.client.refund_batch(&project_id, &token.address, &batch),
+        2
+    );
+    assert_eq!(
+        ctx.client.refund_batch(&project_id, &token.address, &batch),
+        0
+    );
+
+    assert_eq!(token.balance(&alice), 100);
+    assert_eq!(token.balance(&bob), 200);
+    assert_eq!(token.balance(&carol), 300);
+    assert_eq!(
+        ctx.client.get_total_refunded(&project_id, &token.address),
+        600
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_refund_batch_rejected_before_expiry() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+    let donor = ctx.generate_address();
+    sac.mint(&donor, &100);
+    ctx.client
+        .deposit(&project.id, &donor, &token.address, &100);
+
+    let batch = Vec::from_array(&ctx.env, [donor]);
+    ctx.client.refund_batch(&project.id, &token.address, &batch);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #41)")]
+fn test_refund_batch_too_large() {
+    let ctx = TestContext::new();
+    let (project_id, token, _) = expired_with_donors(&ctx);
+    let mut batch = Vec::new(&ctx.env);
+    for _ in 0..31 {
+        batch.push_back(ctx.generate_address());
+    }
+    ctx.client.refund_batch(&project_id, &token.address, &batch);
+}
@@ -0,0 +1,153 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus, REFUND_WINDOW};
+
+const WINDOW: u64 = 7 * 24 * 60 * 60;
+
+#[test]
+fn test_refund_window_defaults_to_constant() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_refund_window(), REFUND_WINDOW);
+}
+
+#[test]
+fn test_refund_allowed_within_window() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_refund_window(&ctx.admin, &WINDOW);
+    assert_eq!(ctx.client.get_refund_window(), WINDOW);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    let expired = ctx.client.get_project(&project.id);
+    assert_eq!(expired.status, ProjectStatus::Expired);
+    assert_eq!(expired.refund_expiry, ctx.env.ledger().timestamp() + WINDOW);
+
+    ctx.jump_time(WINDOW - 1);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 400);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+fn test_refund_rejected_after_window() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_refund_window(&ctx.admin, &WINDOW);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.jump_time(WINDOW);
+    ctx.client.refund(&donator, &project.id, &token.address);
+}
+
+#[test]
+fn test_sweep_unclaimed_moves_balance_to_treasury() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_refund_window(&ctx.admin, &WINDOW);
+
+    let refunded = ctx.generate_address();
+    let absent = ctx.generate_address();
+    sac.mint(&refunded, &300);
+    sac.mint(&absent, &500);
+    ctx.client.deposit(&project.id, &refunded, &token.address, &300);
+    ctx.client.deposit(&project.id, &absent, &token.address, &500);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&refunded, &project.id, &token.address);
+
+    ctx.jump_time(WINDOW);
+    ctx.client
+        .sweep_unclaimed(&ctx.admin, &project.id, &token.address);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 500);
+
+    let treasury = ctx.generate_address();
+    ctx.client
+        .withdraw_treasury(&ctx.admin, &token.address, &treasury, &500);
+    assert_eq!(token.balance(&treasury), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+fn test_sweep_unclaimed_blocked_during_window() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client
+        .sweep_unclaimed(&ctx.admin, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_refund_window_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_refund_window(&ctx.manager, &WINDOW);
+}
+
+#[test]
+fn test_total_refunded_aggregates_donor_refunds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.env.mock_all_auths();
+
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    let carol = ctx.generate_address();
+    for (donor, amount) in [(&alice, 300), (&bob, 500), (&carol, 200)] {
+        sac.mint(donor, &amount);
+        ctx.client.deposit(&project.id, donor, &token.address, &amount);
+    }
+    assert_eq!(ctx.client.get_total_refunded(&project.id, &token.address), 0);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&alice, &project.id, &token.address);
+    ctx.client.refund(&bob, &project.id, &token.address);
+
+    assert_eq!(ctx.client.get_total_refunded(&project.id, &token.address), 800);
+    // Carol never reclaimed: refunded plus remaining equals what was raised.
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #65)")]
+fn test_refund_exceeding_project_balance_underflows() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+
+    // Corrupt the project balance so the donor's refund exceeds it.
+    ctx.env.as_contract(&ctx.client.address, || {
+        crate::storage::set_token_balance(&ctx.env, project.id, &token.address, 100);
+    });
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&donator, &project.id, &token.address);
+}
@@ -0,0 +1,62 @@
+extern crate std;
+
+use soroban_sdk::{BytesN, Vec};
+
+use crate::test_utils::TestContext;
+use crate::types::{Milestone, ProjectStatus};
+
+fn register_and_seed(
+    ctx: &TestContext,
+    goal: i128,
+    seed: i128,
+) -> (crate::types::Project, soroban_sdk::token::Client<'static>) {
+    let (token, sac) = ctx.create_token();
+    sac.mint(&ctx.manager, &seed);
+    let proof_hash = ctx.dummy_proof();
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [Milestone {
+            label: BytesN::from_array(&ctx.env, &[0u8; 32]),
+            amount_bps: 10000,
+            proof_hash: proof_hash.clone(),
+        }],
+    );
+    let project = ctx.client.register_and_deposit(
+        &ctx.manager,
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        &goal,
+        &proof_hash,
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        &Vec::new(&ctx.env),
+        &0u32,
+        &token.address,
+        &seed,
+    );
+    (project, token)
+}
+
+#[test]
+fn test_register_and_deposit_seeds_project() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let (project, token) = register_and_seed(&ctx, 1000, 250);
+
+    assert_eq!(project.donation_count, 1);
+    assert_eq!(project.status, ProjectStatus::Funding);
+    assert_eq!(ctx.client.get_project(&project.id).creator, ctx.manager);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 250);
+    assert_eq!(token.balance(&ctx.manager), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #56)")]
+fn test_register_and_deposit_honors_self_deposit_delay() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_self_deposit_delay(&ctx.admin, &3600);
+    register_and_seed(&ctx, 1000, 250);
+}
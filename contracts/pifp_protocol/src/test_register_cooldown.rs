@@ -0,0 +1,53 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::test_utils::TestContext;
+
+fn set_cooldown(ctx: &TestContext, cooldown: u64) {
+    ctx.mock_auth(&ctx.admin, "set_register_cooldown", (&ctx.admin, cooldown));
+    ctx.client.set_register_cooldown(&ctx.admin, &cooldown);
+}
+
+#[test]
+fn test_default_cooldown_allows_back_to_back_registration() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+
+    assert_eq!(ctx.client.get_register_cooldown(), 0);
+    let first = ctx.register_project(&tokens, 1000, false);
+    let second = ctx.register_project(&tokens, 1000, false);
+    assert_eq!(second.id, first.id + 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #38)")]
+fn test_registration_within_cooldown_fails() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+    set_cooldown(&ctx, 3_600);
+
+    ctx.register_project(&tokens, 1000, false);
+    ctx.jump_time(3_599);
+    ctx.register_project(&tokens, 1000, false);
+}
+
+#[test]
+fn test_registration_after_cooldown_succeeds() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+    set_cooldown(&ctx, 3_600);
+
+    let first = ctx.register_project(&tokens, 1000, false);
+    ctx.jump_time(3_600);
+    let second = ctx.register_project(&tokens, 1000, false);
+    assert_eq!(second.id, first.id + 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_register_cooldown_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.mock_auth(&ctx.manager, "set_register_cooldown", (&ctx.manager, 60u64));
+    ctx.client.set_register_cooldown(&ctx.manager, &60);
+}
@@ -0,0 +1,116 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::Events,
+    xdr::{ContractEventBody, ScVal},
+    IntoVal, TryFromVal, Val,
+};
+
+use crate::{events::FundsReleased, test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_release_to_defaults_to_creator() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    assert_eq!(project.release_to, ctx.manager);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+fn test_claim_sends_funds_to_release_to() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let custody = ctx.generate_address();
+    ctx.client
+        .set_release_to(&ctx.manager, &project.id, &custody);
+    let updated = ctx.client.get_project(&project.id);
+    assert_eq!(updated.release_to, custody);
+    assert_eq!(updated.creator, ctx.manager);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    // Newest first: fnd_claim, status, then the release.
+    let released = events.events().iter().rev().nth(2).unwrap().clone();
+    let ContractEventBody::V0(body) = released.body;
+    let expected = FundsReleased {
+        project_id: project.id,
+        token: token.address.clone(),
+        amount: 1000,
+        to: custody.clone(),
+    };
+    let expected_val: Val = expected.into_val(&ctx.env);
+    assert_eq!(body.data, ScVal::try_from_val(&ctx.env, &expected_val).unwrap());
+
+    assert_eq!(token.balance(&custody), 1000);
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_creator_keeps_control_with_release_to() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let custody = ctx.generate_address();
+    ctx.client
+        .set_release_to(&ctx.manager, &project.id, &custody);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Cancelled
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_release_to_creator_only() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let custody = ctx.generate_address();
+    ctx.client.set_release_to(&ctx.admin, &project.id, &custody);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_set_release_to_rejected_after_funding() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1000);
+
+    let custody = ctx.generate_address();
+    ctx.client
+        .set_release_to(&ctx.manager, &project.id, &custody);
+}
@@ -0,0 +1,128 @@
+extern crate std;
+
+use soroban_sdk::{token, Vec};
+
+use crate::{test_utils::TestContext, Project};
+
+/// Register a project accepting two tokens and return it with both.
+fn two_token_project(
+    ctx: &TestContext,
+) -> (
+    Project,
+    (token::Client<'static>, token::StellarAssetClient<'static>),
+    (token::Client<'static>, token::StellarAssetClient<'static>),
+) {
+    let primary = ctx.create_token();
+    let secondary = ctx.create_token();
+    let tokens = Vec::from_array(
+        &ctx.env,
+        [primary.0.address.clone(), secondary.0.address.clone()],
+    );
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+    (project, primary, secondary)
+}
+
+#[test]
+fn test_remove_unused_token() {
+    let ctx = TestContext::new();
+    let (project, primary, (secondary, _)) = two_token_project(&ctx);
+
+    ctx.client
+        .remove_token(&project.creator, &project.id, &secondary.address);
+    let project = ctx.client.get_project(&project.id);
+    assert_eq!(
+        project.accepted_tokens,
+        Vec::from_array(&ctx.env, [primary.0.address.clone()])
+    );
+    assert_eq!(
+        ctx.client
+            .projects_accepting(&secondary.address, &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #71)")]
+fn test_remove_token_blocked_with_balance() {
+    let ctx = TestContext::new();
+    let (project, _, (secondary, sac)) = two_token_project(&ctx);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &secondary.address, &300);
+    ctx.client
+        .remove_token(&project.creator, &project.id, &secondary.address);
+}
+
+#[test]
+fn test_remove_token_allowed_once_refunded() {
+    let ctx = TestContext::new();
+    let (project, _, (secondary, sac)) = two_token_project(&ctx);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &secondary.address, &300);
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&donator, &project.id, &secondary.address);
+
+    ctx.client
+        .remove_token(&project.creator, &project.id, &secondary.address);
+    assert_eq!(ctx.client.get_project(&project.id).accepted_tokens.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_remove_primary_token_rejected() {
+    let ctx = TestContext::new();
+    let (project, (primary, _), _) = two_token_project(&ctx);
+    ctx.client
+        .remove_token(&project.creator, &project.id, &primary.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_remove_token_requires_creator_or_admin() {
+    let ctx = TestContext::new();
+    let (project, _, (secondary, _)) = two_token_project(&ctx);
+    ctx.client
+        .remove_token(&ctx.oracle, &project.id, &secondary.address);
+}
+
+#[test]
+fn test_remove_token_prunes_reverse_index() {
+    let ctx = TestContext::new();
+    let (primary, _) = ctx.create_token();
+    let (secondary, _) = ctx.create_token();
+    let tokens = Vec::from_array(
+        &ctx.env,
+        [primary.address.clone(), secondary.address.clone()],
+    );
+    let first = ctx.register_project(&tokens, 1000, false);
+    let second = ctx.register_project(&tokens, 1000, false);
+    let third = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .remove_token(&first.creator, &first.id, &secondary.address);
+    // The last project takes the freed slot; no stale entry is left behind.
+    assert_eq!(
+        ctx.client.projects_accepting(&secondary.address, &0, &10),
+        Vec::from_array(&ctx.env, [third.id, second.id])
+    );
+    assert_eq!(
+        ctx.client.projects_accepting(&primary.address, &0, &10),
+        Vec::from_array(&ctx.env, [first.id, second.id, third.id])
+    );
+
+    ctx.client
+        .remove_token(&third.creator, &third.id, &secondary.address);
+    assert_eq!(
+        ctx.client.projects_accepting(&secondary.address, &0, &10),
+        Vec::from_array(&ctx.env, [second.id])
+    );
+}
@@ -0,0 +1,201 @@
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Vec};
+
+use crate::{test_utils::TestContext, Milestone, Project, ProjectStatus, Role};
+
+fn proof(ctx: &TestContext, byte: u8) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[byte; 32])
+}
+
+/// Register a project requiring delivery (0xd1) and quality (0xd2) proofs.
+fn setup_multi_proof(ctx: &TestContext) -> Project {
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let proofs = Vec::from_array(&ctx.env, [proof(ctx, 0xd1), proof(ctx, 0xd2)]);
+    ctx.client
+        .set_required_proofs(&ctx.manager, &project.id, &proofs);
+    project
+}
+
+/// Register a 2-of-2 project over oracles `a` and `b` requiring the same
+/// two proofs as `setup_multi_proof`.
+fn setup_multi_oracle_proofs(ctx: &TestContext, a: &Address, b: &Address) -> Project {
+    let (token, _) = ctx.create_token();
+    ctx.env.mock_all_auths();
+    ctx.client.grant_role(&ctx.admin, a, &Role::Oracle);
+    ctx.client.grant_role(&ctx.admin, b, &Role::Oracle);
+    let mut milestones = Vec::new(&ctx.env);
+    milestones.push_back(Milestone {
+        label: BytesN::from_array(&ctx.env, &[0u8; 32]),
+        amount_bps: 10000,
+        proof_hash: ctx.dummy_proof(),
+    });
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        &1000i128,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        &Vec::from_array(&ctx.env, [a.clone(), b.clone()]),
+        &2u32,
+    );
+    let proofs = Vec::from_array(&ctx.env, [proof(ctx, 0xd1), proof(ctx, 0xd2)]);
+    ctx.client
+        .set_required_proofs(&ctx.manager, &project.id, &proofs);
+    project
+}
+
+#[test]
+fn test_release_only_after_last_proof() {
+    let ctx = TestContext::new();
+    let project = setup_multi_proof(&ctx);
+
+    // Submitted out of order: quality before delivery.
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &proof(&ctx, 0xd2));
+    assert!(ctx.client.is_proof_matched(&project.id, &1));
+    assert!(!ctx.client.is_proof_matched(&project.id, &0));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &proof(&ctx, 0xd1));
+    let updated = ctx.client.get_project(&project.id);
+    assert_eq!(updated.status, ProjectStatus::Verified);
+    assert_eq!(updated.last_proof_time, ctx.env.ledger().timestamp());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #77)")]
+fn test_project_proof_hash_not_accepted_when_proofs_required() {
+    let ctx = TestContext::new();
+    let project = setup_multi_proof(&ctx);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_same_required_proof_twice_fails() {
+    let ctx = TestContext::new();
+    let project = setup_multi_proof(&ctx);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &proof(&ctx, 0xd1));
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &proof(&ctx, 0xd1));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_required_proofs_creator_or_admin_only() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let stranger = ctx.generate_address();
+    let proofs = Vec::from_array(&ctx.env, [proof(&ctx, 0xd1)]);
+    ctx.client
+        .set_required_proofs(&stranger, &project.id, &proofs);
+}
+
+#[test]
+fn test_single_proof_flow_unchanged_without_required_proofs() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+fn test_required_proofs_with_oracle_threshold() {
+    let ctx = TestContext::new();
+    let (a, b) = (ctx.generate_address(), ctx.generate_address());
+    let project = setup_multi_oracle_proofs(&ctx, &a, &b);
+
+    ctx.client
+        .verify_and_release(&a, &project.id, &proof(&ctx, 0xd1));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+    ctx.client
+        .verify_and_release(&b, &project.id, &proof(&ctx, 0xd2));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+fn test_matched_proof_counts_as_vote_of_another_oracle() {
+    let ctx = TestContext::new();
+    let (a, b) = (ctx.generate_address(), ctx.generate_address());
+    let project = setup_multi_oracle_proofs(&ctx, &a, &b);
+
+    // One oracle supplies every proof but is only one vote.
+    ctx.client
+        .verify_and_release(&a, &project.id, &proof(&ctx, 0xd1));
+    ctx.client
+        .verify_and_release(&a, &project.id, &proof(&ctx, 0xd2));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    ctx.client
+        .verify_and_release(&b, &project.id, &proof(&ctx, 0xd1));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_oracle_outside_authorized_set_cannot_match_proofs() {
+    let ctx = TestContext::new();
+    let (a, b) = (ctx.generate_address(), ctx.generate_address());
+    let project = setup_multi_oracle_proofs(&ctx, &a, &b);
+
+    // ctx.oracle holds the Oracle role but is not one of the project's oracles.
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &proof(&ctx, 0xd1));
+}
+
+#[test]
+fn test_replacing_required_proofs_forgets_matches() {
+    let ctx = TestContext::new();
+    let project = setup_multi_proof(&ctx);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &proof(&ctx, 0xd1));
+    assert!(ctx.client.is_proof_matched(&project.id, &0));
+
+    let proofs = Vec::from_array(&ctx.env, [proof(&ctx, 0xe1), proof(&ctx, 0xe2)]);
+    ctx.client
+        .set_required_proofs(&ctx.manager, &project.id, &proofs);
+    assert!(!ctx.client.is_proof_matched(&project.id, &0));
+
+    // The first new proof alone does not verify.
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &proof(&ctx, 0xe2));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+}
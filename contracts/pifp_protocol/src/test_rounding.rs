@@ -0,0 +1,94 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, RoundingMode};
+
+/// Release 1030 units with a 2.5% fee and a 1% oracle reward under `mode`
+/// and return (treasury, oracle, creator).
+fn release_uneven(mode: Option<RoundingMode>) -> (i128, i128, i128) {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    if let Some(mode) = mode {
+        ctx.client.set_rounding_mode(&ctx.admin, &mode);
+    }
+    ctx.client.set_oracle_reward_bps(&ctx.admin, &100);
+    let fee_recipient = ctx.generate_address();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &fee_recipient, &250);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1030);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1030);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    let split = (
+        ctx.client.get_treasury_balance(&token.address),
+        token.balance(&ctx.oracle),
+        token.balance(&ctx.manager),
+    );
+    // Nothing is stranded in the contract beyond the accrued fee.
+    assert_eq!(token.balance(&ctx.client.address), split.0);
+    assert_eq!(split.0 + split.1 + split.2, 1030);
+    split
+}
+
+#[test]
+fn test_default_rounds_down() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_rounding_mode(), RoundingMode::Down);
+    // Fee 25.75 -> 25, reward 10.3 -> 10: existing deployments keep
+    // truncating.
+    assert_eq!(release_uneven(None), (25, 10, 995));
+}
+
+#[test]
+fn test_round_to_nearest() {
+    // Fee 25.75 -> 26, reward 10.3 -> 10.
+    assert_eq!(
+        release_uneven(Some(RoundingMode::NearestWithRemainderToCreator)),
+        (26, 10, 994)
+    );
+}
+
+#[test]
+fn test_round_up_takes_remainder_as_cuts() {
+    assert_eq!(release_uneven(Some(RoundingMode::Up)), (26, 11, 993));
+}
+
+#[test]
+fn test_exact_half_goes_to_creator() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_rounding_mode(&ctx.admin, &RoundingMode::NearestWithRemainderToCreator);
+    let fee_recipient = ctx.generate_address();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &fee_recipient, &250);
+
+    // 2.5% of 1020 is 25.5: the half stays with the creator.
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1020);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1020);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 25);
+    assert_eq!(token.balance(&ctx.manager), 995);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_rounding_mode_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_rounding_mode(&ctx.manager, &RoundingMode::Up);
+}
@@ -0,0 +1,75 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_created_at_recorded() {
+    let ctx = TestContext::new();
+    let now = ctx.env.ledger().timestamp();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(project.created_at, now);
+    assert_eq!(ctx.client.get_project(&project.id).created_at, now);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #56)")]
+fn test_self_deposit_within_delay_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_self_deposit_delay(&ctx.admin, &3600);
+
+    sac.mint(&ctx.manager, &100);
+    ctx.jump_time(3599);
+    ctx.client
+        .deposit(&project.id, &ctx.manager, &token.address, &100);
+}
+
+#[test]
+fn test_self_deposit_after_delay_allowed() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_self_deposit_delay(&ctx.admin, &3600);
+    assert_eq!(ctx.client.get_self_deposit_delay(), 3600);
+
+    sac.mint(&ctx.manager, &100);
+    ctx.jump_time(3600);
+    ctx.client
+        .deposit(&project.id, &ctx.manager, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_third_party_deposit_unaffected_by_delay() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_self_deposit_delay(&ctx.admin, &3600);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_self_deposit_allowed_by_default() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    sac.mint(&ctx.manager, &100);
+    ctx.client
+        .deposit(&project.id, &ctx.manager, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_self_deposit_delay_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_self_deposit_delay(&ctx.manager, &3600);
+}
@@ -0,0 +1,268 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::Events,
+    xdr::{ContractEventBody, FromXdr, ScVal},
+    Address, Bytes, BytesN, IntoVal, TryFromVal, Val, Vec,
+};
+
+use crate::{
+    events::VerificationChallenged, test_utils::TestContext, Milestone, PifpProtocol,
+    PifpProtocolClient, ProjectStatus,
+};
+
+fn oracle_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+fn signed_message(ctx: &TestContext, key: &SigningKey, project_id: u64) -> (Bytes, BytesN<64>) {
+    let prefix = ctx.client.get_signed_message_prefix(&project_id);
+    sign_with_prefix(ctx, key, &prefix)
+}
+
+fn sign_with_prefix(ctx: &TestContext, key: &SigningKey, prefix: &Bytes) -> (Bytes, BytesN<64>) {
+    let mut raw: std::vec::Vec<u8> = prefix.iter().collect();
+    raw.extend_from_slice(b"proof-of-impact");
+    let signature = key.sign(&raw).to_bytes();
+    (
+        Bytes::from_slice(&ctx.env, &raw),
+        BytesN::from_array(&ctx.env, &signature),
+    )
+}
+
+/// The Stellar account of `key`, recorded as the verifier.
+fn signing_account(ctx: &TestContext, key: &SigningKey) -> Address {
+    let mut xdr = Bytes::from_array(&ctx.env, &[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+    xdr.extend_from_array(&key.verifying_key().to_bytes());
+    Address::from_xdr(&ctx.env, &xdr).unwrap()
+}
+
+fn register_pubkey(ctx: &TestContext, key: &SigningKey) {
+    let pubkey = BytesN::from_array(&ctx.env, &key.verifying_key().to_bytes());
+    ctx.client.set_oracle_pubkey(&ctx.admin, &pubkey);
+}
+
+#[test]
+fn test_signed_verification_marks_project_verified() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    let (message, signature) = signed_message(&ctx, &key, project.id);
+
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+
+    let updated = ctx.client.get_project(&project.id);
+    assert_eq!(updated.status, ProjectStatus::Verified);
+    assert!(updated.last_proof_time > 0);
+}
+
+#[test]
+#[should_panic]
+fn test_signed_verification_rejects_tampered_message() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    let (mut message, signature) = signed_message(&ctx, &key, project.id);
+    message.push_back(0xff);
+
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+}
+
+#[test]
+#[should_panic]
+fn test_signed_verification_rejects_foreign_key() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    register_pubkey(&ctx, &oracle_key());
+    let impostor = SigningKey::from_bytes(&[9u8; 32]);
+    let (message, signature) = signed_message(&ctx, &impostor, project.id);
+
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_signed_verification_rejects_other_project_message() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    let (message, signature) = signed_message(&ctx, &key, project.id + 1);
+
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_signed_verification_rejects_other_contract_message() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    let other = PifpProtocolClient::new(&ctx.env, &ctx.env.register(PifpProtocol, ()));
+    let prefix = other.get_signed_message_prefix(&project.id);
+    let (message, signature) = sign_with_prefix(&ctx, &key, &prefix);
+
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_signed_verification_cannot_be_replayed_after_challenge() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    let (message, signature) = signed_message(&ctx, &key, project.id);
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+    ctx.client.challenge_verification(&ctx.admin, &project.id);
+
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_signed_verification_rejects_project_with_oracle_set() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.env.mock_all_auths();
+    let mut milestones = Vec::new(&ctx.env);
+    milestones.push_back(Milestone {
+        label: BytesN::from_array(&ctx.env, &[0u8; 32]),
+        amount_bps: 10000,
+        proof_hash: ctx.dummy_proof(),
+    });
+    // A 2-of-2 project must not be verifiable by the single global key.
+    let oracles = Vec::from_array(&ctx.env, [ctx.generate_address(), ctx.generate_address()]);
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        &1000i128,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &milestones,
+        &0u32,
+        &oracles,
+        &2u32,
+    );
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    let (message, signature) = signed_message(&ctx, &key, project.id);
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+}
+
+#[test]
+fn test_signed_verification_records_signing_account() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    let (message, signature) = signed_message(&ctx, &key, project.id);
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+    let verified_at = ctx.env.ledger().timestamp();
+    ctx.client.challenge_verification(&ctx.admin, &project.id);
+
+    // The challenge event names the recorded verifier.
+    let account = signing_account(&ctx, &key);
+    let expected: Val = VerificationChallenged {
+        project_id: project.id,
+        auditor: ctx.admin.clone(),
+        oracle: Some(account),
+        verified_at,
+    }
+    .into_val(&ctx.env);
+    let events = ctx
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&ctx.client.address);
+    let ContractEventBody::V0(body) = events.events().last().unwrap().clone().body;
+    assert_eq!(body.data, ScVal::try_from_val(&ctx.env, &expected).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #78)")]
+fn test_signed_verification_below_consensus_threshold_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    ctx.client.set_consensus_threshold(&ctx.admin, &2);
+    let (message, signature) = signed_message(&ctx, &key, project.id);
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+}
+
+#[test]
+fn test_signed_verification_uses_signing_account_weight() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let key = oracle_key();
+    register_pubkey(&ctx, &key);
+    ctx.client.set_consensus_threshold(&ctx.admin, &2);
+    ctx.client
+        .set_oracle_weight(&ctx.admin, &signing_account(&ctx, &key), &2);
+    let (message, signature) = signed_message(&ctx, &key, project.id);
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #37)")]
+fn test_signed_verification_requires_pubkey() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let (message, signature) = signed_message(&ctx, &oracle_key(), project.id);
+    ctx.client
+        .verify_and_release_signed(&project.id, &signature, &message);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_oracle_pubkey_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let stranger = ctx.generate_address();
+    let pubkey = BytesN::from_array(&ctx.env, &oracle_key().verifying_key().to_bytes());
+
+    ctx.client.set_oracle_pubkey(&stranger, &pubkey);
+}
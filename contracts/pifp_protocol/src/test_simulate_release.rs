@@ -0,0 +1,88 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, PayoutLine, RoundingMode};
+
+#[test]
+fn test_simulate_release_matches_claim() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_rounding_mode(&ctx.admin, &RoundingMode::Up);
+    ctx.client.set_oracle_reward_bps(&ctx.admin, &100);
+    let fee_recipient = ctx.generate_address();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &fee_recipient, &250);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1030);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1030);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let lines = ctx.client.simulate_release(&project.id);
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines.get(0).unwrap(),
+        PayoutLine {
+            recipient: ctx.client.address.clone(),
+            token: token.address.clone(),
+            amount: 26,
+        }
+    );
+    assert_eq!(
+        lines.get(1).unwrap(),
+        PayoutLine {
+            recipient: ctx.oracle.clone(),
+            token: token.address.clone(),
+            amount: 11,
+        }
+    );
+    assert_eq!(
+        lines.get(2).unwrap(),
+        PayoutLine {
+            recipient: ctx.manager.clone(),
+            token: token.address.clone(),
+            amount: 993,
+        }
+    );
+    // The dry run moved nothing.
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1030);
+
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 26);
+    assert_eq!(token.balance(&ctx.oracle), 11);
+    assert_eq!(token.balance(&ctx.manager), 993);
+}
+
+#[test]
+fn test_simulate_release_before_verification_has_no_reward() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_oracle_reward_bps(&ctx.admin, &100);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    let lines = ctx.client.simulate_release(&project.id);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(
+        lines.get(0).unwrap(),
+        PayoutLine {
+            recipient: ctx.manager.clone(),
+            token: token.address.clone(),
+            amount: 500,
+        }
+    );
+}
+
+#[test]
+fn test_simulate_release_empty_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.simulate_release(&project.id).len(), 0);
+}
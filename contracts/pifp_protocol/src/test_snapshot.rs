@@ -0,0 +1,52 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_snapshots_capture_balances_at_each_point() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let first = ctx.client.take_snapshot(&project.id);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &250);
+    ctx.client.deposit(&project.id, &donator, &token.address, &250);
+    let second = ctx.client.take_snapshot(&project.id);
+    assert_eq!((first, second), (0, 1));
+
+    let before = ctx.client.get_snapshot(&project.id, &first).unwrap();
+    let after = ctx.client.get_snapshot(&project.id, &second).unwrap();
+    assert_eq!(before.project_id, project.id);
+    assert_eq!(before.balances.get(0).unwrap().balance, 0);
+    assert_eq!(after.balances.get(0).unwrap().balance, 250);
+    assert_ne!(before.balances, after.balances);
+}
+
+#[test]
+fn test_get_snapshot_missing_index() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert!(ctx.client.get_snapshot(&project.id, &0).is_none());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #52)")]
+fn test_snapshots_are_bounded() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    for _ in 0..33 {
+        ctx.client.take_snapshot(&project.id);
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_snapshot_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.take_snapshot(&7);
+}
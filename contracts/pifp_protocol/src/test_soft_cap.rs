@@ -0,0 +1,83 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_below_soft_cap_stays_refundable() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_soft_cap(&ctx.manager, &project.id, &500);
+    assert_eq!(ctx.client.get_project(&project.id).soft_cap, 500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Expired
+    );
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 400);
+}
+
+#[test]
+fn test_above_soft_cap_releases_to_creator() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_soft_cap(&ctx.manager, &project.id, &500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    // No refund window to wait out: the creator reclaims straight away.
+    ctx.client.reclaim_expired_funds(&ctx.manager, &project.id);
+    assert_eq!(token.balance(&ctx.manager), 600);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #76)")]
+fn test_above_soft_cap_refund_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_soft_cap(&ctx.manager, &project.id, &500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    ctx.jump_time(86_400);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&donator, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_soft_cap_above_goal_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client.set_soft_cap(&ctx.manager, &project.id, &1001);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_soft_cap_requires_creator_or_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    let stranger = ctx.generate_address();
+    ctx.client.set_soft_cap(&stranger, &project.id, &500);
+}
@@ -0,0 +1,93 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_split_deposit_credits_each_project() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let first = ctx.register_project(&tokens, 500, false);
+    let second = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    let allocations = Vec::from_array(&ctx.env, [(first.id, 600i128), (second.id, 400i128)]);
+    ctx.client
+        .deposit_split(&donator, &token.address, &allocations);
+
+    assert_eq!(ctx.client.get_balance(&first.id, &token.address), 600);
+    assert_eq!(ctx.client.get_balance(&second.id, &token.address), 400);
+    assert_eq!(token.balance(&donator), 0);
+    assert_eq!(token.balance(&ctx.client.address), 1_000);
+
+    assert_eq!(
+        ctx.client.get_project(&first.id).status,
+        ProjectStatus::Active
+    );
+    assert_eq!(
+        ctx.client.get_project(&second.id).status,
+        ProjectStatus::Funding
+    );
+}
+
+#[test]
+fn test_split_deposit_rolls_back_on_invalid_project() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let (other_token, _) = ctx.create_token();
+    let good = ctx.register_project(
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        1000,
+        false,
+    );
+    let wrong_token = ctx.register_project(
+        &Vec::from_array(&ctx.env, [other_token.address.clone()]),
+        1000,
+        false,
+    );
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    let allocations = Vec::from_array(&ctx.env, [(good.id, 300i128), (wrong_token.id, 300i128)]);
+    let result = ctx
+        .client
+        .try_deposit_split(&donator, &token.address, &allocations);
+
+    assert!(result.is_err());
+    assert_eq!(ctx.client.get_balance(&good.id, &token.address), 0);
+    assert_eq!(token.balance(&donator), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #39)")]
+fn test_split_deposit_caps_allocations() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    let mut allocations = Vec::new(&ctx.env);
+    for _ in 0..11 {
+        allocations.push_back((project.id, 10i128));
+    }
+    ctx.client
+        .deposit_split(&donator, &token.address, &allocations);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_split_deposit_rejects_empty_allocations() {
+    let ctx = TestContext::new();
+    let (_, token, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    ctx.client
+        .deposit_split(&donator, &token.address, &Vec::new(&ctx.env));
+}
@@ -0,0 +1,161 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::Events,
+    xdr::{ContractEventBody, ScVal},
+    IntoVal, TryFromVal, Val,
+};
+
+use crate::{
+    events::{GoalReachedWithDeadline, StatusChanged},
+    test_utils::TestContext,
+    ProjectStatus,
+};
+
+/// Whether the last invocation emitted `status` for `old -> new`.
+fn emitted_status_change(
+    ctx: &TestContext,
+    project_id: u64,
+    old_status: ProjectStatus,
+    new_status: ProjectStatus,
+) -> bool {
+    let expected: Val = StatusChanged {
+        project_id,
+        old_status,
+        new_status,
+    }
+    .into_val(&ctx.env);
+    let expected = ScVal::try_from_val(&ctx.env, &expected).unwrap();
+    ctx.env
+        .events()
+        .all()
+        .filter_by_contract(&ctx.client.address)
+        .events()
+        .iter()
+        .any(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            body.data == expected
+        })
+}
+
+#[test]
+fn test_status_event_on_goal_reached() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+    assert!(!emitted_status_change(
+        &ctx,
+        project.id,
+        ProjectStatus::Funding,
+        ProjectStatus::Active
+    ));
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+    assert!(emitted_status_change(
+        &ctx,
+        project.id,
+        ProjectStatus::Funding,
+        ProjectStatus::Active
+    ));
+}
+
+#[test]
+fn test_status_events_on_verify_and_claim() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert!(emitted_status_change(
+        &ctx,
+        project.id,
+        ProjectStatus::Active,
+        ProjectStatus::Verified
+    ));
+
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+    assert!(emitted_status_change(
+        &ctx,
+        project.id,
+        ProjectStatus::Verified,
+        ProjectStatus::Completed
+    ));
+}
+
+#[test]
+fn test_status_event_on_expire() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.jump_time(86_401);
+    ctx.client.expire_project(&project.id);
+    assert!(emitted_status_change(
+        &ctx,
+        project.id,
+        ProjectStatus::Funding,
+        ProjectStatus::Expired
+    ));
+}
+
+#[test]
+fn test_status_event_on_cancel() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    assert!(emitted_status_change(
+        &ctx,
+        project.id,
+        ProjectStatus::Active,
+        ProjectStatus::Cancelled
+    ));
+}
+
+#[test]
+fn test_goal_reached_event_carries_remaining_time() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.jump_time(6_400);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    let expected: Val = GoalReachedWithDeadline {
+        project_id: project.id,
+        deadline: project.deadline,
+        remaining: project.deadline - ctx.env.ledger().timestamp(),
+    }
+    .into_val(&ctx.env);
+    let expected = ScVal::try_from_val(&ctx.env, &expected).unwrap();
+    let emitted = ctx
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&ctx.client.address)
+        .events()
+        .iter()
+        .any(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            body.data == expected
+        });
+    assert!(emitted);
+    assert_eq!(project.deadline - ctx.env.ledger().timestamp(), 80_000);
+}
@@ -0,0 +1,144 @@
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, Env};
+
+use crate::test_utils::TestContext;
+
+/// Pays out `amount_in * rate / 100` of `to_token` from its own balance,
+/// ignoring `min_out` so the protocol's own slippage check is exercised.
+#[contract]
+struct MockRouter;
+
+#[contractimpl]
+impl MockRouter {
+    pub fn set_rate(env: Env, rate: i128) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate);
+    }
+
+    pub fn swap(
+        env: Env,
+        _from_token: Address,
+        to_token: Address,
+        amount_in: i128,
+        _min_out: i128,
+        to: Address,
+    ) -> i128 {
+        let rate: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("rate"))
+            .unwrap();
+        let out = amount_in * rate / 100;
+        token::Client::new(&env, &to_token).transfer(&env.current_contract_address(), &to, &out);
+        out
+    }
+}
+
+/// Project accepting token B, a funded router swapping A -> B at `rate`%,
+/// and a donor holding 1000 of token A.
+fn setup(
+    ctx: &TestContext,
+    rate: i128,
+) -> (
+    u64,
+    token::Client<'static>,
+    token::Client<'static>,
+    Address,
+    Address,
+) {
+    let (project, token_b, sac_b) = ctx.setup_project(10_000);
+    let (token_a, sac_a) = ctx.create_token();
+    ctx.env.mock_all_auths();
+
+    let router = ctx.env.register(MockRouter, ());
+    MockRouterClient::new(&ctx.env, &router).set_rate(&rate);
+    sac_b.mint(&router, &100_000);
+    ctx.client.set_swap_router(&ctx.admin, &router);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    (project.id, token_a, token_b, router, donator)
+}
+
+#[test]
+fn test_deposit_and_swap_credits_swapped_amount() {
+    let ctx = TestContext::new();
+    let (project_id, token_a, token_b, router, donator) = setup(&ctx, 50);
+
+    ctx.client.deposit_and_swap(
+        &donator,
+        &project_id,
+        &token_a.address,
+        &1000,
+        &token_b.address,
+        &450,
+    );
+
+    assert_eq!(token_a.balance(&donator), 0);
+    assert_eq!(token_a.balance(&router), 1000);
+    assert_eq!(ctx.client.get_balance(&project_id, &token_b.address), 500);
+    assert_eq!(ctx.client.get_balance(&project_id, &token_a.address), 0);
+    assert_eq!(ctx.client.get_swap_router(), Some(router));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #59)")]
+fn test_deposit_and_swap_rejects_slippage() {
+    let ctx = TestContext::new();
+    let (project_id, token_a, token_b, _, donator) = setup(&ctx, 40);
+
+    ctx.client.deposit_and_swap(
+        &donator,
+        &project_id,
+        &token_a.address,
+        &1000,
+        &token_b.address,
+        &450,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_deposit_and_swap_rejects_unaccepted_target() {
+    let ctx = TestContext::new();
+    let (project_id, token_a, _, _, donator) = setup(&ctx, 50);
+    let (other, _) = ctx.create_token();
+
+    ctx.client.deposit_and_swap(
+        &donator,
+        &project_id,
+        &token_a.address,
+        &1000,
+        &other.address,
+        &1,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_deposit_and_swap_requires_router() {
+    let ctx = TestContext::new();
+    let (project, token_b, _) = ctx.setup_project(10_000);
+    let (token_a, sac_a) = ctx.create_token();
+    ctx.env.mock_all_auths();
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+
+    ctx.client.deposit_and_swap(
+        &donator,
+        &project.id,
+        &token_a.address,
+        &1000,
+        &token_b.address,
+        &1,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_swap_router_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let router = ctx.generate_address();
+    ctx.client.set_swap_router(&ctx.manager, &router);
+}
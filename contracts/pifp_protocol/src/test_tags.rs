@@ -0,0 +1,169 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, vec, Symbol, Vec};
+
+use crate::{test_utils::TestContext, Visibility};
+
+#[test]
+fn test_projects_queryable_by_each_tag() {
+    let ctx = TestContext::new();
+    let (water, token, _) = ctx.setup_project(1000);
+    let tokens = vec![&ctx.env, token.address.clone()];
+    let school = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+
+    let (wash, kenya, edu) = (
+        symbol_short!("wash"),
+        symbol_short!("kenya"),
+        symbol_short!("edu"),
+    );
+    ctx.client.set_tags(
+        &ctx.manager,
+        &water.id,
+        &vec![&ctx.env, wash.clone(), kenya.clone()],
+    );
+    ctx.client.set_tags(
+        &ctx.manager,
+        &school.id,
+        &vec![&ctx.env, edu.clone(), kenya.clone()],
+    );
+
+    assert_eq!(
+        ctx.client.get_project(&water.id).tags,
+        vec![&ctx.env, wash.clone(), kenya.clone()]
+    );
+    assert_eq!(
+        ctx.client.projects_by_tag(&wash, &0, &10),
+        vec![&ctx.env, water.id]
+    );
+    assert_eq!(
+        ctx.client.projects_by_tag(&edu, &0, &10),
+        vec![&ctx.env, school.id]
+    );
+    assert_eq!(
+        ctx.client.projects_by_tag(&kenya, &0, &10),
+        vec![&ctx.env, water.id, school.id]
+    );
+    assert_eq!(
+        ctx.client.projects_by_tag(&kenya, &1, &10),
+        vec![&ctx.env, school.id]
+    );
+    assert!(ctx
+        .client
+        .projects_by_tag(&symbol_short!("health"), &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_retagging_updates_index() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let (old, new) = (symbol_short!("old"), symbol_short!("new"));
+    ctx.client
+        .set_tags(&ctx.manager, &project.id, &vec![&ctx.env, old.clone()]);
+    ctx.client
+        .set_tags(&ctx.manager, &project.id, &vec![&ctx.env, new.clone()]);
+    assert!(ctx.client.projects_by_tag(&old, &0, &10).is_empty());
+    assert_eq!(
+        ctx.client.projects_by_tag(&new, &0, &10),
+        vec![&ctx.env, project.id]
+    );
+
+    ctx.client
+        .set_tags(&ctx.manager, &project.id, &Vec::new(&ctx.env));
+    assert!(ctx.client.projects_by_tag(&new, &0, &10).is_empty());
+}
+
+#[test]
+fn test_unlisted_projects_hidden_from_tag_query() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let tag = symbol_short!("wash");
+    ctx.client
+        .set_tags(&ctx.manager, &project.id, &vec![&ctx.env, tag.clone()]);
+    ctx.client
+        .set_visibility(&ctx.manager, &project.id, &Visibility::Unlisted);
+    assert!(ctx.client.projects_by_tag(&tag, &0, &10).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #74)")]
+fn test_set_tags_rejects_more_than_max() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let tags = vec![
+        &ctx.env,
+        symbol_short!("a"),
+        symbol_short!("b"),
+        symbol_short!("c"),
+        symbol_short!("d"),
+        symbol_short!("e"),
+        symbol_short!("f"),
+    ];
+    ctx.client.set_tags(&ctx.manager, &project.id, &tags);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #74)")]
+fn test_set_tags_rejects_long_tag() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let tag = Symbol::new(&ctx.env, "sanitation");
+    ctx.client
+        .set_tags(&ctx.manager, &project.id, &vec![&ctx.env, tag]);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #74)")]
+fn test_set_tags_rejects_duplicates() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let tag = symbol_short!("wash");
+    ctx.client
+        .set_tags(&ctx.manager, &project.id, &vec![&ctx.env, tag.clone(), tag]);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_tags_requires_creator_or_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let stranger = ctx.generate_address();
+    ctx.client.set_tags(
+        &stranger,
+        &project.id,
+        &vec![&ctx.env, symbol_short!("wash")],
+    );
+}
+
+#[test]
+fn test_clone_carries_tags_into_index() {
+    let ctx = TestContext::new();
+    let (template, _, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let tag = symbol_short!("wash");
+    ctx.client
+        .set_tags(&ctx.manager, &template.id, &vec![&ctx.env, tag.clone()]);
+    let deadline = ctx.env.ledger().timestamp() + 172_800;
+    let clone = ctx
+        .client
+        .clone_project(&ctx.manager, &template.id, &deadline, &ctx.dummy_proof());
+    assert_eq!(clone.tags, vec![&ctx.env, tag.clone()]);
+    assert_eq!(
+        ctx.client.projects_by_tag(&tag, &0, &10),
+        vec![&ctx.env, template.id, clone.id]
+    );
+}
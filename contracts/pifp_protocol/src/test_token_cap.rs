@@ -0,0 +1,65 @@
+extern crate std;
+
+use soroban_sdk::{Address, Vec};
+
+use crate::{test_utils::TestContext, DEFAULT_MAX_TOKENS};
+
+fn tokens(ctx: &TestContext, count: u32) -> Vec<Address> {
+    let mut tokens = Vec::new(&ctx.env);
+    for _ in 0..count {
+        tokens.push_back(ctx.generate_address());
+    }
+    tokens
+}
+
+#[test]
+fn test_max_tokens_defaults_to_ten() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_max_tokens_per_project(), DEFAULT_MAX_TOKENS);
+    let project = ctx.register_project(&tokens(&ctx, DEFAULT_MAX_TOKENS), 1000, false);
+    assert_eq!(project.accepted_tokens.len(), DEFAULT_MAX_TOKENS);
+}
+
+#[test]
+fn test_raised_cap_allows_more_tokens() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_tokens_per_project(&ctx.admin, &20);
+    assert_eq!(ctx.client.get_max_tokens_per_project(), 20);
+
+    let project = ctx.register_project(&tokens(&ctx, 15), 1000, false);
+    assert_eq!(project.accepted_tokens.len(), 15);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_lowered_cap_rejects_registration() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_tokens_per_project(&ctx.admin, &3);
+    ctx.register_project(&tokens(&ctx, 4), 1000, false);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_cap_above_ceiling_rejected() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_tokens_per_project(&ctx.admin, &51);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_zero_cap_rejected() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_tokens_per_project(&ctx.admin, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_max_tokens_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.client.set_max_tokens_per_project(&ctx.manager, &20);
+}
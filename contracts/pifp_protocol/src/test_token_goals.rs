@@ -0,0 +1,153 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short, testutils::Events, xdr::ScVal, Address, IntoVal, TryFromVal, Val, Vec,
+};
+
+use crate::{events::FundingProgress, test_utils::TestContext};
+
+fn ready_events(ctx: &TestContext) -> u32 {
+    let topic = ScVal::try_from_val(&ctx.env, &symbol_short!("ready_ver")).unwrap();
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    events
+        .events()
+        .iter()
+        .filter(|e| {
+            let soroban_sdk::xdr::ContractEventBody::V0(body) = &e.body;
+            body.topics.first() == Some(&topic)
+        })
+        .count() as u32
+}
+
+#[test]
+fn test_ready_once_last_token_goal_met() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_token_goal(&project.creator, &project.id, &token_b.address, &500);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    sac_b.mint(&donator, &600);
+
+    // `events().all()` only covers the latest invocation, so check events
+    // before making the next call.
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &1000);
+    assert_eq!(ready_events(&ctx), 0);
+    assert!(!ctx.client.is_goal_met(&project.id));
+
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &499);
+    assert_eq!(ready_events(&ctx), 0);
+    assert!(!ctx.client.is_goal_met(&project.id));
+
+    ctx.client.deposit(&project.id, &donator, &token_b.address, &1);
+    assert_eq!(ready_events(&ctx), 1);
+    assert!(ctx.client.is_goal_met(&project.id));
+
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &100);
+    assert_eq!(ready_events(&ctx), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_token_goal_rejects_primary_token() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_token_goal(&project.creator, &project.id, &token.address, &500);
+}
+
+#[test]
+fn test_goal_status_records_time_goal_was_reached() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &400);
+    assert_eq!(ctx.client.goal_status(&project.id), (false, 0));
+
+    ctx.jump_time(3_600);
+    let reached_at = ctx.env.ledger().timestamp();
+    ctx.client.deposit(&project.id, &donator, &token.address, &600);
+    assert_eq!(ctx.client.goal_status(&project.id), (true, reached_at));
+
+    ctx.jump_time(3_600);
+    assert_eq!(ctx.client.goal_status(&project.id), (true, reached_at));
+}
+
+/// Whether the last invocation emitted exactly `expected` as a progress event.
+fn emitted_progress(ctx: &TestContext, expected: FundingProgress) -> bool {
+    let expected: Val = expected.into_val(&ctx.env);
+    let expected = ScVal::try_from_val(&ctx.env, &expected).unwrap();
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    events.events().iter().any(|e| {
+        let soroban_sdk::xdr::ContractEventBody::V0(body) = &e.body;
+        body.data == expected
+    })
+}
+
+#[test]
+fn test_deposit_emits_funding_progress_per_token() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_token_goal(&project.creator, &project.id, &token_b.address, &400);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    sac_b.mint(&donator, &500);
+    let progress = |token: &Address, balance: i128, goal: i128, pct: u32| {
+        FundingProgress {
+            project_id: project.id,
+            token: token.clone(),
+            balance,
+            goal,
+            pct,
+        }
+    };
+
+    ctx.client.deposit(&project.id, &donator, &token_a.address, &250);
+    assert!(emitted_progress(&ctx, progress(&token_a.address, 250, 1000, 2500)));
+    ctx.client.deposit(&project.id, &donator, &token_a.address, &500);
+    assert!(emitted_progress(&ctx, progress(&token_a.address, 750, 1000, 7500)));
+
+    ctx.client.deposit(&project.id, &donator, &token_b.address, &100);
+    assert!(emitted_progress(&ctx, progress(&token_b.address, 100, 400, 2500)));
+    // Overfunding reports more than 100%.
+    ctx.client.deposit(&project.id, &donator, &token_b.address, &400);
+    assert!(emitted_progress(&ctx, progress(&token_b.address, 500, 400, 12500)));
+}
+
+#[test]
+fn test_no_funding_progress_without_token_goal() {
+    let ctx = TestContext::new();
+    let (token_a, _) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+
+    let donator = ctx.generate_address();
+    sac_b.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token_b.address, &100);
+    let topic = ScVal::try_from_val(&ctx.env, &symbol_short!("progress")).unwrap();
+    let events = ctx.env.events().all().filter_by_contract(&ctx.client.address);
+    assert!(!events.events().iter().any(|e| {
+        let soroban_sdk::xdr::ContractEventBody::V0(body) = &e.body;
+        body.topics.first() == Some(&topic)
+    }));
+}
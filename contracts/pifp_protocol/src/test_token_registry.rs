@@ -0,0 +1,69 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_gate_off_accepts_unapproved_tokens() {
+    let ctx = TestContext::new();
+    assert!(!ctx.client.is_token_gate_enabled());
+
+    let (token, _) = ctx.create_token();
+    assert!(!ctx.client.is_token_approved(&token.address));
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000, false);
+    assert_eq!(project.accepted_tokens, tokens);
+}
+
+#[test]
+fn test_gate_on_accepts_approved_tokens() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let (token, _) = ctx.create_token();
+    ctx.client.approve_token(&ctx.admin, &token.address);
+    ctx.client.set_token_gate(&ctx.admin, &true);
+    assert!(ctx.client.is_token_approved(&token.address));
+
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000, false);
+    assert_eq!(project.accepted_tokens, tokens);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #51)")]
+fn test_gate_on_rejects_unapproved_token() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let (approved, _) = ctx.create_token();
+    let (stranger, _) = ctx.create_token();
+    ctx.client.approve_token(&ctx.admin, &approved.address);
+    ctx.client.set_token_gate(&ctx.admin, &true);
+
+    let tokens = Vec::from_array(&ctx.env, [approved.address.clone(), stranger.address.clone()]);
+    ctx.register_project(&tokens, 1000, false);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #51)")]
+fn test_unapproved_token_rejected_under_gate() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let (token, _) = ctx.create_token();
+    ctx.client.approve_token(&ctx.admin, &token.address);
+    ctx.client.unapprove_token(&ctx.admin, &token.address);
+    assert!(!ctx.client.is_token_approved(&token.address));
+    ctx.client.set_token_gate(&ctx.admin, &true);
+
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    ctx.register_project(&tokens, 1000, false);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_approve_token_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let (token, _) = ctx.create_token();
+    ctx.client.approve_token(&ctx.manager, &token.address);
+}
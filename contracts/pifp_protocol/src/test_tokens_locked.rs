@@ -0,0 +1,78 @@
+extern crate std;
+
+use soroban_sdk::{Address, Vec};
+
+use crate::{test_utils::TestContext, Project};
+
+/// Register a project accepting two tokens and return it with both addresses.
+fn two_token_project(ctx: &TestContext) -> (Project, Address, Address) {
+    let (primary, _) = ctx.create_token();
+    let (secondary, _) = ctx.create_token();
+    let tokens = Vec::from_array(
+        &ctx.env,
+        [primary.address.clone(), secondary.address.clone()],
+    );
+    let project = ctx.register_project(&tokens, 1000, false);
+    ctx.env.mock_all_auths();
+    (project, primary.address, secondary.address)
+}
+
+#[test]
+fn test_tokens_unlocked_by_default() {
+    let ctx = TestContext::new();
+    let (project, _, secondary) = two_token_project(&ctx);
+    assert!(!project.tokens_locked);
+
+    ctx.client
+        .remove_token(&ctx.manager, &project.id, &secondary);
+}
+
+#[test]
+fn test_lock_tokens_sets_flag() {
+    let ctx = TestContext::new();
+    let (project, _, _) = two_token_project(&ctx);
+    ctx.client.lock_tokens(&ctx.manager, &project.id);
+    assert!(ctx.client.get_project(&project.id).tokens_locked);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #79)")]
+fn test_locked_project_rejects_remove_by_creator() {
+    let ctx = TestContext::new();
+    let (project, _, secondary) = two_token_project(&ctx);
+    ctx.client.lock_tokens(&ctx.manager, &project.id);
+
+    ctx.client
+        .remove_token(&ctx.manager, &project.id, &secondary);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #79)")]
+fn test_locked_project_rejects_remove_by_admin() {
+    let ctx = TestContext::new();
+    let (project, _, secondary) = two_token_project(&ctx);
+    ctx.client.lock_tokens(&ctx.manager, &project.id);
+
+    ctx.client.remove_token(&ctx.admin, &project.id, &secondary);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #79)")]
+fn test_locked_project_rejects_migration() {
+    let ctx = TestContext::new();
+    let (project, _, secondary) = two_token_project(&ctx);
+    ctx.client.lock_tokens(&ctx.manager, &project.id);
+
+    let (new, _) = ctx.create_token();
+    ctx.client
+        .migrate_token(&ctx.admin, &project.id, &secondary, &new.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_lock_tokens_requires_creator_or_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = two_token_project(&ctx);
+    let stranger = ctx.generate_address();
+    ctx.client.lock_tokens(&stranger, &project.id);
+}
@@ -0,0 +1,55 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_fees_accrue_in_treasury_until_withdrawn() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+
+    let fee_recipient = ctx.generate_address();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &fee_recipient, &500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(86_400);
+    ctx.client.claim_funds(&project.id);
+
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 50);
+    assert_eq!(token.balance(&fee_recipient), 0);
+    assert_eq!(token.balance(&ctx.client.address), 50);
+
+    let sink = ctx.generate_address();
+    ctx.client
+        .withdraw_treasury(&ctx.admin, &token.address, &sink, &30);
+    assert_eq!(token.balance(&sink), 30);
+    assert_eq!(ctx.client.get_treasury_balance(&token.address), 20);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_withdraw_treasury_rejects_over_withdrawal() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.env.mock_all_auths();
+
+    let to = ctx.generate_address();
+    ctx.client.withdraw_treasury(&ctx.admin, &token.address, &to, &1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_withdraw_treasury_requires_super_admin() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.env.mock_all_auths();
+
+    let stranger = ctx.generate_address();
+    ctx.client
+        .withdraw_treasury(&stranger, &token.address, &stranger, &1);
+}
@@ -241,7 +241,7 @@ impl TestContext {
                 invoke: &MockAuthInvoke {
                     contract: &self.client.address,
                     fn_name: "deposit",
-                    args: (project_id, donator, token, amount).into_val(&self.env),
+                    args: (project_id, token, amount).into_val(&self.env),
                     sub_invocations: &[
                         MockAuthInvoke {
                             contract: token,
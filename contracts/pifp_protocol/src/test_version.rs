@@ -0,0 +1,59 @@
+extern crate std;
+
+use crate::{storage, test_utils::TestContext, CONTRACT_VERSION};
+
+#[test]
+fn test_version_matches_code_constant() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.version(), CONTRACT_VERSION);
+}
+
+#[test]
+fn test_init_records_version() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_version(), CONTRACT_VERSION);
+}
+
+#[test]
+fn test_migrate_is_noop_when_current() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+
+    ctx.client.migrate(&ctx.admin);
+    assert_eq!(ctx.client.get_version(), CONTRACT_VERSION);
+}
+
+#[test]
+fn test_migrate_bumps_stale_version() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.env.as_contract(&ctx.client.address, || {
+        storage::set_contract_version(&ctx.env, 0);
+    });
+    assert_eq!(ctx.client.get_version(), 0);
+
+    ctx.client.migrate(&ctx.admin);
+    assert_eq!(ctx.client.get_version(), CONTRACT_VERSION);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_migrate_rejects_downgrade() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    ctx.env.as_contract(&ctx.client.address, || {
+        storage::set_contract_version(&ctx.env, CONTRACT_VERSION + 1);
+    });
+
+    ctx.client.migrate(&ctx.admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_migrate_requires_super_admin() {
+    let ctx = TestContext::new();
+    ctx.env.mock_all_auths();
+    let stranger = ctx.generate_address();
+
+    ctx.client.migrate(&stranger);
+}
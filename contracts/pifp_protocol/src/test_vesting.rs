@@ -0,0 +1,135 @@
+extern crate std;
+
+use soroban_sdk::token;
+
+use crate::{test_utils::TestContext, Project, ProjectStatus};
+
+const GRACE: u64 = 86_400;
+const DURATION: u64 = 1_000;
+
+/// Fund a 1000-unit vesting project to its goal and verify it.
+fn setup_verified(ctx: &TestContext) -> (Project, token::Client<'static>) {
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.env.mock_all_auths();
+    ctx.client
+        .set_vesting_duration(&ctx.manager, &project.id, &DURATION);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_proof(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    (project, token)
+}
+
+#[test]
+fn test_claim_vested_at_start_pays_nothing() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_verified(&ctx);
+    ctx.jump_time(GRACE);
+
+    let claimed = ctx
+        .client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+
+    assert_eq!(claimed, 0);
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+}
+
+#[test]
+fn test_claim_vested_halfway_then_full() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_verified(&ctx);
+    ctx.jump_time(GRACE + DURATION / 2);
+
+    let claimed = ctx
+        .client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+    assert_eq!(claimed, 500);
+    assert_eq!(token.balance(&ctx.manager), 500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+    assert_eq!(
+        ctx.client.get_vesting_claimed(&project.id, &token.address),
+        500
+    );
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+
+    ctx.jump_time(DURATION / 2);
+    let claimed = ctx
+        .client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+    assert_eq!(claimed, 500);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_claim_vested_after_full_period_pays_everything() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_verified(&ctx);
+    ctx.jump_time(GRACE + DURATION * 3);
+
+    let claimed = ctx
+        .client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+
+    assert_eq!(claimed, 1000);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #34)")]
+fn test_claim_vested_during_grace_period_fails() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_verified(&ctx);
+
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_claim_funds_refused_for_vesting_project() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_verified(&ctx);
+    ctx.jump_time(GRACE);
+
+    ctx.client.claim_funds(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_claim_vested_creator_only() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_verified(&ctx);
+    ctx.jump_time(GRACE + DURATION);
+
+    let stranger = ctx.generate_address();
+    ctx.client
+        .claim_vested(&stranger, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_admin_cancel_after_vested_claim_fails() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_verified(&ctx);
+    ctx.jump_time(GRACE + DURATION / 2);
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+
+    ctx.client.admin_cancel_project(&ctx.admin, &project.id);
+}
@@ -75,7 +75,7 @@ fn test_whitelist_funding_restricted() {
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "deposit",
-                args: (project.id, &donor, &token.address, 500i128).into_val(&env),
+                args: (project.id, &token.address, 500i128).into_val(&env),
                 sub_invocations: &[
                     MockAuthInvoke {
                         contract: &token.address,
@@ -147,7 +147,7 @@ fn test_whitelist_funding_allowed() {
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "deposit",
-                args: (project.id, &donor, &token.address, 500i128).into_val(&env),
+                args: (project.id, &token.address, 500i128).into_val(&env),
                 sub_invocations: &[
                     MockAuthInvoke {
                         contract: &token.address,
@@ -23,12 +23,14 @@
 //!     └──► Expired
 //! Active ──► Expired
 //! Active ──► Cancelled
+//! Funding / Active / Verified ──► Cancelled   (admin force-cancel)
+//! Verified ──► Cancelled   (creator did not claim within the claim timeout)
 //! ```
 //!
 //! Backward transitions and transitions out of terminal states (`Completed`,
 //! `Expired`, `Cancelled`) are rejected by lifecycle entrypoints.
 
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Symbol, Vec};
 
 /// Current lifecycle state of a funding project.
 #[contracttype]
@@ -44,11 +46,63 @@ pub enum ProjectStatus {
     Completed,
     /// Deadline passed without reaching goal or verification.
     Expired,
-    /// Project was manually cancelled after becoming active.
-    /// Remaining donor balances stay refundable.
+    /// Project was manually cancelled after becoming active, or
+    /// force-cancelled by an admin. Remaining donor balances stay refundable.
     Cancelled,
 }
 
+/// What to do with a deposit that would take a project past its goal.
+///
+/// Only applies to deposits in the first accepted token, in which the goal
+/// is denominated.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OvershootPolicy {
+    /// Accept the full deposit (default).
+    Keep,
+    /// Refuse the deposit with `CapExceeded`.
+    Reject,
+    /// Credit up to the goal and send the remainder straight back.
+    RefundExcess,
+}
+
+/// Whether a project shows up in discovery queries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Visibility {
+    /// Listed everywhere (default).
+    Public,
+    /// Left out of discovery queries such as `projects_accepting`; still
+    /// readable by ID, e.g. for invite-only campaigns.
+    Unlisted,
+}
+
+/// How basis-point cuts (protocol fee, oracle reward, deposit fee) round.
+/// Whatever is not taken as a cut stays with the project, so the cuts and
+/// the creator's share always add up to the amount being divided.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Truncate every cut, so the remainder goes to the creator (default).
+    Down,
+    /// Round every cut up.
+    Up,
+    /// Round every cut to the nearest unit; exact halves round down and
+    /// stay with the creator.
+    NearestWithRemainderToCreator,
+}
+
+/// Whether funds may be released when the goal was not reached.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FundingModel {
+    /// Verification releases whatever was raised (default).
+    KeepItAll,
+    /// Verification is refused with `GoalNotMet` until every token goal is
+    /// met, so donors are refunded unless the campaign fully funds.
+    AllOrNothing,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Milestone {
@@ -72,6 +126,22 @@ pub struct ProjectConfig {
     pub categories: u32,
     pub authorized_oracles: Vec<Address>,
     pub threshold: u32,
+    pub hash_algo: Symbol,
+    pub deposit_deadline: u64,
+    pub open_ended: bool,
+    pub overshoot_policy: OvershootPolicy,
+    pub release_to: Address,
+    pub category: Symbol,
+    pub created_at: u64,
+    pub required_proofs: Vec<BytesN<32>>,
+    pub funding_model: FundingModel,
+    pub visibility: Visibility,
+    pub lock_on_first_confirmation: bool,
+    pub institutions_only: bool,
+    pub display_goal_usd: i128,
+    pub tags: Vec<Symbol>,
+    pub soft_cap: i128,
+    pub tokens_locked: bool,
 }
 
 impl ProjectConfig {
@@ -93,14 +163,15 @@ impl ProjectConfig {
 pub struct ProjectState {
     pub status: ProjectStatus,
     /// Count of unique (donator, token) pairs that have deposited.
+    /// Saturates at `u32::MAX`.
     pub donation_count: u32,
     /// Emergency pause flag for this project. When true, deposits and
     /// verification/releases are blocked until an admin unpauses it.
     pub paused: bool,
     /// Ledger timestamp after which donors can no longer refund and the
-    /// creator may reclaim unclaimed funds.  Set to `deadline + REFUND_WINDOW`
-    /// when the project transitions to Expired, or `cancel_time + REFUND_WINDOW`
-    /// when cancelled.  Zero while the project is still in a non-terminal state.
+    /// creator may reclaim unclaimed funds.  Set to `deadline + refund window`
+    /// when the project transitions to Expired, or `cancel_time + refund window`
+    /// when cancelled (see `set_refund_window`).  Zero while the project is still in a non-terminal state.
     pub refund_expiry: u64,
     /// Ledger timestamp when the oracle verified the proof.  Zero until
     /// `verify_proof` is called.  Used to enforce the 24-hour grace period
@@ -118,11 +189,13 @@ pub struct ProjectState {
 pub struct Project {
     /// Auto-incremented unique ID.
     pub id: u64,
-    /// Address that registered and will receive released funds.
+    /// Address that registered and controls the project. Released funds go
+    /// to `release_to`, which defaults to this address.
     pub creator: Address,
-    /// Ordered list of SAC token addresses this project accepts.
-    /// Set once at registration; cannot be changed after creation.
-    /// Length: 1–10 tokens.
+    /// Ordered list of SAC token addresses this project accepts, set at
+    /// registration. `remove_token` drops a secondary token and
+    /// `migrate_token` swaps one for its redeployed address in place;
+    /// neither is allowed once `tokens_locked` is set. Length: 1–10 tokens.
     pub accepted_tokens: soroban_sdk::Vec<Address>,
     /// Funding goal expressed in the *first* accepted token's units.
     /// Used as a reference denominator; cross-token goals require off-chain logic.
@@ -136,7 +209,8 @@ pub struct Project {
     /// Current lifecycle state.
     pub status: ProjectStatus,
     /// Count of unique (token, donator) pairs that have donated.
-    /// Informational; incremented on each new deposit.
+    /// Informational; incremented on each new deposit, saturating at
+    /// `u32::MAX`.
     pub donation_count: u32,
     /// Is this a private project (whitelist only)?
     pub is_private: bool,
@@ -154,6 +228,52 @@ pub struct Project {
     pub completed_milestones: Vec<bool>,
     pub authorized_oracles: Vec<Address>,
     pub threshold: u32,
+    /// Digest algorithm used to produce `proof_hash` (e.g. `sha256`,
+    /// `keccak`). Informational only: lets off-chain verifiers recompute it.
+    pub hash_algo: Symbol,
+    /// Ledger timestamp after which new deposits are refused. Defaults to
+    /// `deadline`; may be set earlier so verification can continue after
+    /// fundraising closes.
+    pub deposit_deadline: u64,
+    /// Open-donation project: `goal` may be zero and deposits never move it
+    /// out of `Funding`.
+    pub open_ended: bool,
+    /// Handling of deposits that would overshoot `goal`.
+    pub overshoot_policy: OvershootPolicy,
+    /// Recipient of released funds. Defaults to `creator`; may point at a
+    /// custody contract while `creator` keeps control of the project.
+    pub release_to: Address,
+    /// Campaign type (e.g. `education`, `health`), published as an event
+    /// topic so indexers can subscribe per category. Defaults to `general`.
+    pub category: Symbol,
+    /// Ledger timestamp at which the project was registered.
+    pub created_at: u64,
+    /// Independent proofs (e.g. delivery + quality) that must all be
+    /// matched, one per `verify_proof` call, before the project is verified.
+    /// Empty means the single `proof_hash` suffices.
+    pub required_proofs: Vec<BytesN<32>>,
+    /// Whether verification requires the funding goal to be met.
+    pub funding_model: FundingModel,
+    /// Whether the project is listed in discovery queries.
+    pub visibility: Visibility,
+    /// Refuse deposits once any oracle confirmation has been recorded, so
+    /// the balance cannot move while a multi-oracle verification is pending.
+    pub lock_on_first_confirmation: bool,
+    /// Accept deposits only from donors in the admin-managed institution
+    /// registry.
+    pub institutions_only: bool,
+    /// Informational goal in USD with 6 decimals (0 = unset), for frontends
+    /// to show a fiat figure. Never used by release logic.
+    pub display_goal_usd: i128,
+    /// Free-form discovery tags (at most `MAX_TAGS`), each indexed for
+    /// `projects_by_tag`. Set via `set_tags`.
+    pub tags: Vec<Symbol>,
+    /// Minimum viable amount of the first accepted token (0 = none). Once
+    /// reached, an expired project is no longer refundable and its creator
+    /// may reclaim the funds straight away.
+    pub soft_cap: i128,
+    /// Once set via `lock_tokens`, `accepted_tokens` can never change again.
+    pub tokens_locked: bool,
 }
 
 impl Project {
@@ -189,6 +309,8 @@ pub struct ProjectBalances {
 pub struct OracleAgreement {
     pub votes: u32,
     pub voter_count: u32,
+    /// Summed consensus weight of the oracles that voted.
+    pub weight: u32,
 }
 
 /// Global protocol configuration managed by the SuperAdmin.
@@ -201,6 +323,108 @@ pub struct ProtocolConfig {
     pub fee_bps: u32,
 }
 
+/// A single deposit as recorded in the per-project donation log, kept
+/// distinct (not summed) so off-chain quadratic-funding math can run.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationRecord {
+    /// `None` for a deposit made through `deposit_anonymous`.
+    pub donor: Option<Address>,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// What an audit record's action was applied to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditSubject {
+    /// Nothing in particular: a protocol-wide action such as `pause`.
+    Protocol,
+    /// A project, e.g. for a release or a project pause.
+    Project(u64),
+    /// An address, e.g. the target of a role change.
+    Account(Address),
+}
+
+/// One entry of the on-chain audit log returned by `get_audit_log`.
+/// `action` is the event topic of the recorded call (e.g. `role_set`), or
+/// `su_xfer` for `transfer_super_admin`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditRecord {
+    /// Position in the full log, counting from the first record ever written.
+    pub seq: u32,
+    /// The authorizing caller, or this contract for `claim_funds`, which
+    /// anyone may trigger.
+    pub actor: Address,
+    pub action: Symbol,
+    pub subject: AuditSubject,
+    pub timestamp: u64,
+}
+
+/// An open pledge returned by `get_pledges`: tokens promised to a project
+/// but not yet transferred.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pledge {
+    pub donor: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Status snapshot returned by `health` for monitoring dashboards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthStatus {
+    /// `init` has been called and a SuperAdmin is set.
+    pub initialized: bool,
+    /// Protocol-wide pause flag.
+    pub paused: bool,
+    /// Number of projects registered so far.
+    pub project_count: u64,
+    /// Platform fee in basis points; zero when no protocol config is set.
+    pub fee_bps: u32,
+}
+
+/// A donor's recorded contribution, returned by `attest_contribution` for
+/// off-chain systems to anchor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionAttestation {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    /// The donor's refundable balance in `token` at `ledger`.
+    pub amount: i128,
+    pub ledger: u32,
+    /// sha256 over the XDR of `(project_id, donator, token, amount, ledger)`.
+    pub hash: BytesN<32>,
+}
+
+/// Key timestamps of a project, returned by `get_project_timeline`.
+/// Milestones not reached yet are zero.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectTimeline {
+    pub created_at: u64,
+    /// When the first-token balance first reached `goal`.
+    pub goal_reached_at: u64,
+    /// When the oracle last verified a proof.
+    pub verified_at: u64,
+    pub deadline: u64,
+}
+
+/// One transfer `simulate_release` predicts: `amount` of `token` to
+/// `recipient`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutLine {
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
 /// A single entry in a `batch_deposit` call.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]